@@ -1,29 +1,41 @@
+use aoc_core::error::{parse_lines_with, parse_number, ParseError};
 use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::solution::{Example, Solution};
 use std::collections::VecDeque;
 use std::str::Lines;
 
-fn main() {
-    let input = PuzzleInput::new("aoc-01/input.txt");
-    let summer = DepthSummer::new(input.to_lines());
-    println!(
-        "Number of depth increments with window size [ 1 ]: {:?}",
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const EXAMPLES: &'static [Example] = &[Example::both(include_str!("../example.txt"), "7", "5")];
+    type Parsed = DepthSummer;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+        DepthSummer::new(input.to_lines())
+    }
+
+    /// Number of depth increments with window size 1.
+    fn part_one(summer: &Self::Parsed) -> Self::PartOne {
         summer.sum(1)
-    );
-    println!(
-        "Number of depth increments with window size [ 3 ]: {:?}",
+    }
+
+    /// Number of depth increments with window size 3.
+    fn part_two(summer: &Self::Parsed) -> Self::PartTwo {
         summer.sum(3)
-    );
+    }
 }
 
-struct DepthSummer {
+pub struct DepthSummer {
     lines: Vec<i32>,
 }
 
 impl DepthSummer {
-    fn new(lines: Lines) -> Self {
-        DepthSummer {
-            lines: lines.map(|x| x.parse().unwrap()).collect(),
-        }
+    fn new(lines: Lines) -> Result<Self, ParseError> {
+        let lines = parse_lines_with(lines, parse_number)?;
+        Ok(DepthSummer { lines })
     }
 
     fn sum(&self, window_size: usize) -> i32 {
@@ -55,3 +67,19 @@ impl DepthSummer {
         sum
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::Day01;
+    use aoc_core::harness::{assert_answers, assert_examples};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day01>();
+    }
+
+    #[test]
+    fn answers() {
+        assert_answers::<Day01>();
+    }
+}
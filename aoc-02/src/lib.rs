@@ -0,0 +1,138 @@
+use std::str::{FromStr, Lines};
+
+use aoc_core::error::{parse_lines, parse_number, ParseError};
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::solution::{Example, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const EXAMPLES: &'static [Example] =
+        &[Example::both(include_str!("../example.txt"), "150", "900")];
+    type Parsed = SubNavigation;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+        SubNavigation::new(input.to_lines())
+    }
+
+    /// Product of the final horizontal position and depth, without aim.
+    fn part_one(nav: &Self::Parsed) -> Self::PartOne {
+        let position = nav.navigate_basic();
+        position.horizontal * position.depth
+    }
+
+    /// Product of the final horizontal position and depth, with aim.
+    fn part_two(nav: &Self::Parsed) -> Self::PartTwo {
+        let position = nav.navigate_advanced();
+        position.horizontal * position.depth
+    }
+}
+
+struct SubPosition {
+    horizontal: i32,
+    depth: i32,
+    aim: i32,
+}
+
+impl SubPosition {
+    fn new() -> Self {
+        SubPosition {
+            horizontal: 0,
+            depth: 0,
+            aim: 0,
+        }
+    }
+}
+
+pub struct SubNavigation {
+    movement: Vec<SubMovement>,
+}
+
+impl SubNavigation {
+    fn new(lines: Lines) -> Result<Self, ParseError> {
+        // FromStr is impl for SubMovement, and since movement is returned as part of SubNavigation, its type can be inferred, so parse_lines knows to parse each &str element to a SubMovement. Compilers!
+        let movement = parse_lines(lines)?;
+
+        Ok(SubNavigation { movement })
+    }
+
+    fn navigate_basic(&self) -> SubPosition {
+        let mut pos = SubPosition::new();
+        for step in &self.movement {
+            match step {
+                SubMovement::Forward(dist) => {
+                    pos.horizontal += dist;
+                }
+                SubMovement::Down(dist) => {
+                    pos.depth += dist;
+                }
+                SubMovement::Up(dist) => {
+                    pos.depth -= dist;
+                }
+            }
+        }
+        pos
+    }
+
+    fn navigate_advanced(&self) -> SubPosition {
+        let mut pos = SubPosition::new();
+        for step in &self.movement {
+            match step {
+                SubMovement::Forward(dist) => {
+                    pos.horizontal += dist;
+                    pos.depth += pos.aim * dist;
+                }
+                SubMovement::Down(dist) => {
+                    pos.aim += dist;
+                }
+                SubMovement::Up(dist) => {
+                    pos.aim -= dist;
+                }
+            }
+        }
+        pos
+    }
+}
+
+#[derive(Debug)]
+enum SubMovement {
+    Forward(i32),
+    Up(i32),
+    Down(i32),
+}
+
+impl FromStr for SubMovement {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (dir, dist) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new("Expected a direction and a distance", s))?;
+        let dist: i32 = parse_number(dist).map_err(|e| e.located_in(s, dist))?;
+        match dir {
+            "down" => Ok(SubMovement::Down(dist)),
+            "up" => Ok(SubMovement::Up(dist)),
+            "forward" => Ok(SubMovement::Forward(dist)),
+            _ => Err(ParseError::new("Expected forward, down or up", dir)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day02;
+    use aoc_core::harness::{assert_answers, assert_examples};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day02>();
+    }
+
+    #[test]
+    fn answers() {
+        assert_answers::<Day02>();
+    }
+}
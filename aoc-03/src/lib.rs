@@ -1,24 +1,41 @@
 use aoc_core::bit::bitmap::Bitmap;
 use aoc_core::bit::bitmap_list::BitmapList;
+use aoc_core::error::ParseError;
 use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::solution::{Example, Solution};
 
-fn main() {
-    let input = PuzzleInput::new("aoc-03/input.txt");
-    let sub_computer = SubmarineComputer::new(input.to_lines().into());
-    let list_size = sub_computer.get_data_size();
+pub struct Day03;
 
-    let gamma = sub_computer.get_count_bitmap(|c| c >= list_size as u32 / 2);
-    let epsilon = !gamma; // ! is "not" on things like integers too!
-    let product_gamma_epsilon: u32 = u32::from(gamma) * u32::from(epsilon);
-    println!("Gamma-Epsilon product: {:?}", product_gamma_epsilon);
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const EXAMPLES: &'static [Example] =
+        &[Example::both(include_str!("../example.txt"), "198", "230")];
+    type Parsed = SubmarineComputer;
+    type PartOne = u32;
+    type PartTwo = u32;
 
-    let oxy = sub_computer.get_life_support(|count, size| count * 2 >= size);
-    let co2 = sub_computer.get_life_support(|count, size| count * 2 < size);
-    let product_oxy_co2 = u32::from(oxy) * u32::from(co2);
-    println!("Oxygen-CO2 product: {:?}", product_oxy_co2);
+    fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+        let data = BitmapList::try_from(input.to_lines())?;
+        Ok(SubmarineComputer::new(data))
+    }
+
+    /// Gamma-epsilon product.
+    fn part_one(sub_computer: &Self::Parsed) -> Self::PartOne {
+        let list_size = sub_computer.get_data_size();
+        let gamma = sub_computer.get_count_bitmap(|c| c >= list_size as u32 / 2);
+        let epsilon = !gamma; // ! is "not" on things like integers too!
+        u32::from(gamma) * u32::from(epsilon)
+    }
+
+    /// Oxygen-CO2 product.
+    fn part_two(sub_computer: &Self::Parsed) -> Self::PartTwo {
+        let oxy = sub_computer.get_life_support(|count, size| count * 2 >= size);
+        let co2 = sub_computer.get_life_support(|count, size| count * 2 < size);
+        u32::from(oxy) * u32::from(co2)
+    }
 }
 
-struct SubmarineComputer {
+pub struct SubmarineComputer {
     /// The internal collection of bitmaps.
     data: BitmapList,
     /// A collection of integer counts, where the index corresponds to the bit position (right-most first),
@@ -42,9 +59,9 @@ impl SubmarineComputer {
     fn calc_bit_counts(bitmap_list: &BitmapList) -> Vec<u32> {
         let mut bit_counts = vec![0u32; bitmap_list.bitmap_size];
         for bitmap in &bitmap_list.list {
-            for pos in 0..bitmap_list.bitmap_size {
+            for (pos, count) in bit_counts.iter_mut().enumerate() {
                 if bitmap.get(pos) {
-                    bit_counts[pos] += 1;
+                    *count += 1;
                 }
             }
         }
@@ -53,10 +70,10 @@ impl SubmarineComputer {
 
     /// Calculates a new bitmap according to the given test, where each bit represents whether or not the test returned true for that bit position.
     /// The argument passed to `test` is the number of 1s in the bit position being tested.
-    fn calc_count_bitmap<F: Fn(u32) -> bool>(bit_counts: &Vec<u32>, test: F) -> Bitmap {
+    fn calc_count_bitmap<F: Fn(u32) -> bool>(bit_counts: &[u32], test: F) -> Bitmap {
         let mut bitmap = Bitmap::new(0, bit_counts.len());
         for (index, count) in bit_counts.iter().enumerate() {
-            if test(*count) == true {
+            if test(*count) {
                 bitmap.set(index, true);
             }
         }
@@ -92,10 +109,10 @@ impl SubmarineComputer {
             .list
             .iter()
             .filter(|b| b.get(pos) == ref_value)
-            .map(|x| x.clone())
+            .copied()
             .collect();
         if filtered.len() == 1 {
-            return filtered.first().unwrap().clone();
+            return *filtered.first().unwrap();
         }
         if filtered.is_empty() {
             panic!("Filtered down to an empty list!")
@@ -112,3 +129,19 @@ impl SubmarineComputer {
         SubmarineComputer::calc_life_support(&self.data, self.data.bitmap_size, test)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::Day03;
+    use aoc_core::harness::{assert_answers, assert_examples};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day03>();
+    }
+
+    #[test]
+    fn answers() {
+        assert_answers::<Day03>();
+    }
+}
@@ -1,35 +1,51 @@
 use aoc_core::bit::bitmap::Bitmap;
+use aoc_core::error::{parse_numbers, ParseError};
 use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::solution::{Example, Solution};
 use std::fmt::{Debug, Display, Formatter};
-use std::str::Lines;
-
-fn main() {
-    let input = PuzzleInput::new("aoc-04/input.txt");
-    let lines = input.to_lines();
-
-    let mut bingo_bot: BingoBot = lines.into();
-    let sorted_boards = bingo_bot.sort_boards();
-    let first = sorted_boards.first().expect("No winner!");
-    println!(
-        "First board to win:\n{}\nFinal score: {}\n",
-        first,
+use std::str::FromStr;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const EXAMPLES: &'static [Example] = &[Example::both(
+        include_str!("../example.txt"),
+        "4512",
+        "1924",
+    )];
+    type Parsed = BingoBot;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+        BingoBot::try_from(input)
+    }
+
+    /// Final score of the first board to win.
+    fn part_one(bingo_bot: &Self::Parsed) -> Self::PartOne {
+        let mut bingo_bot = bingo_bot.clone();
+        let sorted_boards = bingo_bot.sort_boards();
+        let first = sorted_boards.first().expect("No winner!");
         first.get_final_score().expect("No final score recorded!")
-    );
-    let last = sorted_boards.last().expect("No winner!");
-    println!(
-        "Last board to win:\n{}\nFinal score: {}\n",
-        last,
+    }
+
+    /// Final score of the last board to win.
+    fn part_two(bingo_bot: &Self::Parsed) -> Self::PartTwo {
+        let mut bingo_bot = bingo_bot.clone();
+        let sorted_boards = bingo_bot.sort_boards();
+        let last = sorted_boards.last().expect("No winner!");
         last.get_final_score().expect("No final score recorded!")
-    );
+    }
 }
 
 const fn new_const(value: u32, bitmap_size: usize) -> Bitmap<u32> {
     Bitmap::new_const(value, bitmap_size)
 }
 
-static WINNING_BOARDS: [Bitmap; 12] = {
-    let row0 = new_const(31 << 0, 25);
-    let col0 = new_const(1_082_401 << 0, 25);
+static WINNING_BOARDS: [Bitmap; 10] = {
+    let row0 = new_const(31, 25);
+    let col0 = new_const(1_082_401, 25);
     let row1 = new_const(31 << 5, 25);
     let col1 = new_const(1_082_401 << 1, 25);
     let row2 = new_const(31 << 10, 25);
@@ -38,27 +54,13 @@ static WINNING_BOARDS: [Bitmap; 12] = {
     let col3 = new_const(1_082_401 << 3, 25);
     let row4 = new_const(31 << 20, 25);
     let col4 = new_const(1_082_401 << 4, 25);
-    let left_down_diag = new_const(17_043_521u32, 25);
-    let right_down_diag = new_const(1_118_480u32, 25);
-    let boards: [Bitmap; 12] = [
-        row0,
-        col0,
-        row1,
-        col1,
-        row2,
-        col2,
-        row3,
-        col3,
-        row4,
-        col4,
-        left_down_diag,
-        right_down_diag,
-    ];
+    let boards: [Bitmap; 10] = [row0, col0, row1, col1, row2, col2, row3, col3, row4, col4];
     boards
 };
 
 /// A helpful robot that runs the bingo game, drawing numbers, keeping track of winners, and making sure everyone's marked their boards.
-struct BingoBot {
+#[derive(Debug, Clone)]
+pub struct BingoBot {
     boards: Vec<BingoBoard>,
     drawings: Vec<u8>,
     current_drawing: usize,
@@ -84,8 +86,9 @@ impl BingoBot {
                 if self.boards[idx].is_winner() {
                     self.boards[idx].set_final_score(drawn_number);
                     sorted.push(self.boards.remove(idx));
+                } else {
+                    idx += 1;
                 }
-                idx += 1;
             }
             self.current_drawing += 1;
         }
@@ -94,35 +97,21 @@ impl BingoBot {
     }
 }
 
-impl<'a> From<Lines<'a>> for BingoBot {
-    fn from(mut lines: Lines) -> Self {
-        // Parse drawings
-        let drawings: Vec<u8> = lines
+impl TryFrom<&PuzzleInput> for BingoBot {
+    type Error = ParseError;
+
+    /// Parses the drawings, then each board, with a blank line between each of them.
+    fn try_from(input: &PuzzleInput) -> Result<Self, Self::Error> {
+        let mut sections = input.sections();
+        let drawings = sections
             .next()
-            .unwrap()
-            .split(',')
-            .map(|x| x.parse().unwrap())
-            .collect();
-        lines.next();
-
-        // Parse boards
-        let mut bingo_board_builders: Vec<String> = vec![String::new()];
-        for line in lines {
-            match line {
-                "" => bingo_board_builders.push(String::new()),
-                _ => {
-                    let idx = bingo_board_builders.len() - 1;
-                    bingo_board_builders[idx].push_str(" ");
-                    bingo_board_builders[idx].push_str(line);
-                }
-            }
-        }
-        let boards = bingo_board_builders
-            .iter()
-            .map(|x| x.as_str().into())
-            .collect();
+            .ok_or_else(|| ParseError::new("Expected a list of drawings", ""))?
+            .numbers()?;
+        let boards = sections
+            .map(|section| section.parse())
+            .collect::<Result<_, _>>()?;
 
-        BingoBot::new(boards, drawings)
+        Ok(BingoBot::new(boards, drawings))
     }
 }
 
@@ -138,7 +127,7 @@ impl Display for BingoBot {
 
 /// A 5x5 bingo board.
 #[derive(Debug, Clone)]
-struct BingoBoard {
+pub struct BingoBoard {
     /// The numbers at each position of the board, from left-to-right, top-to-bottom (left-topmost is index 0, right-bottommost is index 24).
     board: Vec<u8>,
     /// A bitmap where each bit position corresponds to whether the board at that index has been marked.
@@ -199,15 +188,19 @@ impl BingoBoard {
     }
 }
 
-impl From<&str> for BingoBoard {
-    fn from(input: &str) -> Self {
-        let trimmed = input.replace("  ", " ");
-        let board = trimmed
-            .trim()
-            .split(' ')
-            .map(|x| x.parse().expect(format!("{} is not parsable!", x).as_str()))
-            .collect();
-        BingoBoard::new(board)
+impl FromStr for BingoBoard {
+    type Err = ParseError;
+
+    /// Parses a board from its rows of numbers, one row per line.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let board = parse_numbers(input)?;
+        if board.len() != 25 {
+            return Err(ParseError::new(
+                "Bingo boards can only be 25 elements long",
+                input,
+            ));
+        }
+        Ok(BingoBoard::new(board))
     }
 }
 
@@ -224,10 +217,26 @@ impl Display for BingoBoard {
                     item.replace_range(3..4, "<");
                 }
                 if index % 5 == 4 {
-                    item.push_str("\n");
+                    item.push('\n');
                 }
                 format!("{}{}", acc, item)
             });
         f.write_str(result.as_str())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::Day04;
+    use aoc_core::harness::{assert_answers, assert_examples};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day04>();
+    }
+
+    #[test]
+    fn answers() {
+        assert_answers::<Day04>();
+    }
+}
@@ -0,0 +1,182 @@
+use aoc_core::error::{parse_number, ParseError};
+use aoc_core::grid::Grid;
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::solution::{Example, Solution};
+use std::str::FromStr;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const EXAMPLES: &'static [Example] =
+        &[Example::both(include_str!("../example.txt"), "5", "12")];
+    type Parsed = Vec<Line>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+        input.parse_lines()
+    }
+
+    /// Number of points where at least two horizontal or vertical lines overlap.
+    fn part_one(lines: &Self::Parsed) -> Self::PartOne {
+        let straight_lines = lines
+            .iter()
+            .filter(|line| line.a.x == line.b.x || line.a.y == line.b.y);
+
+        let mut straight_line_grid = VentMap::new(lines);
+        for line in straight_lines {
+            straight_line_grid.add_line(line);
+        }
+        straight_line_grid.get_count_overlaps()
+    }
+
+    /// Number of points where at least two lines overlap.
+    fn part_two(lines: &Self::Parsed) -> Self::PartTwo {
+        let mut all_line_grid = VentMap::new(lines);
+        for line in lines {
+            all_line_grid.add_line(line);
+        }
+        all_line_grid.get_count_overlaps()
+    }
+}
+
+/// Counts how many lines pass through each point on the ocean floor.
+#[derive(Debug)]
+struct VentMap {
+    data: Grid<u8>,
+}
+
+impl VentMap {
+    /// Creates an empty map, just large enough to fit every one of `lines`.
+    pub fn new(lines: &[Line]) -> Self {
+        let points = lines.iter().flat_map(|line| [&line.a, &line.b]);
+        let (width, height) = points.fold((0, 0), |(width, height), point| {
+            (width.max(point.x + 1), height.max(point.y + 1))
+        });
+        VentMap {
+            data: Grid::filled(width as usize, height as usize, 0),
+        }
+    }
+
+    pub fn add_line(&mut self, line: &Line) {
+        if line.a.x == line.b.x {
+            // Is vertical
+            let range = if line.a.y < line.b.y {
+                line.a.y..=line.b.y
+            } else {
+                line.b.y..=line.a.y
+            };
+            for i in range {
+                self.data[(line.a.x as usize, i as usize)] += 1
+            }
+        } else if line.a.y == line.b.y {
+            // Is horizontal
+            let range = if line.a.x < line.b.x {
+                line.a.x..=line.b.x
+            } else {
+                line.b.x..=line.a.x
+            };
+            for i in range {
+                self.data[(i as usize, line.a.y as usize)] += 1
+            }
+        } else {
+            // Is diagonal
+            let dist = line.b.x.abs_diff(line.a.x);
+            let is_h_increment = line.a.x < line.b.x;
+            let is_v_increment = line.a.y < line.b.y;
+            for i in 0..=dist {
+                let x = if is_h_increment {
+                    line.a.x + i
+                } else {
+                    line.a.x - i
+                };
+                let y = if is_v_increment {
+                    line.a.y + i
+                } else {
+                    line.a.y - i
+                };
+                self.data[(x as usize, y as usize)] += 1;
+            }
+        }
+    }
+
+    pub fn get_count_overlaps(&self) -> u32 {
+        self.data
+            .iter()
+            .filter(|(_, _, overlaps)| **overlaps >= 2)
+            .count() as u32
+    }
+}
+
+#[derive(Debug)]
+pub struct Point {
+    x: u32,
+    y: u32,
+}
+
+impl Point {
+    pub fn new(x: u32, y: u32) -> Self {
+        Point { x, y }
+    }
+}
+
+#[derive(Debug)]
+pub struct Line {
+    a: Point,
+    b: Point,
+}
+
+impl Line {
+    pub fn new(a: Point, b: Point) -> Self {
+        Line { a, b }
+    }
+}
+
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (x, y) = input
+            .split_once(',')
+            .ok_or_else(|| ParseError::new("Expected a point like x,y", input))?;
+        let x = parse_number(x)?;
+        let y = parse_number(y).map_err(|e| e.located_in(input, y))?;
+        Ok(Point::new(x, y))
+    }
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (a, b) = input
+            .split_once(" -> ")
+            .ok_or_else(|| ParseError::new("Expected a line like x1,y1 -> x2,y2", input))?;
+        let a: Point = a.parse()?;
+        let b: Point = b.parse().map_err(|e: ParseError| e.located_in(input, b))?;
+        if a.x != b.x && a.y != b.y && a.x.abs_diff(b.x) != a.y.abs_diff(b.y) {
+            return Err(ParseError::new(
+                "Lines must be horizontal, vertical, or diagonal at 45 degrees",
+                input,
+            ));
+        }
+        Ok(Line::new(a, b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day05;
+    use aoc_core::harness::{assert_answers, assert_examples};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day05>();
+    }
+
+    #[test]
+    fn answers() {
+        assert_answers::<Day05>();
+    }
+}
@@ -0,0 +1,100 @@
+use aoc_core::error::{parse_number, parse_separated_with, ParseError};
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::solution::{Example, Solution};
+use std::str::FromStr;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const EXAMPLES: &'static [Example] = &[Example::both(
+        include_str!("../example.txt"),
+        "5934",
+        "26984457539",
+    )];
+    type Parsed = School;
+    type PartOne = u128;
+    type PartTwo = u128;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+        input.as_string().trim_end().parse()
+    }
+
+    /// Lanternfish school population on day 80.
+    fn part_one(school: &Self::Parsed) -> Self::PartOne {
+        school.size_on_day(80)
+    }
+
+    /// Lanternfish school population on day 256.
+    fn part_two(school: &Self::Parsed) -> Self::PartTwo {
+        school.size_on_day(256)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct School {
+    /// The index is the days until spawn, the value is the amount of fish with that value.
+    population: [u128; 9],
+    day: u32,
+}
+
+impl School {
+    pub fn new(population: [u128; 9]) -> Self {
+        School { population, day: 0 }
+    }
+
+    pub fn simulate_day(&mut self) {
+        let new_fish = self.population[0];
+        for i in 0usize..=7usize {
+            self.population[i] = self.population[i + 1];
+        }
+        self.population[8] = new_fish;
+        self.population[6] += new_fish;
+        self.day += 1;
+    }
+
+    /// Returns the size the school will be on the given day, without changing the current school.
+    pub fn size_on_day(&self, day: u32) -> u128 {
+        let mut school = self.clone();
+        while school.day < day {
+            school.simulate_day();
+        }
+        school.size()
+    }
+
+    pub fn size(&self) -> u128 {
+        self.population.iter().fold(0u128, |acc, val| acc + *val)
+    }
+}
+
+impl FromStr for School {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut population = [0u128; 9];
+        let timers = parse_separated_with(input, |x| match parse_number::<usize>(x)? {
+            timer if timer < population.len() => Ok(timer),
+            _ => Err(ParseError::new("Lanternfish spawn at most 8 days apart", x)),
+        })?;
+        for timer in timers {
+            population[timer] += 1;
+        }
+        Ok(School::new(population))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day06;
+    use aoc_core::harness::{assert_answers, assert_examples};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day06>();
+    }
+
+    #[test]
+    fn answers() {
+        assert_answers::<Day06>();
+    }
+}
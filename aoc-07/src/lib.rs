@@ -1,28 +1,35 @@
+use aoc_core::error::ParseError;
 use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::solution::{Example, Solution};
 
-fn main() {
-    let input = PuzzleInput::new("aoc-07/input.txt");
-    let swarm = CrabSwarm::new(
-        input
-            .as_string()
-            .split(',')
-            .map(|x| CrabSub::new(x.parse().expect(format!("Could not parse {}", x).as_str())))
-            .collect(),
-    );
-    let constant_convergence = swarm.converge_constant();
-    println!(
-        "Swarm converges most efficiently to position {}, using {} units of fuel, using a constant fuel consumption model",
-        constant_convergence.0, constant_convergence.1
-    );
-    let triangular_convergence = swarm.converge_triangular();
-    println!(
-        "Swarm converges most efficiently to position {}, using {} units of fuel, using a triangular fuel consumption model",
-        triangular_convergence.0, triangular_convergence.1
-    );
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const EXAMPLES: &'static [Example] =
+        &[Example::both(include_str!("../example.txt"), "37", "168")];
+    type Parsed = CrabSwarm;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+        let subs = input.numbers()?.into_iter().map(CrabSub::new).collect();
+        Ok(CrabSwarm::new(subs))
+    }
+
+    /// Fuel used to converge on the best position, using a constant fuel consumption model.
+    fn part_one(swarm: &Self::Parsed) -> Self::PartOne {
+        swarm.converge_constant().1
+    }
+
+    /// Fuel used to converge on the best position, using a triangular fuel consumption model.
+    fn part_two(swarm: &Self::Parsed) -> Self::PartTwo {
+        swarm.converge_triangular().1
+    }
 }
 
 #[derive(Debug)]
-struct CrabSub {
+pub struct CrabSub {
     pos: u32,
 }
 
@@ -58,7 +65,7 @@ impl From<u32> for CrabSub {
 }
 
 #[derive(Debug)]
-struct CrabSwarm {
+pub struct CrabSwarm {
     subs: Vec<CrabSub>,
 }
 
@@ -118,3 +125,19 @@ impl CrabSwarm {
         max
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::Day07;
+    use aoc_core::harness::{assert_answers, assert_examples};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day07>();
+    }
+
+    #[test]
+    fn answers() {
+        assert_answers::<Day07>();
+    }
+}
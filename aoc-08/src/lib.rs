@@ -1,36 +1,65 @@
+use aoc_core::error::ParseError;
 use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::solution::{Example, Solution};
 use std::collections::HashMap;
+use std::str::FromStr;
 
-fn main() {
-    let input = PuzzleInput::new("aoc-08/input.txt");
-    let mut segment_displays: Vec<SegmentDisplay> =
-        input.to_lines().map(SegmentDisplay::from).collect();
-    let mut count_unique_segments = 0u32;
-    let mut sum_outputs = 0u32;
-    for display in segment_displays.iter_mut() {
-        display.decode();
-        let decoded = display.get_decoded();
-        for digit in &decoded {
-            match digit {
-                1 | 4 | 7 | 8 => count_unique_segments += 1,
-                _ => {}
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const EXAMPLES: &'static [Example] =
+        &[Example::both(include_str!("../example.txt"), "26", "61229")];
+    type Parsed = Vec<SegmentDisplay>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+        input.parse_lines()
+    }
+
+    /// Occurrences of digits with a unique number of segments in the outputs.
+    fn part_one(segment_displays: &Self::Parsed) -> Self::PartOne {
+        let mut count_unique_segments = 0u32;
+        for decoded in decode_all(segment_displays) {
+            for digit in &decoded {
+                match digit {
+                    1 | 4 | 7 | 8 => count_unique_segments += 1,
+                    _ => {}
+                }
             }
         }
-        let output = decoded.iter().rev().enumerate().fold(0u32, |acc, val| {
-            let digit = *val.1 as u32;
-            acc + (10u32.pow(val.0 as u32) * digit)
-        });
-        sum_outputs += output;
-    }
-    println!(
-        "Occurrences of digits with unique # of segments: {}",
         count_unique_segments
-    );
-    println!("Sum of all outputs: {}", sum_outputs);
+    }
+
+    /// Sum of all outputs.
+    fn part_two(segment_displays: &Self::Parsed) -> Self::PartTwo {
+        decode_all(segment_displays)
+            .iter()
+            .map(|decoded| {
+                decoded.iter().rev().enumerate().fold(0u32, |acc, val| {
+                    let digit = *val.1 as u32;
+                    acc + (10u32.pow(val.0 as u32) * digit)
+                })
+            })
+            .sum()
+    }
+}
+
+/// Decodes a copy of every display, returning the decoded output digits of each.
+fn decode_all(segment_displays: &[SegmentDisplay]) -> Vec<Vec<u8>> {
+    segment_displays
+        .iter()
+        .map(|display| {
+            let mut display = display.clone();
+            display.decode();
+            display.get_decoded()
+        })
+        .collect()
 }
 
-#[derive(Debug)]
-struct SegmentDisplay {
+#[derive(Debug, Clone)]
+pub struct SegmentDisplay {
     // The list of 10 unique signal inputs that will show on this display
     notes: Vec<SegmentDigit>,
     // The list of digits to decode
@@ -154,7 +183,7 @@ impl SegmentDisplay {
             .notes
             .iter_mut()
             .find(|dig| dig.get_segments_len() == segment_len)
-            .expect(format!("No segment digit with {} segments!", segment_len).as_str());
+            .unwrap_or_else(|| panic!("No segment digit with {} segments!", segment_len));
         seg_digit.set_decoded(digit);
     }
 
@@ -164,7 +193,7 @@ impl SegmentDisplay {
             .notes
             .iter_mut()
             .find(|dig| **dig == match_digit)
-            .expect(format!("No segment digit with segments: {:?}", &segments).as_str());
+            .unwrap_or_else(|| panic!("No segment digit with segments: {:?}", &segments));
         seg_digit.set_decoded(digit);
     }
 
@@ -234,7 +263,7 @@ impl SegmentDisplay {
         four.segments
             .iter()
             .find(|seg| **seg != top_left && **seg != top_right && **seg != bot_right)
-            .map(Segment::clone)
+            .copied()
             .expect("No middle segment found!")
     }
 
@@ -250,7 +279,7 @@ impl SegmentDisplay {
         let mut occurrences: HashMap<Segment, u8> = HashMap::new();
         for item in &self.notes {
             for segment in &item.segments {
-                let next_value = occurrences.get(segment).map(|i| *i).unwrap_or(0) + 1;
+                let next_value = occurrences.get(segment).copied().unwrap_or(0) + 1;
                 occurrences.insert(*segment, next_value);
             }
         }
@@ -258,20 +287,34 @@ impl SegmentDisplay {
     }
 }
 
-impl From<&str> for SegmentDisplay {
-    fn from(input: &str) -> Self {
-        let mut split: Vec<Vec<SegmentDigit>> = input
-            .split(" | ")
-            .map(|inner| inner.split(' ').map(SegmentDigit::from).collect())
-            .collect();
-        let notes = split.remove(0);
-        let output = split.remove(0);
-        SegmentDisplay::new(notes, output)
+impl FromStr for SegmentDisplay {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (notes, output) = input
+            .split_once(" | ")
+            .ok_or_else(|| ParseError::new("Expected notes and output separated by |", input))?;
+        let parse_digits = |inner: &str| {
+            inner
+                .split(' ')
+                .map(|digit| {
+                    digit
+                        .parse()
+                        .map_err(|e: ParseError| e.located_in(input, digit))
+                })
+                .collect::<Result<Vec<SegmentDigit>, ParseError>>()
+        };
+        let notes = parse_digits(notes)?;
+        if notes.len() != 10 {
+            return Err(ParseError::new("Expected notes for all 10 digits", input));
+        }
+        let output = parse_digits(output)?;
+        Ok(SegmentDisplay::new(notes, output))
     }
 }
 
-#[derive(Debug)]
-struct SegmentDigit {
+#[derive(Debug, Clone)]
+pub struct SegmentDigit {
     segments: Vec<Segment>,
     decoded: Option<u8>,
 }
@@ -297,10 +340,18 @@ impl SegmentDigit {
     }
 }
 
-impl From<&str> for SegmentDigit {
-    fn from(input: &str) -> Self {
-        let segments: Vec<Segment> = input.chars().map(Segment::from).collect();
-        SegmentDigit::new(segments)
+impl FromStr for SegmentDigit {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let segments = input
+            .char_indices()
+            .map(|(index, c)| Segment::try_from(c).map_err(|e| e.offset_columns(index)))
+            .collect::<Result<Vec<Segment>, ParseError>>()?;
+        if segments.is_empty() || segments.len() > 7 {
+            return Err(ParseError::new("Expected between 1 and 7 segments", input));
+        }
+        Ok(SegmentDigit::new(segments))
     }
 }
 
@@ -314,7 +365,7 @@ impl PartialEq for SegmentDigit {
 impl Eq for SegmentDigit {}
 
 #[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
-enum Segment {
+pub enum Segment {
     A,
     B,
     C,
@@ -324,24 +375,31 @@ enum Segment {
     G,
 }
 
-impl From<char> for Segment {
-    fn from(input: char) -> Self {
+impl TryFrom<char> for Segment {
+    type Error = ParseError;
+
+    fn try_from(input: char) -> Result<Self, Self::Error> {
         match input {
-            'a' => Segment::A,
-            'b' => Segment::B,
-            'c' => Segment::C,
-            'd' => Segment::D,
-            'e' => Segment::E,
-            'f' => Segment::F,
-            'g' => Segment::G,
-            _ => panic!("Invalid value: {}", input),
+            'a' => Ok(Segment::A),
+            'b' => Ok(Segment::B),
+            'c' => Ok(Segment::C),
+            'd' => Ok(Segment::D),
+            'e' => Ok(Segment::E),
+            'f' => Ok(Segment::F),
+            'g' => Ok(Segment::G),
+            _ => Err(ParseError::new(
+                "Expected a segment from a to g",
+                &input.to_string(),
+            )),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{Segment, SegmentDigit};
+    use crate::{Day08, Segment, SegmentDigit, SegmentDisplay};
+    use aoc_core::harness::{assert_answers, assert_examples};
+    use std::str::FromStr;
 
     #[test]
     fn can_eq_segments() {
@@ -363,4 +421,40 @@ mod tests {
         assert_ne!(dig_1, dig_3);
         assert_eq!(dig_3, dig_2);
     }
+
+    #[test]
+    fn can_parse_segment_digits() {
+        let digit = SegmentDigit::from_str("fcb").unwrap();
+        assert_eq!(
+            digit,
+            SegmentDigit::new(vec![Segment::B, Segment::C, Segment::F])
+        );
+
+        let error = SegmentDigit::from_str("fcx").unwrap_err();
+        assert_eq!(error.get_column(), 3);
+        assert_eq!(error.get_text(), "x");
+    }
+
+    #[test]
+    fn can_parse_segment_displays() {
+        let line = "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe";
+        assert!(SegmentDisplay::from_str(line).is_ok());
+
+        let line = "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbz";
+        let error = SegmentDisplay::from_str(line).unwrap_err();
+        assert_eq!(error.get_column(), 86);
+        assert_eq!(error.get_text(), "z");
+
+        assert!(SegmentDisplay::from_str("be cfbegad | fdgacbe").is_err());
+    }
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day08>();
+    }
+
+    #[test]
+    fn answers() {
+        assert_answers::<Day08>();
+    }
 }
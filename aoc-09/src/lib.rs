@@ -1,30 +1,44 @@
+use aoc_core::error::ParseError;
 use aoc_core::intmap::{IntMap, IntMapPoint};
 use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::solution::{Example, Solution};
 use colored::*;
 use std::collections::HashSet;
 use std::fmt::{Display, Formatter};
 use std::str::Lines;
 
-fn main() {
-    let input = PuzzleInput::new("aoc-09/input.txt");
-    let depth_map: DepthMap = input.to_lines().into();
-    println!("Depth Map:\n{}", depth_map);
-    let low_points = depth_map.get_local_minimums();
-    let risk_levels: Vec<u8> = low_points.iter().map(|x| x.2 + 1).collect();
-    let risk_level_sum = risk_levels.iter().fold(0u32, |acc, val| acc + *val as u32);
-    println!("Sum of risk levels at low points: {}", risk_level_sum);
-    debug_assert_eq!(risk_level_sum, 480);
-
-    let mut basins = depth_map.get_basins();
-    basins.sort_by(|a, b| b.len().cmp(&a.len()));
-    let basin_product = basins[0].len() * basins[1].len() * basins[2].len();
-    println!("Product of 3 largest basins: {}", basin_product);
-    debug_assert_eq!(basin_product, 1045660);
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const EXAMPLES: &'static [Example] =
+        &[Example::both(include_str!("../example.txt"), "15", "1134")];
+    type Parsed = DepthMap;
+    type PartOne = u32;
+    type PartTwo = usize;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+        DepthMap::try_from(input.to_lines())
+    }
+
+    /// Sum of risk levels at low points.
+    fn part_one(depth_map: &Self::Parsed) -> Self::PartOne {
+        let low_points = depth_map.get_local_minimums();
+        let risk_levels: Vec<u8> = low_points.iter().map(|x| x.2 + 1).collect();
+        risk_levels.iter().fold(0u32, |acc, val| acc + *val as u32)
+    }
+
+    /// Product of the sizes of the 3 largest basins.
+    fn part_two(depth_map: &Self::Parsed) -> Self::PartTwo {
+        let mut basins = depth_map.get_basins();
+        basins.sort_by_key(|b| std::cmp::Reverse(b.len()));
+        basins[0].len() * basins[1].len() * basins[2].len()
+    }
 }
 
 type Basin = HashSet<IntMapPoint>;
 
-struct DepthMap {
+pub struct DepthMap {
     inner: IntMap,
 }
 
@@ -72,10 +86,12 @@ impl DepthMap {
     }
 }
 
-impl<'a> From<Lines<'a>> for DepthMap {
-    fn from(input: Lines) -> Self {
-        let intmap = IntMap::from(input);
-        DepthMap::new(intmap)
+impl TryFrom<Lines<'_>> for DepthMap {
+    type Error = ParseError;
+
+    fn try_from(input: Lines<'_>) -> Result<Self, Self::Error> {
+        let intmap = IntMap::try_from(input)?;
+        Ok(DepthMap::new(intmap))
     }
 }
 
@@ -100,3 +116,19 @@ impl Display for DepthMap {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::Day09;
+    use aoc_core::harness::{assert_answers, assert_examples};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day09>();
+    }
+
+    #[test]
+    fn answers() {
+        assert_answers::<Day09>();
+    }
+}
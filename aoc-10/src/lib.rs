@@ -0,0 +1,169 @@
+use aoc_core::error::ParseError;
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::solution::{Example, Solution};
+use std::str::FromStr;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const EXAMPLES: &'static [Example] = &[Example::both(
+        include_str!("../example.txt"),
+        "26397",
+        "288957",
+    )];
+    type Parsed = Vec<NavigationInstruction>;
+    type PartOne = u32;
+    type PartTwo = u64;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+        input.parse_lines()
+    }
+
+    /// Score from corrupt lines.
+    fn part_one(instructions: &Self::Parsed) -> Self::PartOne {
+        instructions
+            .iter()
+            .map(NavigationInstruction::complete)
+            .filter_map(Result::err)
+            .fold(0u32, |acc, val| acc + val.get_corrupt_score())
+    }
+
+    /// Median score from incomplete lines.
+    fn part_two(instructions: &Self::Parsed) -> Self::PartTwo {
+        let incomplete: Vec<Vec<NavigationChunkType>> = instructions
+            .iter()
+            .map(NavigationInstruction::complete)
+            .filter_map(Result::ok)
+            .collect();
+        let mut incomplete_scores: Vec<u64> = incomplete
+            .iter()
+            .map(|incomplete_item| {
+                incomplete_item
+                    .iter()
+                    .fold(0u64, |acc, val| (acc * 5) + val.get_incomplete_score())
+            })
+            .collect();
+        incomplete_scores.sort();
+        let midpoint = (incomplete_scores.len() - 1) / 2;
+        incomplete_scores[midpoint]
+    }
+}
+
+pub struct NavigationInstruction {
+    elements: Vec<NavigationChunkElement>,
+}
+
+impl NavigationInstruction {
+    pub fn new(elements: Vec<NavigationChunkElement>) -> Self {
+        NavigationInstruction { elements }
+    }
+
+    /// If the line is not corrupt, then returns Ok with a vec of elements that can complete the line.
+    /// If the line is corrupt, then returns Err with the type that caused the error.
+    pub fn complete(&self) -> Result<Vec<NavigationChunkType>, NavigationChunkType> {
+        let mut stack: Vec<NavigationChunkType> = Vec::new();
+        for element in &self.elements {
+            match element {
+                NavigationChunkElement::Open(subtype) => stack.push(*subtype),
+                NavigationChunkElement::Close(subtype) => {
+                    // A close element with nothing at all to pair it with is just as corrupt as one that pairs with the wrong type.
+                    if stack.pop() != Some(*subtype) {
+                        return Err(*subtype);
+                    }
+                }
+            }
+        }
+        stack.reverse();
+        Ok(stack)
+    }
+}
+
+impl FromStr for NavigationInstruction {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let elements = input
+            .char_indices()
+            .map(|(index, c)| {
+                NavigationChunkElement::try_from(c).map_err(|e| e.offset_columns(index))
+            })
+            .collect::<Result<Vec<NavigationChunkElement>, ParseError>>()?;
+        Ok(NavigationInstruction::new(elements))
+    }
+}
+
+pub enum NavigationChunkElement {
+    Open(NavigationChunkType),
+    Close(NavigationChunkType),
+}
+
+impl TryFrom<char> for NavigationChunkElement {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '{' | '(' | '[' | '<' => Ok(NavigationChunkElement::Open(value.try_into()?)),
+            '}' | ')' | ']' | '>' => Ok(NavigationChunkElement::Close(value.try_into()?)),
+            _ => Err(ParseError::new("Expected a bracket", &value.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum NavigationChunkType {
+    Curly,
+    Square,
+    Round,
+    Pointy, // yes they are called "pointy brackets"
+}
+
+impl NavigationChunkType {
+    pub fn get_corrupt_score(&self) -> u32 {
+        match self {
+            NavigationChunkType::Curly => 1197,
+            NavigationChunkType::Square => 57,
+            NavigationChunkType::Round => 3,
+            NavigationChunkType::Pointy => 25137,
+        }
+    }
+
+    pub fn get_incomplete_score(&self) -> u64 {
+        match self {
+            NavigationChunkType::Curly => 3,
+            NavigationChunkType::Square => 2,
+            NavigationChunkType::Round => 1,
+            NavigationChunkType::Pointy => 4,
+        }
+    }
+}
+
+impl TryFrom<char> for NavigationChunkType {
+    type Error = ParseError;
+
+    fn try_from(input: char) -> Result<Self, Self::Error> {
+        match input {
+            '{' | '}' => Ok(NavigationChunkType::Curly),
+            '[' | ']' => Ok(NavigationChunkType::Square),
+            '(' | ')' => Ok(NavigationChunkType::Round),
+            '<' | '>' => Ok(NavigationChunkType::Pointy),
+            _ => Err(ParseError::new("Expected a bracket", &input.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day10;
+    use aoc_core::harness::{assert_answers, assert_examples};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day10>();
+    }
+
+    #[test]
+    fn answers() {
+        assert_answers::<Day10>();
+    }
+}
@@ -0,0 +1,63 @@
+pub mod octomap;
+
+use aoc_core::error::ParseError;
+use aoc_core::intmap::IntMap;
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::solution::{Example, Solution};
+use octomap::OctoMap;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const EXAMPLES: &'static [Example] =
+        &[Example::both(include_str!("../example.txt"), "1656", "195")];
+    type Parsed = OctoMap;
+    type PartOne = u64;
+    type PartTwo = usize;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+        let map = IntMap::try_from(input.to_lines())?;
+        Ok(OctoMap::new(map))
+    }
+
+    /// Flashes after 100 steps.
+    fn part_one(map: &Self::Parsed) -> Self::PartOne {
+        let mut map = map.clone();
+        let mut flashes = 0u64;
+        for _ in 0..100 {
+            flashes += map.step();
+        }
+        flashes
+    }
+
+    /// The first step on which every octopus flashed.
+    fn part_two(map: &Self::Parsed) -> Self::PartTwo {
+        let mut map = map.clone();
+        let mut step = 0usize;
+        loop {
+            step += 1;
+            let step_flashes = map.step();
+            if step_flashes == map.get_size() as u64 {
+                break;
+            }
+        }
+        step
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day11;
+    use aoc_core::harness::{assert_answers, assert_examples};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day11>();
+    }
+
+    #[test]
+    fn answers() {
+        assert_answers::<Day11>();
+    }
+}
@@ -1,6 +1,7 @@
 use aoc_core::intmap::{IntMap, IntMapPoint};
 use std::collections::HashSet;
 
+#[derive(Debug, Clone)]
 pub struct OctoMap {
     map: IntMap,
 }
@@ -45,7 +46,7 @@ impl OctoMap {
             pass_flashes = 0;
             for octo in &octopoints {
                 // If the octopus already flashed in this invocation of flash, continue.
-                if did_flash.contains(&octo) {
+                if did_flash.contains(octo) {
                     continue;
                 }
                 let map_point = self.map.get_point(octo.0, octo.1);
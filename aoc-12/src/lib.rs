@@ -1,27 +1,41 @@
+use aoc_core::error::ParseError;
 use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::solution::{Example, Solution};
 use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Debug, Display, Formatter};
-use std::str::Lines;
-
-fn main() {
-    let input = PuzzleInput::new("aoc-12/input.txt");
-    let map = CaveMap::from(input.to_lines());
-    println!("--- Map of the caves ---\n{}", map);
-    let paths = map.get_paths(false);
-    println!(
-        "Number of unique paths, without revisiting any tiny caves: {:?}",
-        paths.len()
-    );
-    let paths = map.get_paths(true);
-    println!(
-        "Number of unique paths, allowing a revisit to a tiny cave one time: {:?}",
-        paths.len()
-    );
+use std::str::{FromStr, Lines};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const EXAMPLES: &'static [Example] = &[
+        Example::both(include_str!("../example-1.txt"), "10", "36"),
+        Example::both(include_str!("../example-2.txt"), "19", "103"),
+        Example::both(include_str!("../example-3.txt"), "226", "3509"),
+    ];
+    type Parsed = CaveMap;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+        CaveMap::try_from(input.to_lines())
+    }
+
+    /// Number of unique paths, without revisiting any tiny caves.
+    fn part_one(map: &Self::Parsed) -> Self::PartOne {
+        map.get_paths(false).len()
+    }
+
+    /// Number of unique paths, allowing a revisit to a tiny cave one time.
+    fn part_two(map: &Self::Parsed) -> Self::PartTwo {
+        map.get_paths(true).len()
+    }
 }
 
 #[derive(Debug)]
-struct CaveMap {
+pub struct CaveMap {
     map: HashMap<Cave, HashSet<Cave>>,
 }
 
@@ -30,6 +44,21 @@ impl CaveMap {
         CaveMap { map }
     }
 
+    fn parse_connection(line: &str) -> Result<(Cave, Cave), ParseError> {
+        let (lhs, rhs) = line
+            .split_once('-')
+            .ok_or_else(|| ParseError::new("Expected two caves separated by -", line))?;
+        let lhs: Cave = lhs.parse()?;
+        let rhs: Cave = rhs
+            .parse()
+            .map_err(|e: ParseError| e.located_in(line, rhs))?;
+        if let (Cave::Huge(_), Cave::Huge(_)) = (&lhs, &rhs) {
+            // We'd be able to bounce back and forth between these two forever!
+            return Err(ParseError::new("Two huge caves can't be connected", line));
+        }
+        Ok((lhs, rhs))
+    }
+
     pub fn get_paths(&self, allow_tinly_revisit: bool) -> HashSet<Vec<Cave>> {
         self.continue_path(vec![Cave::Start], allow_tinly_revisit)
     }
@@ -49,15 +78,12 @@ impl CaveMap {
                 continue;
             } else if let Cave::Tiny(_) = step {
                 if path.contains(step) {
-                    if allow_tiny_revisit == false {
+                    if !allow_tiny_revisit {
                         continue;
                     }
                     let has_existing_duplicate = path
                         .iter()
-                        .filter(|c| match c {
-                            Cave::Tiny(_) => true,
-                            _ => false,
-                        })
+                        .filter(|c| matches!(c, Cave::Tiny(_)))
                         .any(|c| path.iter().filter(|p| *p == c).count() > 1);
                     if has_existing_duplicate {
                         continue;
@@ -76,15 +102,15 @@ impl CaveMap {
 
 impl Display for CaveMap {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let mut names: Vec<Cave> = self.map.keys().map(|x| x.clone()).collect();
+        let mut names: Vec<Cave> = self.map.keys().cloned().collect();
         names.sort();
 
         for name in &names {
             f.write_str(format!("{} -> ", String::from(name)).as_str())?;
             let values = self
                 .map
-                .get(&name)
-                .expect(format!("No key in map for {}!", String::from(name)).as_str());
+                .get(name)
+                .unwrap_or_else(|| panic!("No key in map for {}!", String::from(name)));
             for (i, c) in values.iter().enumerate() {
                 if i != 0 {
                     f.write_str(" | ")?;
@@ -98,13 +124,13 @@ impl Display for CaveMap {
     }
 }
 
-impl<'a> From<Lines<'a>> for CaveMap {
-    fn from(input: Lines) -> Self {
+impl TryFrom<Lines<'_>> for CaveMap {
+    type Error = ParseError;
+
+    fn try_from(input: Lines<'_>) -> Result<Self, Self::Error> {
         let mut map = HashMap::new();
-        for line in input {
-            let mut split = line.split('-');
-            let lhs: Cave = split.next().expect("No first element in split!").into();
-            let rhs: Cave = split.next().expect("No second element in split!").into();
+        for (index, line) in input.enumerate() {
+            let (lhs, rhs) = CaveMap::parse_connection(line).map_err(|e| e.offset_lines(index))?;
 
             map.entry(lhs.clone())
                 .or_insert(HashSet::new())
@@ -112,12 +138,15 @@ impl<'a> From<Lines<'a>> for CaveMap {
             map.entry(rhs).or_insert(HashSet::new()).insert(lhs);
         }
 
-        CaveMap::new(map)
+        if !map.contains_key(&Cave::Start) || !map.contains_key(&Cave::End) {
+            return Err(ParseError::new("Expected caves named start and end", ""));
+        }
+        Ok(CaveMap::new(map))
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
-enum Cave {
+pub enum Cave {
     Start,
     End,
     Tiny(String),
@@ -154,16 +183,25 @@ impl Ord for Cave {
     }
 }
 
-impl From<&str> for Cave {
-    fn from(input: &str) -> Self {
+impl FromStr for Cave {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
         if input.eq("start") {
-            Cave::Start
+            Ok(Cave::Start)
         } else if input.eq("end") {
-            Cave::End
-        } else if input.chars().next().expect("Empty string!").is_lowercase() {
-            Cave::Tiny(input.to_string())
+            Ok(Cave::End)
+        } else if input.is_empty() || !input.chars().all(|c| c.is_ascii_alphabetic()) {
+            Err(ParseError::new("Expected the name of a cave", input))
+        } else if input.chars().all(|c| c.is_ascii_lowercase()) {
+            Ok(Cave::Tiny(input.to_string()))
+        } else if input.chars().all(|c| c.is_ascii_uppercase()) {
+            Ok(Cave::Huge(input.to_string()))
         } else {
-            Cave::Huge(input.to_string())
+            Err(ParseError::new(
+                "Caves must be all lowercase or all uppercase",
+                input,
+            ))
         }
     }
 }
@@ -181,8 +219,10 @@ impl From<&Cave> for String {
 
 #[cfg(test)]
 mod tests {
-    use crate::Cave;
+    use crate::{Cave, CaveMap, Day12};
+    use aoc_core::harness::{assert_answers, assert_examples};
     use std::collections::HashSet;
+    use std::str::FromStr;
 
     #[test]
     fn cave_eq() {
@@ -208,17 +248,30 @@ mod tests {
 
     #[test]
     fn cave_from_str() {
-        let cave_start = Cave::from("start");
+        let cave_start = Cave::from_str("start").unwrap();
         assert_eq!(cave_start, Cave::Start);
 
-        let cave_end = Cave::from("end");
+        let cave_end = Cave::from_str("end").unwrap();
         assert_eq!(cave_end, Cave::End);
 
-        let cave_tiny = Cave::from("ab");
+        let cave_tiny = Cave::from_str("ab").unwrap();
         assert_eq!(cave_tiny, Cave::Tiny("ab".to_string()));
 
-        let cave_huge = Cave::from("AB");
+        let cave_huge = Cave::from_str("AB").unwrap();
         assert_eq!(cave_huge, Cave::Huge("AB".to_string()));
+
+        assert!(Cave::from_str("").is_err());
+        assert!(Cave::from_str("aB").is_err());
+        assert!(Cave::from_str("a-b").is_err());
+    }
+
+    #[test]
+    fn cave_map_from_invalid_lines() {
+        let error = CaveMap::try_from("start-A\nA-b\nb-".lines()).unwrap_err();
+        assert_eq!((error.get_line(), error.get_column()), (3, 3));
+
+        let error = CaveMap::try_from("start-A\nA-B".lines()).unwrap_err();
+        assert_eq!(error.get_line(), 2);
     }
 
     #[test]
@@ -261,4 +314,14 @@ mod tests {
         vec_set.insert(vec_5.clone());
         assert_eq!(vec_set.len(), 2);
     }
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day12>();
+    }
+
+    #[test]
+    fn answers() {
+        assert_answers::<Day12>();
+    }
 }
@@ -0,0 +1,176 @@
+use aoc_core::error::ParseError;
+use aoc_core::pattern::Pattern;
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::solution::{Example, Solution};
+use aoc_core::sparse_grid::SparseGrid;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const EXAMPLES: &'static [Example] = &[Example::both(
+        include_str!("../example.txt"),
+        "17",
+        "█████ \n█   █ \n█   █ \n█   █ \n█████ \n      \n",
+    )];
+    type Parsed = DotSheet;
+    type PartOne = usize;
+    type PartTwo = String;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+        DotSheet::try_from(input)
+    }
+
+    /// Dots visible after the first fold.
+    fn part_one(sheet: &Self::Parsed) -> Self::PartOne {
+        let mut sheet = sheet.clone();
+        sheet.fold_next();
+        sheet.get_dots()
+    }
+
+    /// The dot sheet after every fold, which should spell out the code.
+    fn part_two(sheet: &Self::Parsed) -> Self::PartTwo {
+        let mut sheet = sheet.clone();
+        while sheet.get_next_fold().is_some() {
+            sheet.fold_next();
+        }
+        sheet.to_string()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DotSheet {
+    /// Every point on the sheet, where `true` is a dot.
+    dots: SparseGrid<bool>,
+    folds: Vec<DotSheetFold>,
+    folds_completed: usize,
+}
+
+impl DotSheet {
+    pub fn new(dots: SparseGrid<bool>, folds: Vec<DotSheetFold>) -> Self {
+        DotSheet {
+            dots,
+            folds,
+            folds_completed: 0,
+        }
+    }
+
+    pub fn fold_next(&mut self) -> Option<DotSheetFold> {
+        let next_fold = *self.get_next_fold()?;
+        match &next_fold {
+            DotSheetFold::Up(line) => self.fold_up(*line),
+            DotSheetFold::Left(line) => self.fold_left(*line),
+        };
+
+        self.folds_completed += 1;
+        Some(next_fold)
+    }
+
+    pub fn get_next_fold(&self) -> Option<&DotSheetFold> {
+        self.folds.get(self.folds_completed)
+    }
+
+    pub fn get_dots(&self) -> usize {
+        self.dots.len()
+    }
+
+    fn fold_up(&mut self, line: u32) {
+        self.fold(|(x, y)| (x, y.min(2 * line as i64 - y)));
+    }
+
+    fn fold_left(&mut self, line: u32) {
+        self.fold(|(x, y)| (x.min(2 * line as i64 - x), y));
+    }
+
+    /// Moves every dot to wherever `reflect` puts it. Dots that land on top of each other merge into one.
+    fn fold<F: Fn((i64, i64)) -> (i64, i64)>(&mut self, reflect: F) {
+        let mut folded = SparseGrid::new(false);
+        for (dot, _) in self.dots.iter() {
+            folded.set(reflect(dot), true);
+        }
+        self.dots = folded;
+    }
+}
+
+impl Display for DotSheet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (_, (x_max, y_max)) = self.dots.get_bounds().unwrap_or(((0, 0), (0, 0)));
+
+        // Leave a blank row and column around the far edges, so the last letter isn't squished up against them.
+        for y in 0..=y_max + 1 {
+            for x in 0..=x_max + 1 {
+                let char = if *self.dots.get((x, y)) { '█' } else { ' ' };
+                f.write_str(char.to_string().as_str())?;
+            }
+            f.write_str("\n")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<&PuzzleInput> for DotSheet {
+    type Error = ParseError;
+
+    /// Parses the dots, then the folds, with a blank line between them.
+    fn try_from(input: &PuzzleInput) -> Result<Self, Self::Error> {
+        let mut sections = input.sections();
+        let dot_section = sections
+            .next()
+            .ok_or_else(|| ParseError::new("Expected a list of dots", ""))?;
+        let mut dots = SparseGrid::new(false);
+        for (x, y) in Pattern::new("{},{}")
+            .parse_lines::<(i64, i64), _>(dot_section.to_lines())
+            .map_err(|e| dot_section.locate(e))?
+        {
+            dots.set((x, y), true);
+        }
+        let folds = match sections.next() {
+            Some(fold_section) => fold_section.parse_lines()?,
+            None => Vec::new(),
+        };
+
+        Ok(DotSheet::new(dots, folds))
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DotSheetFold {
+    Left(u32),
+    Up(u32),
+}
+
+impl FromStr for DotSheetFold {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (axis, line): (char, u32) = Pattern::new("fold along {}={}").parse(input)?;
+        match axis {
+            'x' => Ok(DotSheetFold::Left(line)),
+            'y' => Ok(DotSheetFold::Up(line)),
+            // The axis always comes right after "fold along ".
+            _ => Err(
+                ParseError::new("Expected to fold along x or y", &axis.to_string())
+                    .offset_columns(11),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day13;
+    use aoc_core::harness::{assert_answers, assert_examples};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day13>();
+    }
+
+    #[test]
+    fn answers() {
+        assert_answers::<Day13>();
+    }
+}
@@ -1,3 +1,4 @@
+use aoc_core::error::ParseError;
 use std::fmt::{Debug, Display, Formatter};
 
 #[derive(Clone, Copy, Eq, PartialEq, Hash)]
@@ -14,9 +15,11 @@ pub enum Element {
     V,
 }
 
-impl From<char> for Element {
-    fn from(input: char) -> Self {
-        match input {
+impl TryFrom<char> for Element {
+    type Error = ParseError;
+
+    fn try_from(input: char) -> Result<Self, Self::Error> {
+        let element = match input {
             'B' => Element::B,
             'C' => Element::C,
             'F' => Element::F,
@@ -27,8 +30,9 @@ impl From<char> for Element {
             'P' => Element::P,
             'S' => Element::S,
             'V' => Element::V,
-            _ => panic!("{} is not a known element!", input),
-        }
+            _ => return Err(ParseError::new("Not a known element", &input.to_string())),
+        };
+        Ok(element)
     }
 }
 
@@ -0,0 +1,62 @@
+pub mod element;
+pub mod element_pair;
+pub mod polymer_template;
+
+use crate::polymer_template::PolymerTemplate;
+use aoc_core::error::ParseError;
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::solution::{Example, Solution};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const EXAMPLES: &'static [Example] = &[Example::both(
+        include_str!("../example.txt"),
+        "1588",
+        "2188189693529",
+    )];
+    type Parsed = PolymerTemplate;
+    type PartOne = u128;
+    type PartTwo = u128;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+        PolymerTemplate::try_from(input.to_lines())
+    }
+
+    /// Difference between the most and least common elements after 10 steps.
+    fn part_one(polymer: &Self::Parsed) -> Self::PartOne {
+        common_difference_after(polymer, 10)
+    }
+
+    /// Difference between the most and least common elements after 40 steps.
+    fn part_two(polymer: &Self::Parsed) -> Self::PartTwo {
+        common_difference_after(polymer, 40)
+    }
+}
+
+fn common_difference_after(polymer: &PolymerTemplate, steps: u8) -> u128 {
+    let mut polymer = polymer.clone();
+    for _ in 0..steps {
+        polymer.step();
+    }
+    let least_common = polymer.get_least_common().expect("No elements at all!");
+    let most_common = polymer.get_most_common().expect("No elements at all!");
+    most_common.1 - least_common.1
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day14;
+    use aoc_core::harness::{assert_answers, assert_examples};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day14>();
+    }
+
+    #[test]
+    fn answers() {
+        assert_answers::<Day14>();
+    }
+}
@@ -1,5 +1,6 @@
 use crate::element::Element;
 use crate::element_pair::ElementPair;
+use aoc_core::error::ParseError;
 use std::collections::HashMap;
 use std::str::Lines;
 
@@ -7,7 +8,7 @@ use std::str::Lines;
 ///
 /// Internally, we don't actually care about the order of elements, we're only interested in keeping track of the number of each element,
 /// and the element is appears adjacent to, since pairs of elements determine how we insert new elements.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct PolymerTemplate {
     /// A map of element pairs, where the value is the number of times that pair occurs in the chain.
     pairs: HashMap<ElementPair, u128>,
@@ -56,9 +57,8 @@ impl PolymerTemplate {
     pub fn step(&mut self) {
         let pairs = self.pairs.clone(); // Make a copy, we want a snapshot of our counts at the beginning of the step.
         for (pair, count) in pairs {
-            let insert_el = self.rules.get(&pair);
-            if insert_el.is_some() {
-                let (left, right) = insert_el.unwrap().to_owned();
+            if let Some(insert_el) = self.rules.get(&pair) {
+                let (left, right) = insert_el.to_owned();
                 *self.pairs.entry(pair).or_insert(0) -= count;
                 *self.pairs.entry(left).or_insert(0) += count;
                 *self.pairs.entry(right).or_insert(0) += count;
@@ -66,6 +66,37 @@ impl PolymerTemplate {
         }
     }
 
+    fn parse_elements(input: &str) -> Result<Vec<Element>, ParseError> {
+        input
+            .char_indices()
+            .map(|(index, c)| Element::try_from(c).map_err(|e| e.offset_columns(index)))
+            .collect()
+    }
+
+    /// Parses a pair insertion rule, like `CH -> B`.
+    fn parse_rule(line: &str) -> Result<(ElementPair, Element), ParseError> {
+        let (pair, insert) = line
+            .split_once(" -> ")
+            .ok_or_else(|| ParseError::new("Expected a rule like AB -> C", line))?;
+        let pair = match PolymerTemplate::parse_elements(pair)?[..] {
+            [lhs, rhs] => ElementPair::new(lhs, rhs),
+            _ => return Err(ParseError::new("Expected a pair of elements", pair)),
+        };
+        let insert_el = match PolymerTemplate::parse_elements(insert)
+            .map_err(|e| e.located_in(line, insert))?[..]
+        {
+            [el] => el,
+            _ => {
+                return Err(ParseError::within(
+                    "Expected a single element",
+                    line,
+                    insert,
+                ))
+            }
+        };
+        Ok((pair, insert_el))
+    }
+
     /// Counts the number of each element in the polymer chain.
     ///
     /// An element's occurrences can be counted by taking the number of pairs the element appears in (lhs == rhs are counted twice),
@@ -76,7 +107,7 @@ impl PolymerTemplate {
             *result.entry(el.get_lhs()).or_insert(0) += count;
             *result.entry(el.get_rhs()).or_insert(0) += count;
         }
-        let elements: Vec<Element> = result.keys().map(|key| *key).collect();
+        let elements: Vec<Element> = result.keys().copied().collect();
         for el in elements {
             // It's possible for there to be an odd number of occurrences if the initial chain starts with that, so round up after dividing.
             let el_mod = result[&el] % 2;
@@ -87,16 +118,15 @@ impl PolymerTemplate {
     }
 }
 
-impl<'a> From<Lines<'a>> for PolymerTemplate {
-    fn from(input: Lines<'a>) -> Self {
-        let mut lines = input.into_iter();
+impl TryFrom<Lines<'_>> for PolymerTemplate {
+    type Error = ParseError;
+
+    fn try_from(mut lines: Lines<'_>) -> Result<Self, Self::Error> {
         let mut pairs = HashMap::new();
-        let pairs_vec: Vec<Element> = lines
+        let first = lines
             .next()
-            .expect("No first line!")
-            .chars()
-            .map(|c| Element::from(c))
-            .collect();
+            .ok_or_else(|| ParseError::new("Expected a polymer template", ""))?;
+        let pairs_vec = PolymerTemplate::parse_elements(first)?;
         for pair in pairs_vec.windows(2) {
             let pair = ElementPair::new(pair[0], pair[1]);
             *pairs.entry(pair).or_insert(0u128) += 1;
@@ -104,20 +134,9 @@ impl<'a> From<Lines<'a>> for PolymerTemplate {
         lines.next();
 
         let mut rules = HashMap::new();
-        for line in lines {
-            let mut split = line.split(" -> ");
-            let mut pair_chars = split.next().expect("No first element!").chars();
-            let pair = ElementPair::new(
-                pair_chars.next().expect("No first char in pair!").into(),
-                pair_chars.next().expect("No second char in pair!").into(),
-            );
-            let insert_el: Element = split
-                .next()
-                .expect("No second element!")
-                .chars()
-                .next()
-                .expect("No char!")
-                .into();
+        for (index, line) in lines.enumerate() {
+            let (pair, insert_el) =
+                PolymerTemplate::parse_rule(line).map_err(|e| e.offset_lines(index + 2))?;
             let insert = (
                 ElementPair::new(pair.get_lhs(), insert_el),
                 ElementPair::new(insert_el, pair.get_rhs()),
@@ -125,8 +144,6 @@ impl<'a> From<Lines<'a>> for PolymerTemplate {
             rules.insert(pair, insert);
         }
 
-        println!("{:?}\n{:?}", pairs, rules);
-
-        PolymerTemplate::new(pairs, rules)
+        Ok(PolymerTemplate::new(pairs, rules))
     }
 }
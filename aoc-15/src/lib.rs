@@ -0,0 +1,54 @@
+pub mod movement;
+pub mod risk_map;
+pub mod risk_path;
+
+use crate::risk_map::RiskMap;
+use aoc_core::error::ParseError;
+use aoc_core::intmap::IntMap;
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::solution::{Example, Solution};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const EXAMPLES: &'static [Example] =
+        &[Example::both(include_str!("../example.txt"), "40", "315")];
+    type Parsed = RiskMap;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+        let map = IntMap::try_from(input.to_lines())?;
+        Ok(RiskMap::new(map))
+    }
+
+    /// Risk score of the least risky path.
+    fn part_one(map: &Self::Parsed) -> Self::PartOne {
+        map.get_path().expect("No value returned!").get_risk()
+    }
+
+    /// Risk score of the least risky path through the map tiled 5 times in each direction.
+    fn part_two(map: &Self::Parsed) -> Self::PartTwo {
+        map.expanded(5)
+            .get_path()
+            .expect("No path found!")
+            .get_risk()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day15;
+    use aoc_core::harness::{assert_answers, assert_examples};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day15>();
+    }
+
+    #[test]
+    fn answers() {
+        assert_answers::<Day15>();
+    }
+}
@@ -0,0 +1,40 @@
+use aoc_core::grid::{Adjacency, Tiled};
+use aoc_core::intmap::IntMapPoint;
+
+/// Works out the cost of stepping from one point to another, or `None` if the step isn't allowed.
+pub type StepCost = Box<dyn Fn(&IntMapPoint, &IntMapPoint) -> Option<u64>>;
+
+/// The ways it's possible to move around a risk map.
+pub enum Movement {
+    /// Left, right, up and down. Each step costs the risk of the point entered.
+    Cardinal,
+    /// To any of the eight surrounding points. Each step costs the risk of the point entered.
+    EightWay,
+    /// To any of the given surrounding points, where each step costs whatever the function says it does.
+    Custom(Adjacency, StepCost),
+}
+
+impl Movement {
+    /// Returns every point that can be stepped to from `point`, and what it costs to step there.
+    pub fn get_steps<F>(&self, map: &Tiled<u8, F>, point: &IntMapPoint) -> Vec<(IntMapPoint, u64)>
+    where
+        F: Fn(&u8, usize, usize) -> u8,
+    {
+        let adjacency = match self {
+            Movement::Cardinal => Adjacency::Cardinal,
+            Movement::EightWay => Adjacency::All,
+            Movement::Custom(adjacency, _) => *adjacency,
+        };
+        let points = map.get_adjacent_points_with(point.0, point.1, adjacency);
+        match self {
+            Movement::Custom(_, cost) => points
+                .into_iter()
+                .filter_map(|next| cost(point, &next).map(|cost| (next, cost)))
+                .collect(),
+            _ => points
+                .into_iter()
+                .map(|next| (next, next.2 as u64))
+                .collect(),
+        }
+    }
+}
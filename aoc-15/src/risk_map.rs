@@ -1,61 +1,281 @@
-use crate::risk_map_point::RiskMapPoint;
+use crate::movement::Movement;
+use crate::risk_path::RiskPath;
+use aoc_core::grid::{Grid, Tiled};
 use aoc_core::intmap::{IntMap, IntMapPoint};
-use std::collections::{BinaryHeap, HashMap, HashSet};
+use aoc_core::search::{dijkstra, dijkstra_all};
 
-#[derive(Debug)]
+/// How the risks of a tile change depending on where the tile is in the full map.
+type RiskTransform = fn(&u8, usize, usize) -> u8;
+
+#[derive(Debug, Clone)]
 pub struct RiskMap {
-    pub risks: IntMap,
+    /// The risks of a single tile of the map.
+    risks: IntMap,
+    /// How many times the tile repeats across and down to make the full map.
+    tiles: usize,
 }
 
 impl RiskMap {
     pub fn new(risks: IntMap) -> Self {
-        RiskMap { risks }
-    }
-
-    pub fn get_path(&self) -> Option<u64> {
-        let mut distances = self.make_distances();
-        let mut visited: HashSet<IntMapPoint> = HashSet::new();
-        let mut queue: BinaryHeap<RiskMapPoint> = BinaryHeap::new();
-        let first_point = self.risks.get_point(0, 0);
-        let first = RiskMapPoint::new(first_point, distances[&first_point]);
-        queue.push(first);
-
-        while let Some(risk_point) = queue.pop() {
-            if risk_point.point.0 == self.risks.get_width() - 1
-                && risk_point.point.1 == self.risks.get_height() - 1
-            {
-                return Some(risk_point.risk);
-            }
+        RiskMap { risks, tiles: 1 }
+    }
 
-            if visited.contains(&risk_point.point) || risk_point.risk > distances[&risk_point.point]
-            {
-                continue;
-            }
+    /// Returns the full map, made by repeating this one `n` times across and `n` times down.
+    /// Each repeat is a little riskier than the one above it or to the left of it, see [`increase_risk`].
+    ///
+    /// The full map is never stored, so this is cheap even for very large `n`.
+    pub fn expanded(&self, n: usize) -> Self {
+        let risks = match self.tiles {
+            1 => self.risks.clone(),
+            // Each repeat of an expanded map is riskier as a whole, so it has to become a single tile first.
+            _ => self.get_view().to_grid(),
+        };
+        RiskMap { risks, tiles: n }
+    }
 
-            let adj_points_vec = self
-                .risks
-                .get_adjacent_points_cardinal(risk_point.point.0, risk_point.point.1);
-            let adj_points = adj_points_vec.iter().filter(|x| !visited.contains(x));
-            for adj in adj_points {
-                let next = RiskMapPoint::new(*adj, risk_point.risk + adj.2 as u64);
-                if next.risk < distances[&next.point] {
-                    distances.insert(next.point, next.risk);
-                    queue.push(next);
-                }
-            }
+    /// Returns the horizontal size of the full map (not the last index).
+    pub fn get_width(&self) -> usize {
+        self.get_view().get_width()
+    }
 
-            visited.insert(risk_point.point);
-        }
+    /// Returns the vertical size of the full map (not the last index).
+    pub fn get_height(&self) -> usize {
+        self.get_view().get_height()
+    }
+
+    /// Returns a specific point in the full map. Points are 0-indexed.
+    ///
+    /// Panics if a provided x or y is outside the range of the map.
+    pub fn get_point(&self, x: usize, y: usize) -> IntMapPoint {
+        self.get_view().get_point(x, y)
+    }
+
+    /// Returns the least risky path from the top-left corner to the bottom-right corner, moving in cardinal directions.
+    /// The risk of the starting point isn't counted, since we never enter it. Returns `None` if the map is empty.
+    pub fn get_path(&self) -> Option<RiskPath<'_>> {
+        let goal = (
+            self.get_width().checked_sub(1)?,
+            self.get_height().checked_sub(1)?,
+        );
+        self.find_path((0, 0), &[goal], &Movement::Cardinal)
+    }
 
-        None
+    /// Returns the least risky path from `start` to whichever of the `goals` is the least risky to get to, or `None` if none of them can be reached.
+    ///
+    /// Panics if `start` is outside of the map.
+    pub fn find_path(
+        &self,
+        start: (usize, usize),
+        goals: &[(usize, usize)],
+        movement: &Movement,
+    ) -> Option<RiskPath<'_>> {
+        let view = self.get_view();
+        let start = view.get_point(start.0, start.1);
+        let neighbours = |point: &IntMapPoint| movement.get_steps(&view, point);
+        dijkstra(start, neighbours, |(x, y, _)| goals.contains(&(*x, *y)))
+            .map(|result| RiskPath::new(self, result.cost, result.path))
     }
 
-    fn make_distances(&self) -> HashMap<IntMapPoint, u64> {
-        let mut distances = HashMap::new();
-        for risk in self.risks.iter_points() {
-            distances.insert(risk, u64::MAX);
+    /// Returns a map of the lowest total risk of getting from `start` to every point, or `None` for points that can't be reached.
+    ///
+    /// Panics if `start` is outside of the map.
+    pub fn get_distances(&self, start: (usize, usize), movement: &Movement) -> Grid<Option<u64>> {
+        let view = self.get_view();
+        let start = view.get_point(start.0, start.1);
+        let neighbours = |point: &IntMapPoint| movement.get_steps(&view, point);
+        let mut distances = Grid::filled(view.get_width(), view.get_height(), None);
+        for ((x, y, _), risk) in dijkstra_all(start, neighbours) {
+            distances.set(x, y, Some(risk));
         }
-        distances.insert(self.risks.get_point(0, 0), 0);
         distances
     }
+
+    /// Returns the distances from every point to every other point, where `result[(x, y)]` is [`RiskMap::get_distances`] from `(x, y)`.
+    ///
+    /// This is a search from every single point, so it is only practical for small maps.
+    pub fn get_all_distances(&self, movement: &Movement) -> Grid<Grid<Option<u64>>> {
+        let rows = (0..self.get_height())
+            .map(|y| {
+                (0..self.get_width())
+                    .map(|x| self.get_distances((x, y), movement))
+                    .collect()
+            })
+            .collect();
+        Grid::new(rows)
+    }
+
+    fn get_view(&self) -> Tiled<'_, u8, RiskTransform> {
+        self.risks.tiled(self.tiles, self.tiles, increase_risk)
+    }
+}
+
+/// Each tile is 1 riskier than the tile to the left of it, and 1 riskier than the tile above it.
+/// Risks above 9 wrap back around to 1.
+fn increase_risk(risk: &u8, tile_x: usize, tile_y: usize) -> u8 {
+    let value = *risk as usize + tile_x + tile_y;
+    match value {
+        0..=9 => value as u8,
+        _ => ((value - 1) % 9 + 1) as u8,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::movement::Movement;
+    use crate::risk_map::RiskMap;
+    use aoc_core::grid::Adjacency;
+    use aoc_core::intmap::IntMap;
+
+    const EXAMPLE: &str = "1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581";
+
+    #[test]
+    fn get_path() {
+        let map = RiskMap::new(IntMap::try_from(EXAMPLE.lines()).unwrap());
+        let path = map.get_path().unwrap();
+        assert_eq!(path.get_risk(), 40);
+        let points = path.get_points();
+        assert_eq!(points.len(), 19);
+        assert_eq!(points.first(), Some(&(0, 0, 1)));
+        assert_eq!(points.last(), Some(&(9, 9, 1)));
+        let risk: u64 = points.iter().skip(1).map(|point| point.2 as u64).sum();
+        assert_eq!(risk, 40);
+        // Every step is to a neighbouring point.
+        for step in points.windows(2) {
+            assert_eq!(
+                step[0].0.abs_diff(step[1].0) + step[0].1.abs_diff(step[1].1),
+                1
+            );
+        }
+    }
+
+    #[test]
+    fn display_path() {
+        let map = RiskMap::new(IntMap::try_from(EXAMPLE.lines()).unwrap());
+        let path = map.get_path().unwrap();
+        // Without the highlighting, it's just the map.
+        assert_eq!(path.render(false), EXAMPLE);
+        // Highlighting only wraps the digits along the path in escape codes, whether or not they end up colored.
+        assert_eq!(strip_escapes(&path.to_string()), EXAMPLE);
+    }
+
+    fn strip_escapes(text: &str) -> String {
+        let mut out = String::new();
+        let mut chars = text.chars();
+        while let Some(char) = chars.next() {
+            if char == '\x1b' {
+                chars.by_ref().find(|&char| char == 'm');
+            } else {
+                out.push(char);
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn get_path_empty() {
+        assert!(RiskMap::new(IntMap::new(vec![])).get_path().is_none());
+        assert!(RiskMap::new(IntMap::new(vec![vec![]])).get_path().is_none());
+        let map = RiskMap::new(IntMap::try_from("5".lines()).unwrap());
+        assert_eq!(map.get_path().unwrap().get_points(), &[(0, 0, 5)]);
+    }
+
+    #[test]
+    fn expanded() {
+        let map = RiskMap::new(IntMap::try_from(EXAMPLE.lines()).unwrap()).expanded(5);
+        assert_eq!((map.get_width(), map.get_height()), (50, 50));
+        assert_eq!(map.get_point(0, 0).2, 1);
+        assert_eq!(map.get_point(10, 0).2, 2);
+        assert_eq!(map.get_point(49, 49).2, 9);
+        // 7 + 4 tiles across wraps back around to 2.
+        assert_eq!(map.get_point(44, 0).2, 2);
+        assert_eq!(map.get_path().unwrap().get_risk(), 315);
+        assert!(map.expanded(0).get_path().is_none());
+    }
+
+    #[test]
+    fn expanded_rectangle() {
+        let map = RiskMap::new(IntMap::try_from("19\n98\n11".lines()).unwrap()).expanded(2);
+        assert_eq!((map.get_width(), map.get_height()), (4, 6));
+        assert_eq!(map.get_point(3, 0).2, 1);
+        assert_eq!(map.get_point(3, 5).2, 3);
+        // Expanding again works on the whole expanded map.
+        let map = map.expanded(2);
+        assert_eq!((map.get_width(), map.get_height()), (8, 12));
+        assert_eq!(map.get_point(7, 11).2, 5);
+    }
+
+    #[test]
+    fn find_path() {
+        let map = RiskMap::new(IntMap::try_from(EXAMPLE.lines()).unwrap());
+        // Going backwards, the risk of the bottom-right corner isn't counted but the top-left corner's is, and they're both 1.
+        let path = map
+            .find_path((9, 9), &[(0, 0)], &Movement::Cardinal)
+            .unwrap();
+        assert_eq!(path.get_risk(), 40);
+
+        // The closest goal wins.
+        let path = map
+            .find_path((0, 0), &[(9, 9), (0, 2)], &Movement::Cardinal)
+            .unwrap();
+        assert_eq!(path.get_points().last(), Some(&(0, 2, 2)));
+        assert_eq!(path.get_risk(), 3);
+
+        let path = map
+            .find_path((0, 0), &[(9, 9)], &Movement::EightWay)
+            .unwrap();
+        assert!(path.get_risk() < 40);
+        assert!(path.get_points().len() < 19);
+
+        assert!(map
+            .find_path((0, 0), &[(10, 10)], &Movement::Cardinal)
+            .is_none());
+    }
+
+    #[test]
+    fn find_path_custom() {
+        let map = RiskMap::new(IntMap::try_from(EXAMPLE.lines()).unwrap());
+        // Every step costs the same, as long as it's never onto a 9.
+        let movement = Movement::Custom(
+            Adjacency::Cardinal,
+            Box::new(|_, to| if to.2 == 9 { None } else { Some(1) }),
+        );
+        let path = map.find_path((0, 0), &[(9, 9)], &movement).unwrap();
+        assert_eq!(path.get_risk(), 18);
+        assert!(path.get_points().iter().all(|point| point.2 != 9));
+
+        // Surround the start with walls.
+        let movement = Movement::Custom(Adjacency::All, Box::new(|_, _| None));
+        assert!(map.find_path((0, 0), &[(9, 9)], &movement).is_none());
+    }
+
+    #[test]
+    fn distances() {
+        let map = RiskMap::new(IntMap::try_from("19\n11".lines()).unwrap());
+        let distances = map.get_distances((0, 0), &Movement::Cardinal);
+        assert_eq!(distances[(0, 0)], Some(0));
+        assert_eq!(distances[(1, 0)], Some(9));
+        assert_eq!(distances[(1, 1)], Some(2));
+
+        let movement = Movement::Custom(
+            Adjacency::Cardinal,
+            Box::new(|_, to| (to.2 != 9).then_some(1)),
+        );
+        let distances = map.get_distances((0, 0), &movement);
+        assert_eq!(distances[(1, 0)], None);
+
+        let all = map.get_all_distances(&Movement::Cardinal);
+        assert_eq!((all.get_width(), all.get_height()), (2, 2));
+        assert_eq!(all[(1, 1)][(0, 0)], Some(2));
+        assert_eq!(all[(1, 0)][(1, 0)], Some(0));
+        assert_eq!(all[(1, 0)][(0, 1)], Some(2));
+    }
 }
@@ -0,0 +1,59 @@
+use crate::risk_map::RiskMap;
+use aoc_core::intmap::IntMapPoint;
+use colored::*;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+
+/// A way across a risk map, and the total risk of taking it.
+#[derive(Debug)]
+pub struct RiskPath<'a> {
+    map: &'a RiskMap,
+    risk: u64,
+    points: Vec<IntMapPoint>,
+}
+
+impl<'a> RiskPath<'a> {
+    pub fn new(map: &'a RiskMap, risk: u64, points: Vec<IntMapPoint>) -> Self {
+        RiskPath { map, risk, points }
+    }
+
+    /// Returns the total risk of every point entered along the path.
+    pub fn get_risk(&self) -> u64 {
+        self.risk
+    }
+
+    /// Returns every point along the path, in order, including the start and the goal.
+    pub fn get_points(&self) -> &[IntMapPoint] {
+        &self.points
+    }
+
+    /// Draws the whole map, highlighting the points along the path if `highlight` is set.
+    ///
+    /// Highlighting still respects whether [`colored`] is allowed to colorize output at all.
+    pub fn render(&self, highlight: bool) -> String {
+        let on_path: HashSet<&IntMapPoint> = self.points.iter().collect();
+        let mut out = String::new();
+        for y in 0..self.map.get_height() {
+            if y > 0 {
+                out.push('\n');
+            }
+            for x in 0..self.map.get_width() {
+                let point = self.map.get_point(x, y);
+                let char_str = point.2.to_string();
+                if highlight && on_path.contains(&point) {
+                    out.push_str(&char_str.bold().red().on_bright_white().to_string());
+                } else {
+                    out.push_str(&char_str);
+                }
+            }
+        }
+        out
+    }
+}
+
+/// Draws the whole map, with the points along the path highlighted.
+impl Display for RiskPath<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.render(true))
+    }
+}
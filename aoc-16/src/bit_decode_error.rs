@@ -0,0 +1,174 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+
+/// Why a transmission couldn't be decoded, where in it that happened, and which packet was being decoded at the time.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct BitDecodeError {
+    kind: BitDecodeErrorKind,
+    bit: usize,
+    /// The index of each sub-packet on the way from the outermost packet down to the one that couldn't be decoded.
+    path: Vec<usize>,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum BitDecodeErrorKind {
+    /// The transmission ended in the middle of a packet.
+    UnexpectedEnd,
+    /// A type ID that isn't an operator.
+    InvalidOperator(u8),
+    /// An operator's sub-packets didn't add up to the number of bits it said they would.
+    LengthMismatch { expected: usize, actual: usize },
+    /// There were bits set after the outermost packet, where there should only be zeroes.
+    NonZeroPadding,
+    /// A character in a stream of hex digits that isn't one.
+    InvalidHexDigit(char),
+    /// The reader a stream of hex digits came from failed.
+    ReadFailed(io::ErrorKind),
+}
+
+impl BitDecodeError {
+    pub fn new(kind: BitDecodeErrorKind, bit: usize) -> Self {
+        BitDecodeError {
+            kind,
+            bit,
+            path: Vec::new(),
+        }
+    }
+
+    /// Records that the error happened inside of the `index`th sub-packet of the packet being decoded.
+    pub fn within_sub_packet(mut self, index: usize) -> Self {
+        self.path.insert(0, index);
+        self
+    }
+
+    pub fn get_kind(&self) -> BitDecodeErrorKind {
+        self.kind
+    }
+
+    /// Returns the offset of the first bit that couldn't be decoded, from the start of the transmission.
+    pub fn get_bit(&self) -> usize {
+        self.bit
+    }
+
+    /// Returns the index of each sub-packet from the outermost packet down, which is empty if it's the outermost packet.
+    pub fn get_path(&self) -> &[usize] {
+        &self.path
+    }
+}
+
+impl Display for BitDecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            BitDecodeErrorKind::UnexpectedEnd => f.write_str("Transmission ended early")?,
+            BitDecodeErrorKind::InvalidOperator(type_id) => {
+                write!(f, "Invalid operator type {}", type_id)?
+            }
+            BitDecodeErrorKind::LengthMismatch { expected, actual } => write!(
+                f,
+                "Expected sub-packets to be {} bits long, got: {}",
+                expected, actual
+            )?,
+            BitDecodeErrorKind::NonZeroPadding => f.write_str("Padding has bits set")?,
+            BitDecodeErrorKind::InvalidHexDigit(c) => write!(f, "Invalid hex digit {:?}", c)?,
+            BitDecodeErrorKind::ReadFailed(kind) => write!(f, "Failed to read ({})", kind)?,
+        }
+        write!(f, " at bit {}", self.bit)?;
+        if self.kind == BitDecodeErrorKind::NonZeroPadding {
+            return Ok(());
+        }
+        if self.path.is_empty() {
+            f.write_str(", in the outermost packet")
+        } else {
+            let path = self.path.iter().map(usize::to_string).collect::<Vec<_>>();
+            write!(f, ", in sub-packet {}", path.join("."))
+        }
+    }
+}
+
+impl Error for BitDecodeError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::bit_decode_error::BitDecodeErrorKind;
+    use crate::bit_operator_type::BitOperatorType;
+    use crate::bit_packet::BitPacket;
+    use crate::bit_packet_data_operator::BitPacketDataOperator;
+    use crate::bit_packet_type::BitPacketType;
+    use crate::bit_trans::BitTrans;
+    use aoc_core::input_resolver::InputResolver;
+    use std::str::FromStr;
+
+    #[test]
+    fn truncated() {
+        let error = BitTrans::from_str("D2FE").unwrap_err();
+        assert_eq!(
+            error.get_reason(),
+            "Transmission ended early at bit 16, in the outermost packet"
+        );
+        assert_eq!(error.get_column(), 5);
+
+        let error = BitTrans::from_str("38006F4529").unwrap_err();
+        assert_eq!(
+            error.get_reason(),
+            "Transmission ended early at bit 40, in sub-packet 1"
+        );
+
+        let packet = BitPacket::from_str("sum(1, max(2, 3))").unwrap();
+        let hex = BitTrans::from(packet).to_hex();
+        let error = BitTrans::from_str(&hex[..hex.len() - 2]).unwrap_err();
+        assert_eq!(
+            error.get_reason(),
+            "Transmission ended early at bit 64, in sub-packet 1.1"
+        );
+    }
+
+    #[test]
+    fn every_prefix() {
+        let input = InputResolver::workspace().load(16).unwrap();
+        let hex = input.as_string().trim_end();
+        for end in (0..hex.len()).step_by(2) {
+            // Only checking that it doesn't panic, a prefix can happen to be a whole packet.
+            let _ = BitTrans::from_str(&hex[..end]);
+        }
+        assert!(BitTrans::from_str(hex).is_ok());
+    }
+
+    #[test]
+    fn length_mismatch() {
+        let sub = vec![BitPacket::literal(6, 10), BitPacket::literal(2, 20)];
+        let op = BitPacketDataOperator::with_bit_count(BitOperatorType::LessThan, sub);
+        let packet = BitPacket::new(1, BitPacketType::Operator(op));
+        let mut bits = BitTrans::from(packet).get_bits().clone();
+        bits.set_range_from_int((7..22).rev(), 26);
+        let error = BitTrans::new(bits).unwrap_err();
+        assert_eq!(
+            error.get_kind(),
+            BitDecodeErrorKind::LengthMismatch {
+                expected: 26,
+                actual: 27
+            }
+        );
+        assert_eq!(error.get_bit(), 22);
+        assert!(error.get_path().is_empty());
+    }
+
+    #[test]
+    fn padding() {
+        assert_eq!(BitTrans::from_str("D2FE28").unwrap().get_padding(), 3);
+        assert_eq!(BitTrans::from_str("D2FE280000").unwrap().get_padding(), 19);
+        let error = BitTrans::from_str("D2FE29").unwrap_err();
+        assert_eq!(error.get_reason(), "Padding has bits set at bit 23");
+        assert_eq!(error.get_column(), 6);
+        assert!(BitTrans::from_str("D2FE2800F0").is_err());
+    }
+
+    #[test]
+    fn invalid_operator() {
+        assert_eq!(
+            BitOperatorType::try_from(4),
+            Err(BitDecodeErrorKind::InvalidOperator(4))
+        );
+        assert_eq!(BitOperatorType::try_from(7), Ok(BitOperatorType::Equal));
+    }
+}
@@ -1,3 +1,135 @@
+use crate::bit_number::BitNumber;
+use crate::bit_operator_type::BitOperatorType;
+use aoc_core::big_uint::BigUint;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
 pub trait BitEvaluate {
     fn evaluate(&self) -> u64;
 }
+
+/// Like [`BitEvaluate`], but returns an error for packets that can't be evaluated, rather than wrapping around or panicking.
+pub trait BitEvaluateChecked {
+    /// Evaluates with whichever kind of number is asked for.
+    fn evaluate_as<N: BitNumber>(&self) -> Result<N, BitEvaluateError>;
+
+    /// Evaluates with a `u64`, returning an error if a literal or result doesn't fit in one.
+    fn evaluate_checked(&self) -> Result<u64, BitEvaluateError> {
+        self.evaluate_as()
+    }
+
+    /// Evaluates with a [`BigUint`], which can't overflow.
+    fn evaluate_big(&self) -> Result<BigUint, BitEvaluateError> {
+        self.evaluate_as()
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum BitEvaluateError {
+    /// A literal is too long for the kind of number being evaluated with.
+    LiteralOverflow,
+    /// The result of an operator is too big for the kind of number being evaluated with.
+    Overflow(BitOperatorType),
+    /// A minimum or maximum of nothing at all.
+    NoSubPackets(BitOperatorType),
+    /// A comparison with something other than 2 sub-packets, and how many it had.
+    NotTwoSubPackets(BitOperatorType, usize),
+}
+
+impl Display for BitEvaluateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BitEvaluateError::LiteralOverflow => f.write_str("Literal is too long"),
+            BitEvaluateError::Overflow(op) => write!(f, "Result of {} overflowed", op),
+            BitEvaluateError::NoSubPackets(op) => write!(f, "Can't take the {} of nothing", op),
+            BitEvaluateError::NotTwoSubPackets(op, count) => write!(
+                f,
+                "Expected {} to have exactly 2 sub-packets, got: {}",
+                op, count
+            ),
+        }
+    }
+}
+
+impl Error for BitEvaluateError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::bit_evaluate::{BitEvaluate, BitEvaluateChecked, BitEvaluateError};
+    use crate::bit_operator_type::BitOperatorType;
+    use crate::bit_packet::BitPacket;
+    use crate::bit_trans::BitTrans;
+    use crate::Day16;
+    use aoc_core::input_resolver::InputResolver;
+    use aoc_core::solution::Solution;
+    use std::str::FromStr;
+
+    #[test]
+    fn checked_matches_unchecked() {
+        let input = InputResolver::workspace().load(16).unwrap();
+        let examples = Day16::EXAMPLES.iter().map(|example| example.input);
+        for hex in examples.chain([input.as_string().trim_end()]) {
+            let bits = BitTrans::from_str(hex).unwrap();
+            assert_eq!(bits.evaluate_checked(), Ok(bits.evaluate()));
+            assert_eq!(bits.evaluate_big().unwrap().to_u64(), Some(bits.evaluate()));
+        }
+    }
+
+    #[test]
+    fn overflow() {
+        let packet = BitPacket::from_str("product(4294967296, 4294967296)").unwrap();
+        assert_eq!(
+            packet.evaluate_checked(),
+            Err(BitEvaluateError::Overflow(BitOperatorType::Product))
+        );
+        assert_eq!(
+            packet.evaluate_big().unwrap().to_string(),
+            "18446744073709551616"
+        );
+
+        let packet = BitPacket::from_str("gt(sum(18446744073709551615, 1), 5)").unwrap();
+        assert_eq!(
+            packet.evaluate_checked(),
+            Err(BitEvaluateError::Overflow(BitOperatorType::Sum))
+        );
+        assert_eq!(packet.evaluate_big().unwrap().to_u64(), Some(1));
+    }
+
+    #[test]
+    fn long_literals() {
+        let max = "340282366920938463463374607431768211455";
+        let hex = BitTrans::from(BitPacket::from_str(max).unwrap()).to_hex();
+        let bits = BitTrans::from_str(&hex).unwrap();
+        assert_eq!(bits.to_string(), format!("{}@0", max));
+        assert_eq!(bits.evaluate_big().unwrap().to_string(), max);
+        assert_eq!(
+            bits.evaluate_checked(),
+            Err(BitEvaluateError::LiteralOverflow)
+        );
+
+        let packet = BitPacket::from_str(&format!("lt(1, min({}, 7))", max)).unwrap();
+        assert_eq!(
+            packet.evaluate_checked(),
+            Err(BitEvaluateError::LiteralOverflow)
+        );
+        assert_eq!(packet.evaluate_big().unwrap().to_u64(), Some(1));
+    }
+
+    #[test]
+    fn invalid_operators() {
+        let literals = vec![BitPacket::literal(0, 1); 3];
+        let packet = BitPacket::operator(0, BitOperatorType::GreaterThan, literals);
+        assert_eq!(
+            packet.evaluate_checked(),
+            Err(BitEvaluateError::NotTwoSubPackets(
+                BitOperatorType::GreaterThan,
+                3
+            ))
+        );
+        let packet = BitPacket::operator(0, BitOperatorType::Minimum, Vec::new());
+        assert_eq!(
+            packet.evaluate_big(),
+            Err(BitEvaluateError::NoSubPackets(BitOperatorType::Minimum))
+        );
+    }
+}
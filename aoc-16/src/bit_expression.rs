@@ -0,0 +1,225 @@
+use crate::bit_operator_size_type::BitOperatorSizeType;
+use crate::bit_operator_type::BitOperatorType;
+use crate::bit_packet::BitPacket;
+use aoc_core::big_uint::BigUint;
+use aoc_core::bit::bit_size::BitSize;
+use aoc_core::error::ParseError;
+use std::str::FromStr;
+
+impl FromStr for BitPacket {
+    type Err = ParseError;
+
+    /// Parses a packet from the expressions [`BitPacket`] is displayed as, like `sum@1(3@4, max(7, 8))`.
+    /// Versions are optional and default to 0, and operators use whichever length type [`BitPacket::operator`] picks.
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        let mut parser = ExpressionParser::new(expression);
+        let packet = parser.packet()?;
+        parser.skip_whitespace();
+        if !parser.rest.is_empty() {
+            return Err(parser.error("Expected the end of the expression"));
+        }
+        Ok(packet)
+    }
+}
+
+/// A recursive descent parser over an expression, keeping track of what's left to parse so errors can point at it.
+struct ExpressionParser<'a> {
+    expression: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> ExpressionParser<'a> {
+    fn new(expression: &'a str) -> Self {
+        ExpressionParser {
+            expression,
+            rest: expression,
+        }
+    }
+
+    /// Parses a literal like `7@2`, or an operator like `max@0(7, 8)`.
+    fn packet(&mut self) -> Result<BitPacket, ParseError> {
+        self.skip_whitespace();
+        let head = self.take_while(|c| c.is_ascii_alphanumeric());
+        if head.is_empty() {
+            return Err(self.error("Expected a packet"));
+        }
+        if head.starts_with(|c: char| c.is_ascii_digit()) {
+            let value = BigUint::from_str(head).map_err(|_| {
+                ParseError::within("Expected a literal value", self.expression, head)
+            })?;
+            let version = self.version()?;
+            return Ok(BitPacket::big_literal(version, &value));
+        }
+
+        let op =
+            BitOperatorType::from_str(head).map_err(|e| e.located_in(self.expression, head))?;
+        let version = self.version()?;
+        let start = self.rest;
+        let sub = self.arguments()?;
+        let arguments = start[..start.len() - self.rest.len()].trim_start();
+        let expected = match op {
+            BitOperatorType::GreaterThan | BitOperatorType::LessThan | BitOperatorType::Equal => {
+                Some(2)
+            }
+            _ => None,
+        };
+        if let Some(expected) = expected.filter(|expected| *expected != sub.len()) {
+            return Err(ParseError::within(
+                format!("Expected exactly {} packets", expected),
+                self.expression,
+                arguments,
+            ));
+        }
+        // Either the number of packets or their length in bits has to fit in the operator's header.
+        if sub.len() > BitOperatorSizeType::MAX_PACKETS
+            && sub.iter().map(BitPacket::bit_size).sum::<usize>() > BitOperatorSizeType::MAX_BITS
+        {
+            return Err(ParseError::within(
+                "Too many packets to encode",
+                self.expression,
+                arguments,
+            ));
+        }
+        Ok(BitPacket::operator(version, op, sub))
+    }
+
+    /// Parses the `@version` after a packet, if there is one.
+    fn version(&mut self) -> Result<u8, ParseError> {
+        if !self.eat('@') {
+            return Ok(0);
+        }
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        match digits.parse() {
+            Ok(version) if version < 8 => Ok(version),
+            _ => Err(ParseError::within(
+                "Expected a version from 0 to 7",
+                self.expression,
+                digits,
+            )),
+        }
+    }
+
+    /// Parses a parenthesized, comma separated list of at least one packet.
+    fn arguments(&mut self) -> Result<Vec<BitPacket>, ParseError> {
+        self.skip_whitespace();
+        if !self.eat('(') {
+            return Err(self.error("Expected \"(\""));
+        }
+        let mut sub = vec![self.packet()?];
+        loop {
+            self.skip_whitespace();
+            if self.eat(')') {
+                return Ok(sub);
+            }
+            if !self.eat(',') {
+                return Err(self.error("Expected \",\" or \")\""));
+            }
+            sub.push(self.packet()?);
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.rest = self.rest.trim_start();
+    }
+
+    /// Moves past `c` if it's next, returning whether it was.
+    fn eat(&mut self, c: char) -> bool {
+        match self.rest.strip_prefix(c) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn take_while<F: Fn(char) -> bool>(&mut self, predicate: F) -> &'a str {
+        let end = self.rest.find(|c| !predicate(c)).unwrap_or(self.rest.len());
+        let (taken, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        taken
+    }
+
+    /// Creates an error pointing at the next character, or at the end of the expression if there's nothing left.
+    fn error(&self, reason: &str) -> ParseError {
+        let end = self.rest.chars().next().map_or(0, char::len_utf8);
+        ParseError::within(reason, self.expression, &self.rest[..end])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bit_packet::BitPacket;
+    use crate::bit_trans::BitTrans;
+    use crate::{BitEvaluate, BitPacketVersion, Day16};
+    use aoc_core::input_resolver::InputResolver;
+    use aoc_core::solution::Solution;
+    use std::str::FromStr;
+
+    #[test]
+    fn display() {
+        let bits = BitTrans::from_str("C200B40A82").unwrap();
+        assert_eq!(bits.to_string(), "sum@6(1@6, 2@2)");
+        assert_eq!(format!("{:#}", bits), "sum(1, 2)");
+
+        let bits = BitTrans::from_str("9C0141080250320F1802104A08").unwrap();
+        assert_eq!(format!("{:#}", bits), "eq(sum(1, 3), product(2, 2))");
+    }
+
+    #[test]
+    fn parse() {
+        let packet = BitPacket::from_str("sum@1(3@4, max ( 7@2,8 ))").unwrap();
+        assert_eq!(packet.to_string(), "sum@1(3@4, max@0(7@2, 8@0))");
+        assert_eq!(packet.evaluate(), 11);
+        assert_eq!(packet.sum_version(), 7);
+
+        let bits = BitTrans::from(BitPacket::from_str("lt(min(5, 9), 6)").unwrap());
+        assert_eq!(BitTrans::from_str(&bits.to_hex()).unwrap().evaluate(), 1);
+    }
+
+    #[test]
+    fn round_trip() {
+        let input = InputResolver::workspace().load(16).unwrap();
+        let examples = Day16::EXAMPLES.iter().map(|example| example.input);
+        for hex in examples.chain([input.as_string().trim_end()]) {
+            let bits = BitTrans::from_str(hex).unwrap();
+            let packet = BitPacket::from_str(&bits.to_string()).unwrap();
+            assert_eq!(packet.to_string(), bits.to_string());
+            assert_eq!(packet.evaluate(), bits.evaluate());
+            assert_eq!(packet.sum_version(), bits.sum_version());
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let error = BitPacket::from_str("sum(1, avg(2, 3))").unwrap_err();
+        assert_eq!(error.get_reason(), "Expected an operator");
+        assert_eq!(error.get_column(), 8);
+        assert_eq!(error.get_text(), "avg");
+
+        let error = BitPacket::from_str("gt(1, 2, 3)").unwrap_err();
+        assert_eq!(error.get_reason(), "Expected exactly 2 packets");
+        assert_eq!(error.get_column(), 3);
+
+        let error = BitPacket::from_str("7@8").unwrap_err();
+        assert_eq!(error.get_column(), 3);
+        let error = BitPacket::from_str("max(1 2)").unwrap_err();
+        assert_eq!(error.get_column(), 7);
+        let error = BitPacket::from_str("sum()").unwrap_err();
+        assert_eq!(error.get_reason(), "Expected a packet");
+        let error = BitPacket::from_str("sum(1").unwrap_err();
+        assert_eq!(error.get_column(), 6);
+
+        // 3000 literals are too many to count, and too long at 11 bits each to measure.
+        let expression = format!("sum({})", vec!["1"; 3000].join(","));
+        let error = BitPacket::from_str(&expression).unwrap_err();
+        assert_eq!(error.get_reason(), "Too many packets to encode");
+        assert_eq!(error.get_column(), 4);
+        // 2500 are too many to count, but short enough to measure instead.
+        let expression = format!("sum({})", vec!["1"; 2500].join(","));
+        assert_eq!(BitPacket::from_str(&expression).unwrap().evaluate(), 2500);
+
+        assert!(BitPacket::from_str("1 2").is_err());
+        assert!(BitPacket::from_str("12ab").is_err());
+    }
+}
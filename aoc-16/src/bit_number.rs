@@ -0,0 +1,74 @@
+use aoc_core::big_uint::BigUint;
+
+/// A kind of number packets can be evaluated with, see [`crate::bit_evaluate::BitEvaluateChecked`].
+pub trait BitNumber: Ord + Clone {
+    fn from_u64(value: u64) -> Self;
+
+    /// Builds a number from groups of 4 bits, most significant first, like a literal packet is made of.
+    /// Returns `None` if the number doesn't fit.
+    fn from_groups(groups: &[u8]) -> Option<Self>;
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self>;
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self>;
+}
+
+impl BitNumber for u64 {
+    fn from_u64(value: u64) -> Self {
+        value
+    }
+
+    fn from_groups(groups: &[u8]) -> Option<Self> {
+        groups.iter().try_fold(0u64, |acc, group| {
+            acc.checked_mul(16)?.checked_add(*group as u64)
+        })
+    }
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        u64::checked_add(*self, *rhs)
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        u64::checked_mul(*self, *rhs)
+    }
+}
+
+/// Never overflows, so literals of any length and products of any size can be evaluated.
+impl BitNumber for BigUint {
+    fn from_u64(value: u64) -> Self {
+        BigUint::from(value)
+    }
+
+    fn from_groups(groups: &[u8]) -> Option<Self> {
+        let mut result = BigUint::zero();
+        for group in groups {
+            result.mul_add_small(16, *group as u32);
+        }
+        Some(result)
+    }
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Some(self * rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bit_number::BitNumber;
+    use aoc_core::big_uint::BigUint;
+
+    #[test]
+    fn from_groups() {
+        assert_eq!(u64::from_groups(&[0b0111, 0b1110, 0b0101]), Some(2021));
+        assert_eq!(u64::from_groups(&[0xf; 16]), Some(u64::MAX));
+        assert_eq!(u64::from_groups(&[0x1; 17]), None);
+        // Leading zeroes don't count towards the size.
+        assert_eq!(u64::from_groups(&[0x0; 20]), Some(0));
+        let big = BigUint::from_groups(&[0x1; 17]).unwrap();
+        assert_eq!(big.to_string(), "19676527011956855057");
+    }
+}
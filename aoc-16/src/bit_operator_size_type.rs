@@ -1,27 +1,64 @@
-use crate::from_bitvec::FromBitvec;
+use crate::bit_decode_error::BitDecodeError;
+use crate::bit_stream::{BitStream, FromBitStream};
+use crate::from_bitvec::{read_int, FromBitvec};
+use crate::to_bitvec::ToBitvec;
 use aoc_core::bit::bit_size::BitSize;
 use aoc_core::bit::bitvec::BitVec;
+use std::io::Read;
 
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum BitOperatorSizeType {
     Bits(u16),
     Packets(u16),
 }
 
 impl FromBitvec<u8> for BitOperatorSizeType {
-    fn from_bitvec(bitvec: &BitVec<u8>, start_bit: usize) -> Self {
-        let type_id = bitvec[start_bit];
+    fn from_bitvec(bitvec: &BitVec<u8>, start_bit: usize) -> Result<Self, BitDecodeError> {
+        let type_id = read_int(bitvec, start_bit, 1)?;
         let size_start = start_bit + 1;
         match type_id {
-            false => {
-                let data_range = (size_start..(size_start + 15)).rev();
-                let bit_count = bitvec.get_range_as_int(data_range);
-                BitOperatorSizeType::Bits(bit_count as u16)
+            0 => {
+                let bit_count = read_int(bitvec, size_start, 15)?;
+                Ok(BitOperatorSizeType::Bits(bit_count as u16))
             }
-            true => {
-                let data_range = (size_start..(size_start + 11)).rev();
-                let packet_count = bitvec.get_range_as_int(data_range);
-                BitOperatorSizeType::Packets(packet_count as u16)
+            _ => {
+                let packet_count = read_int(bitvec, size_start, 11)?;
+                Ok(BitOperatorSizeType::Packets(packet_count as u16))
+            }
+        }
+    }
+}
+
+impl BitOperatorSizeType {
+    /// The largest sub-packet length, in bits, that fits in length type 0.
+    pub const MAX_BITS: usize = (1 << 15) - 1;
+    /// The most sub-packets that fit in length type 1.
+    pub const MAX_PACKETS: usize = (1 << 11) - 1;
+}
+
+impl FromBitStream for BitOperatorSizeType {
+    fn from_bit_stream<R: Read>(stream: &mut BitStream<R>) -> Result<Self, BitDecodeError> {
+        match stream.read_int(1)? {
+            0 => Ok(BitOperatorSizeType::Bits(stream.read_int(15)? as u16)),
+            _ => Ok(BitOperatorSizeType::Packets(stream.read_int(11)? as u16)),
+        }
+    }
+}
+
+impl ToBitvec<u8> for BitOperatorSizeType {
+    fn to_bitvec(&self, bitvec: &mut BitVec<u8>, start_bit: usize) {
+        let size_start = start_bit + 1;
+        match self {
+            BitOperatorSizeType::Bits(bit_count) => {
+                bitvec.set(start_bit, false);
+                bitvec.set_range_from_int((size_start..(size_start + 15)).rev(), *bit_count as u64);
+            }
+            BitOperatorSizeType::Packets(packet_count) => {
+                bitvec.set(start_bit, true);
+                bitvec.set_range_from_int(
+                    (size_start..(size_start + 11)).rev(),
+                    *packet_count as u64,
+                );
             }
         }
     }
@@ -1,6 +1,10 @@
+use crate::bit_decode_error::BitDecodeErrorKind;
 use aoc_core::bit::bit_size::BitSize;
+use aoc_core::error::ParseError;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum BitOperatorType {
     Sum,
     Product,
@@ -11,17 +15,66 @@ pub enum BitOperatorType {
     Equal,
 }
 
-impl From<u8> for BitOperatorType {
-    fn from(input: u8) -> Self {
+impl TryFrom<u8> for BitOperatorType {
+    type Error = BitDecodeErrorKind;
+
+    fn try_from(input: u8) -> Result<Self, Self::Error> {
         match input {
-            0 => BitOperatorType::Sum,
-            1 => BitOperatorType::Product,
-            2 => BitOperatorType::Minimum,
-            3 => BitOperatorType::Maximum,
-            5 => BitOperatorType::GreaterThan,
-            6 => BitOperatorType::LessThan,
-            7 => BitOperatorType::Equal,
-            _ => panic!("Invalid value {}", input),
+            0 => Ok(BitOperatorType::Sum),
+            1 => Ok(BitOperatorType::Product),
+            2 => Ok(BitOperatorType::Minimum),
+            3 => Ok(BitOperatorType::Maximum),
+            5 => Ok(BitOperatorType::GreaterThan),
+            6 => Ok(BitOperatorType::LessThan),
+            7 => Ok(BitOperatorType::Equal),
+            _ => Err(BitDecodeErrorKind::InvalidOperator(input)),
+        }
+    }
+}
+
+impl From<BitOperatorType> for u8 {
+    fn from(op: BitOperatorType) -> Self {
+        match op {
+            BitOperatorType::Sum => 0,
+            BitOperatorType::Product => 1,
+            BitOperatorType::Minimum => 2,
+            BitOperatorType::Maximum => 3,
+            BitOperatorType::GreaterThan => 5,
+            BitOperatorType::LessThan => 6,
+            BitOperatorType::Equal => 7,
+        }
+    }
+}
+
+impl Display for BitOperatorType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            BitOperatorType::Sum => "sum",
+            BitOperatorType::Product => "product",
+            BitOperatorType::Minimum => "min",
+            BitOperatorType::Maximum => "max",
+            BitOperatorType::GreaterThan => "gt",
+            BitOperatorType::LessThan => "lt",
+            BitOperatorType::Equal => "eq",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for BitOperatorType {
+    type Err = ParseError;
+
+    /// Parses the names [`BitOperatorType`] is displayed with, like `sum` or `gt`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "sum" => Ok(BitOperatorType::Sum),
+            "product" => Ok(BitOperatorType::Product),
+            "min" => Ok(BitOperatorType::Minimum),
+            "max" => Ok(BitOperatorType::Maximum),
+            "gt" => Ok(BitOperatorType::GreaterThan),
+            "lt" => Ok(BitOperatorType::LessThan),
+            "eq" => Ok(BitOperatorType::Equal),
+            _ => Err(ParseError::new("Expected an operator", name)),
         }
     }
 }
@@ -1,22 +1,118 @@
-use crate::bit_evaluate::BitEvaluate;
+use crate::bit_decode_error::BitDecodeError;
+use crate::bit_evaluate::{BitEvaluate, BitEvaluateChecked, BitEvaluateError};
+use crate::bit_number::BitNumber;
+use crate::bit_operator_type::BitOperatorType;
+use crate::bit_packet_data_literal::BitPacketDataLiteral;
+use crate::bit_packet_data_operator::BitPacketDataOperator;
 use crate::bit_packet_type::BitPacketType;
 use crate::bit_packet_version::BitPacketVersion;
-use crate::from_bitvec::FromBitvec;
+use crate::bit_stream::{BitStream, FromBitStream};
+use crate::from_bitvec::{read_int, FromBitvec};
+use crate::to_bitvec::ToBitvec;
+use aoc_core::big_uint::BigUint;
 use aoc_core::bit::bit_size::BitSize;
 use aoc_core::bit::bitvec::BitVec;
+use std::fmt::{Display, Formatter};
+use std::io::Read;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct BitPacket {
     version: u8,
     data: BitPacketType,
 }
 
-impl FromBitvec<u8> for BitPacket {
-    fn from_bitvec(bitvec: &BitVec<u8>, start_bit: usize) -> Self {
-        let version = bitvec.get_range_as_int((start_bit..start_bit + 3).rev()) as u8;
-        let data = BitPacketType::from_bitvec(bitvec, start_bit + 3);
+impl BitPacket {
+    /// Panics if `version` doesn't fit in 3 bits.
+    pub fn new(version: u8, data: BitPacketType) -> Self {
+        if version > 7 {
+            panic!("Invalid version {}", version);
+        }
         BitPacket { version, data }
     }
+
+    /// Makes a packet holding the literal `value`.
+    pub fn literal(version: u8, value: u64) -> Self {
+        BitPacket::new(
+            version,
+            BitPacketType::Literal(BitPacketDataLiteral::new(value)),
+        )
+    }
+
+    /// Makes a packet applying `op` to `sub`, using whichever length type [`BitPacketDataOperator::new`] picks.
+    pub fn operator(version: u8, op: BitOperatorType, sub: Vec<BitPacket>) -> Self {
+        BitPacket::new(
+            version,
+            BitPacketType::Operator(BitPacketDataOperator::new(op, sub)),
+        )
+    }
+
+    /// Like [`BitPacket::literal`], for literals that don't fit in a `u64`.
+    pub fn big_literal(version: u8, value: &BigUint) -> Self {
+        BitPacket::new(
+            version,
+            BitPacketType::Literal(BitPacketDataLiteral::from_big(value)),
+        )
+    }
+
+    pub fn get_data(&self) -> &BitPacketType {
+        &self.data
+    }
+}
+
+impl FromBitvec<u8> for BitPacket {
+    fn from_bitvec(bitvec: &BitVec<u8>, start_bit: usize) -> Result<Self, BitDecodeError> {
+        let version = read_int(bitvec, start_bit, 3)? as u8;
+        let data = BitPacketType::from_bitvec(bitvec, start_bit + 3)?;
+        Ok(BitPacket { version, data })
+    }
+}
+
+impl FromBitStream for BitPacket {
+    fn from_bit_stream<R: Read>(stream: &mut BitStream<R>) -> Result<Self, BitDecodeError> {
+        let version = stream.read_int(3)? as u8;
+        let data = BitPacketType::from_bit_stream(stream)?;
+        Ok(BitPacket { version, data })
+    }
+}
+
+impl ToBitvec<u8> for BitPacket {
+    fn to_bitvec(&self, bitvec: &mut BitVec<u8>, start_bit: usize) {
+        bitvec.set_range_from_int((start_bit..start_bit + 3).rev(), self.version as u64);
+        self.data.to_bitvec(bitvec, start_bit + 3);
+    }
+}
+
+impl Display for BitPacket {
+    /// Writes the packet as an expression, like `sum@1(3@4, max@0(7@2, 8@7))`, with each packet's version after an `@`.
+    /// The alternate form (`{:#}`) leaves the versions out, like `sum(3, max(7, 8))`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let version = |f: &mut Formatter<'_>| {
+            if f.alternate() {
+                Ok(())
+            } else {
+                write!(f, "@{}", self.version)
+            }
+        };
+        match &self.data {
+            BitPacketType::Literal(lit) => {
+                let value: BigUint = lit.get_number().expect("Big numbers always fit!");
+                write!(f, "{}", value)?;
+                version(f)
+            }
+            BitPacketType::Operator(op) => {
+                write!(f, "{}", op.get_op())?;
+                version(f)?;
+                f.write_str("(")?;
+                for (idx, sub_packet) in op.get_sub().iter().enumerate() {
+                    if idx > 0 {
+                        f.write_str(", ")?;
+                    }
+                    Display::fmt(sub_packet, f)?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
 }
 
 impl BitEvaluate for BitPacket {
@@ -25,6 +121,12 @@ impl BitEvaluate for BitPacket {
     }
 }
 
+impl BitEvaluateChecked for BitPacket {
+    fn evaluate_as<N: BitNumber>(&self) -> Result<N, BitEvaluateError> {
+        self.data.evaluate_as()
+    }
+}
+
 impl BitSize for BitPacket {
     fn bit_size(&self) -> usize {
         3 + self.data.bit_size()
@@ -1,25 +1,69 @@
-use crate::from_bitvec::FromBitvec;
+use crate::bit_decode_error::BitDecodeError;
+use crate::bit_evaluate::{BitEvaluateChecked, BitEvaluateError};
+use crate::bit_number::BitNumber;
+use crate::bit_stream::{BitStream, FromBitStream};
+use crate::from_bitvec::{read_int, FromBitvec};
+use crate::to_bitvec::ToBitvec;
 use crate::BitEvaluate;
+use aoc_core::big_uint::BigUint;
 use aoc_core::bit::bit_size::BitSize;
 use aoc_core::bit::bitvec::BitVec;
+use std::io::Read;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct BitPacketDataLiteral {
-    value: u64,
-    value_size: usize,
+    /// Each group of 4 bits in the literal, most significant first. Kept as-is so literals longer than 64 bits aren't cut short.
+    groups: Vec<u8>,
+}
+
+impl BitPacketDataLiteral {
+    /// Makes a literal that's encoded in as few groups of 4 bits as it takes to hold `value`.
+    pub fn new(value: u64) -> Self {
+        let significant_bits = (u64::BITS - value.leading_zeros()) as usize;
+        let groups = significant_bits.div_ceil(4).max(1);
+        let groups = (0..groups)
+            .rev()
+            .map(|group| ((value >> (group * 4)) & 0b1111) as u8)
+            .collect();
+        BitPacketDataLiteral { groups }
+    }
+
+    /// Like [`BitPacketDataLiteral::new`], for literals that don't fit in a `u64`.
+    pub fn from_big(value: &BigUint) -> Self {
+        let groups = value.bit_len().div_ceil(4).max(1);
+        let groups = (0..groups)
+            .rev()
+            .map(|group| {
+                (0..4)
+                    .filter(|bit| value.get_bit(group * 4 + bit))
+                    .fold(0, |acc, bit| acc | (1 << bit))
+            })
+            .collect();
+        BitPacketDataLiteral { groups }
+    }
+
+    /// Returns the lowest 64 bits of the literal, see [`BitPacketDataLiteral::get_number`] for literals longer than that.
+    pub fn get_value(&self) -> u64 {
+        self.groups
+            .iter()
+            .fold(0, |acc, group| (acc << 4) | *group as u64)
+    }
+
+    /// Returns the literal as any kind of number, or `None` if it doesn't fit.
+    pub fn get_number<N: BitNumber>(&self) -> Option<N> {
+        N::from_groups(&self.groups)
+    }
 }
 
 impl FromBitvec<u8> for BitPacketDataLiteral {
-    fn from_bitvec(bitvec: &BitVec<u8>, start_bit: usize) -> Self {
-        let mut result = 0u64;
+    fn from_bitvec(bitvec: &BitVec<u8>, start_bit: usize) -> Result<Self, BitDecodeError> {
+        let mut groups = Vec::new();
         let mut size = 0;
         loop {
             let idx = start_bit + size;
-            let continue_reading = bitvec[idx];
-            let next_range = ((idx + 1)..(idx + 5)).rev();
-            let next = bitvec.get_range_as_int(next_range);
-            result = result << 4;
-            result = result | next;
+            let continue_reading = read_int(bitvec, idx, 1)? == 1;
+            let next = read_int(bitvec, idx + 1, 4)?;
+            groups.push(next as u8);
             size += 5;
             if !continue_reading {
                 break;
@@ -31,21 +75,50 @@ impl FromBitvec<u8> for BitPacketDataLiteral {
         // if size % 4 != 0 {
         //     size += 4 - (size % 4);
         // }
-        BitPacketDataLiteral {
-            value: result,
-            value_size: size,
+        Ok(BitPacketDataLiteral { groups })
+    }
+}
+
+impl FromBitStream for BitPacketDataLiteral {
+    fn from_bit_stream<R: Read>(stream: &mut BitStream<R>) -> Result<Self, BitDecodeError> {
+        let mut groups = Vec::new();
+        loop {
+            let continue_reading = stream.read_int(1)? == 1;
+            groups.push(stream.read_int(4)? as u8);
+            if !continue_reading {
+                break;
+            }
+        }
+        Ok(BitPacketDataLiteral { groups })
+    }
+}
+
+impl ToBitvec<u8> for BitPacketDataLiteral {
+    fn to_bitvec(&self, bitvec: &mut BitVec<u8>, start_bit: usize) {
+        // Decoded literals can have leading groups of zeroes, so write as many groups as were read.
+        let last = self.groups.len() - 1;
+        for (group, next) in self.groups.iter().enumerate() {
+            let idx = start_bit + group * 5;
+            bitvec.set(idx, group != last);
+            bitvec.set_range_from_int(((idx + 1)..(idx + 5)).rev(), *next as u64);
         }
     }
 }
 
 impl BitEvaluate for BitPacketDataLiteral {
     fn evaluate(&self) -> u64 {
-        self.value
+        self.get_value()
+    }
+}
+
+impl BitEvaluateChecked for BitPacketDataLiteral {
+    fn evaluate_as<N: BitNumber>(&self) -> Result<N, BitEvaluateError> {
+        self.get_number().ok_or(BitEvaluateError::LiteralOverflow)
     }
 }
 
 impl BitSize for BitPacketDataLiteral {
     fn bit_size(&self) -> usize {
-        self.value_size
+        self.groups.len() * 5
     }
 }
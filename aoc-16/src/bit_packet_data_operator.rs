@@ -1,23 +1,117 @@
+use crate::bit_decode_error::{BitDecodeError, BitDecodeErrorKind};
+use crate::bit_evaluate::{BitEvaluateChecked, BitEvaluateError};
+use crate::bit_number::BitNumber;
 use crate::bit_operator_size_type::BitOperatorSizeType;
 use crate::bit_operator_type::BitOperatorType;
 use crate::bit_packet::BitPacket;
-use crate::from_bitvec::FromBitvec;
+use crate::bit_stream::{BitStream, FromBitStream};
+use crate::from_bitvec::{read_int, FromBitvec};
+use crate::to_bitvec::ToBitvec;
 use crate::{BitEvaluate, BitPacketVersion};
 use aoc_core::bit::bit_size::BitSize;
 use aoc_core::bit::bitvec::BitVec;
+use std::io::Read;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct BitPacketDataOperator {
     op: BitOperatorType,
     sub: Vec<BitPacket>,
     sub_size: BitOperatorSizeType,
 }
 
+impl BitPacketDataOperator {
+    /// Counts the sub-packets (length type 1), since that's the shorter header, unless there are too many of them to count.
+    pub fn new(op: BitOperatorType, sub: Vec<BitPacket>) -> Self {
+        if sub.len() <= BitOperatorSizeType::MAX_PACKETS {
+            BitPacketDataOperator::with_packet_count(op, sub)
+        } else {
+            BitPacketDataOperator::with_bit_count(op, sub)
+        }
+    }
+
+    /// Prefixes the sub-packets with their length in bits (length type 0). Panics if they don't fit in 15 bits.
+    pub fn with_bit_count(op: BitOperatorType, sub: Vec<BitPacket>) -> Self {
+        let bit_count: usize = sub.iter().map(BitPacket::bit_size).sum();
+        if bit_count > BitOperatorSizeType::MAX_BITS {
+            panic!(
+                "Sub-packets are {} bits long, too long to encode!",
+                bit_count
+            );
+        }
+        let sub_size = BitOperatorSizeType::Bits(bit_count as u16);
+        BitPacketDataOperator { op, sub, sub_size }
+    }
+
+    /// Prefixes the sub-packets with how many there are (length type 1). Panics if there are more than fit in 11 bits.
+    pub fn with_packet_count(op: BitOperatorType, sub: Vec<BitPacket>) -> Self {
+        if sub.len() > BitOperatorSizeType::MAX_PACKETS {
+            panic!("There are {} sub-packets, too many to encode!", sub.len());
+        }
+        let sub_size = BitOperatorSizeType::Packets(sub.len() as u16);
+        BitPacketDataOperator { op, sub, sub_size }
+    }
+
+    /// Decodes the rest of an operator from `stream`, once its type has already been read.
+    pub fn from_bit_stream_with_op<R: Read>(
+        op: BitOperatorType,
+        stream: &mut BitStream<R>,
+    ) -> Result<Self, BitDecodeError> {
+        let sub_size = BitOperatorSizeType::from_bit_stream(stream)?;
+        let mut sub = Vec::new();
+        let read_sub = |sub: &mut Vec<BitPacket>, stream: &mut BitStream<R>| {
+            let sub_packet =
+                BitPacket::from_bit_stream(stream).map_err(|e| e.within_sub_packet(sub.len()))?;
+            sub.push(sub_packet);
+            Ok(())
+        };
+
+        match sub_size {
+            BitOperatorSizeType::Bits(bit_count) => {
+                let initial_offset = stream.get_position();
+                while stream.get_position() - initial_offset < (bit_count as usize) {
+                    read_sub(&mut sub, stream)?;
+                }
+                let actual = stream.get_position() - initial_offset;
+                if actual != bit_count as usize {
+                    let kind = BitDecodeErrorKind::LengthMismatch {
+                        expected: bit_count as usize,
+                        actual,
+                    };
+                    return Err(BitDecodeError::new(kind, initial_offset));
+                }
+            }
+            BitOperatorSizeType::Packets(packet_count) => {
+                for _ in 0..packet_count {
+                    read_sub(&mut sub, stream)?;
+                }
+            }
+        }
+
+        Ok(BitPacketDataOperator { op, sub, sub_size })
+    }
+
+    pub fn get_op(&self) -> BitOperatorType {
+        self.op
+    }
+
+    pub fn get_sub(&self) -> &[BitPacket] {
+        &self.sub
+    }
+
+    pub fn get_sub_size(&self) -> BitOperatorSizeType {
+        self.sub_size
+    }
+}
+
 impl FromBitvec<u8> for BitPacketDataOperator {
-    fn from_bitvec(bitvec: &BitVec<u8>, start_bit: usize) -> Self {
-        let type_int = bitvec.get_range_as_int((start_bit..start_bit + 3).rev());
-        let op = BitOperatorType::from(type_int as u8);
-        let sub_size = BitOperatorSizeType::from_bitvec(bitvec, start_bit + 3);
+    fn from_bitvec(bitvec: &BitVec<u8>, start_bit: usize) -> Result<Self, BitDecodeError> {
+        let type_int = read_int(bitvec, start_bit, 3)?;
+        let op = BitOperatorType::try_from(type_int as u8)
+            .map_err(|kind| BitDecodeError::new(kind, start_bit))?;
+        let sub_size = BitOperatorSizeType::from_bitvec(bitvec, start_bit + 3)?;
+        let read_sub = |sub: &Vec<BitPacket>, sub_offset: usize| {
+            BitPacket::from_bitvec(bitvec, sub_offset).map_err(|e| e.within_sub_packet(sub.len()))
+        };
 
         let sub = match sub_size {
             BitOperatorSizeType::Bits(bit_count) => {
@@ -25,10 +119,18 @@ impl FromBitvec<u8> for BitPacketDataOperator {
                 let initial_offset = start_bit + op.bit_size() + sub_size.bit_size();
                 let mut sub_offset = initial_offset;
                 while sub_offset - initial_offset < (bit_count as usize) {
-                    let sub_packet = BitPacket::from_bitvec(bitvec, sub_offset);
+                    let sub_packet = read_sub(&sub, sub_offset)?;
                     sub_offset += sub_packet.bit_size();
                     sub.push(sub_packet);
                 }
+                // The last sub-packet can run past the end of the bits that were set aside for them.
+                if sub_offset - initial_offset != bit_count as usize {
+                    let kind = BitDecodeErrorKind::LengthMismatch {
+                        expected: bit_count as usize,
+                        actual: sub_offset - initial_offset,
+                    };
+                    return Err(BitDecodeError::new(kind, initial_offset));
+                }
 
                 sub
             }
@@ -36,7 +138,7 @@ impl FromBitvec<u8> for BitPacketDataOperator {
                 let mut sub = Vec::new();
                 let mut sub_offset = start_bit + op.bit_size() + sub_size.bit_size();
                 for _ in 0..packet_count {
-                    let sub_packet = BitPacket::from_bitvec(bitvec, sub_offset);
+                    let sub_packet = read_sub(&sub, sub_offset)?;
                     sub_offset += sub_packet.bit_size();
                     sub.push(sub_packet);
                 }
@@ -44,7 +146,21 @@ impl FromBitvec<u8> for BitPacketDataOperator {
             }
         };
 
-        BitPacketDataOperator { op, sub, sub_size }
+        Ok(BitPacketDataOperator { op, sub, sub_size })
+    }
+}
+
+impl ToBitvec<u8> for BitPacketDataOperator {
+    fn to_bitvec(&self, bitvec: &mut BitVec<u8>, start_bit: usize) {
+        let type_int = u8::from(self.op) as u64;
+        bitvec.set_range_from_int((start_bit..start_bit + 3).rev(), type_int);
+        self.sub_size.to_bitvec(bitvec, start_bit + 3);
+
+        let mut sub_offset = start_bit + self.op.bit_size() + self.sub_size.bit_size();
+        for sub_packet in &self.sub {
+            sub_packet.to_bitvec(bitvec, sub_offset);
+            sub_offset += sub_packet.bit_size();
+        }
     }
 }
 
@@ -95,6 +211,46 @@ impl BitEvaluate for BitPacketDataOperator {
     }
 }
 
+impl BitEvaluateChecked for BitPacketDataOperator {
+    fn evaluate_as<N: BitNumber>(&self) -> Result<N, BitEvaluateError> {
+        let values = self
+            .sub
+            .iter()
+            .map(BitPacket::evaluate_as)
+            .collect::<Result<Vec<N>, _>>()?;
+        let overflow = || BitEvaluateError::Overflow(self.op);
+        match self.op {
+            BitOperatorType::Sum => values
+                .iter()
+                .try_fold(N::from_u64(0), |acc, val| acc.checked_add(val))
+                .ok_or_else(overflow),
+            BitOperatorType::Product => values
+                .iter()
+                .try_fold(N::from_u64(1), |acc, val| acc.checked_mul(val))
+                .ok_or_else(overflow),
+            BitOperatorType::Minimum => values
+                .into_iter()
+                .min()
+                .ok_or(BitEvaluateError::NoSubPackets(self.op)),
+            BitOperatorType::Maximum => values
+                .into_iter()
+                .max()
+                .ok_or(BitEvaluateError::NoSubPackets(self.op)),
+            BitOperatorType::GreaterThan | BitOperatorType::LessThan | BitOperatorType::Equal => {
+                let [first, second] = values.as_slice() else {
+                    return Err(BitEvaluateError::NotTwoSubPackets(self.op, values.len()));
+                };
+                let result = match self.op {
+                    BitOperatorType::GreaterThan => first > second,
+                    BitOperatorType::LessThan => first < second,
+                    _ => first == second,
+                };
+                Ok(N::from_u64(result as u64))
+            }
+        }
+    }
+}
+
 impl BitSize for BitPacketDataOperator {
     fn bit_size(&self) -> usize {
         self.sub_size.bit_size()
@@ -109,9 +265,6 @@ impl BitPacketVersion for BitPacketDataOperator {
     }
 
     fn sum_version(&self) -> u64 {
-        self.sub
-            .iter()
-            .map(BitPacket::sum_version)
-            .fold(0, |acc, val| acc + val)
+        self.sub.iter().map(BitPacket::sum_version).sum()
     }
 }
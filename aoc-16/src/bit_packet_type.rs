@@ -1,23 +1,64 @@
-use crate::bit_evaluate::BitEvaluate;
+use crate::bit_decode_error::BitDecodeError;
+use crate::bit_evaluate::{BitEvaluate, BitEvaluateChecked, BitEvaluateError};
+use crate::bit_number::BitNumber;
+use crate::bit_operator_type::BitOperatorType;
 use crate::bit_packet_data_literal::BitPacketDataLiteral;
 use crate::bit_packet_data_operator::BitPacketDataOperator;
 use crate::bit_packet_version::BitPacketVersion;
-use crate::from_bitvec::FromBitvec;
+use crate::bit_stream::{BitStream, FromBitStream};
+use crate::from_bitvec::{read_int, FromBitvec};
+use crate::to_bitvec::ToBitvec;
 use aoc_core::bit::bit_size::BitSize;
 use aoc_core::bit::bitvec::BitVec;
+use std::io::Read;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub enum BitPacketType {
     Literal(BitPacketDataLiteral),
     Operator(BitPacketDataOperator),
 }
 
 impl FromBitvec<u8> for BitPacketType {
-    fn from_bitvec(bitvec: &BitVec<u8>, start_bit: usize) -> Self {
-        let type_int = bitvec.get_range_as_int((start_bit..start_bit + 3).rev());
+    fn from_bitvec(bitvec: &BitVec<u8>, start_bit: usize) -> Result<Self, BitDecodeError> {
+        let type_int = read_int(bitvec, start_bit, 3)?;
         match type_int {
-            4 => BitPacketType::Literal(BitPacketDataLiteral::from_bitvec(bitvec, start_bit + 3)),
-            _ => BitPacketType::Operator(BitPacketDataOperator::from_bitvec(bitvec, start_bit)),
+            4 => Ok(BitPacketType::Literal(BitPacketDataLiteral::from_bitvec(
+                bitvec,
+                start_bit + 3,
+            )?)),
+            _ => Ok(BitPacketType::Operator(BitPacketDataOperator::from_bitvec(
+                bitvec, start_bit,
+            )?)),
+        }
+    }
+}
+
+impl FromBitStream for BitPacketType {
+    fn from_bit_stream<R: Read>(stream: &mut BitStream<R>) -> Result<Self, BitDecodeError> {
+        let type_int = stream.read_int(3)?;
+        match type_int {
+            4 => Ok(BitPacketType::Literal(
+                BitPacketDataLiteral::from_bit_stream(stream)?,
+            )),
+            _ => {
+                let op = BitOperatorType::try_from(type_int as u8)
+                    .map_err(|kind| BitDecodeError::new(kind, stream.get_position() - 3))?;
+                Ok(BitPacketType::Operator(
+                    BitPacketDataOperator::from_bit_stream_with_op(op, stream)?,
+                ))
+            }
+        }
+    }
+}
+
+impl ToBitvec<u8> for BitPacketType {
+    fn to_bitvec(&self, bitvec: &mut BitVec<u8>, start_bit: usize) {
+        match self {
+            BitPacketType::Literal(lit) => {
+                bitvec.set_range_from_int((start_bit..start_bit + 3).rev(), 4);
+                lit.to_bitvec(bitvec, start_bit + 3);
+            }
+            BitPacketType::Operator(op) => op.to_bitvec(bitvec, start_bit),
         }
     }
 }
@@ -31,6 +72,15 @@ impl BitEvaluate for BitPacketType {
     }
 }
 
+impl BitEvaluateChecked for BitPacketType {
+    fn evaluate_as<N: BitNumber>(&self) -> Result<N, BitEvaluateError> {
+        match self {
+            BitPacketType::Literal(lit) => lit.evaluate_as(),
+            BitPacketType::Operator(op) => op.evaluate_as(),
+        }
+    }
+}
+
 impl BitSize for BitPacketType {
     fn bit_size(&self) -> usize {
         match self {
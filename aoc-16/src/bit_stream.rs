@@ -0,0 +1,306 @@
+use crate::bit_decode_error::{BitDecodeError, BitDecodeErrorKind};
+use crate::bit_packet::BitPacket;
+use std::io::{self, ErrorKind, Read};
+
+/// The streaming counterpart of [`crate::from_bitvec::FromBitvec`], for decoding bits as they're read.
+pub trait FromBitStream: Sized {
+    fn from_bit_stream<R: Read>(stream: &mut BitStream<R>) -> Result<Self, BitDecodeError>;
+}
+
+/// Reads bits out of hex digits as they arrive from a reader, without reading any further ahead than it has to.
+///
+/// Each line is a separate transmission, and asking for bits past the end of a line is an error.
+pub struct BitStream<R> {
+    reader: R,
+    /// The hex digit currently being read, and how many of its bits are left to read.
+    digit: u8,
+    digit_bits: usize,
+    /// How many bits of the current transmission have been read.
+    position: usize,
+    /// Whether the current transmission has run out of digits.
+    line_ended: bool,
+    eof: bool,
+}
+
+impl<R: Read> BitStream<R> {
+    /// Reads `reader` one byte at a time, so it should be buffered if reading from it is slow.
+    pub fn new(reader: R) -> Self {
+        BitStream {
+            reader,
+            digit: 0,
+            digit_bits: 0,
+            position: 0,
+            line_ended: false,
+            eof: false,
+        }
+    }
+
+    /// Returns the number of bits read from the current transmission.
+    pub fn get_position(&self) -> usize {
+        self.position
+    }
+
+    /// Reads the next `len` bits as an int, where the first bit is the *most* significant.
+    pub fn read_int(&mut self, len: usize) -> Result<u64, BitDecodeError> {
+        let mut result = 0;
+        for _ in 0..len {
+            result = (result << 1) | self.read_bit()? as u64;
+        }
+        Ok(result)
+    }
+
+    fn read_bit(&mut self) -> Result<bool, BitDecodeError> {
+        if self.digit_bits == 0 {
+            self.digit = self.next_digit()?.ok_or_else(|| {
+                BitDecodeError::new(BitDecodeErrorKind::UnexpectedEnd, self.position)
+            })?;
+            self.digit_bits = 4;
+        }
+        self.digit_bits -= 1;
+        self.position += 1;
+        Ok((self.digit >> self.digit_bits) & 1 == 1)
+    }
+
+    /// Reads the next hex digit of the current transmission, or `None` if the transmission is over.
+    fn next_digit(&mut self) -> Result<Option<u8>, BitDecodeError> {
+        while !self.line_ended {
+            let byte = match self.next_byte() {
+                Some(Ok(byte)) => byte,
+                Some(Err(e)) => {
+                    let kind = BitDecodeErrorKind::ReadFailed(e.kind());
+                    return Err(BitDecodeError::new(kind, self.position));
+                }
+                None => {
+                    self.eof = true;
+                    self.line_ended = true;
+                    break;
+                }
+            };
+            match byte {
+                b'\n' => self.line_ended = true,
+                b'\r' => {}
+                _ => {
+                    let digit = (byte as char).to_digit(16).ok_or_else(|| {
+                        let kind = BitDecodeErrorKind::InvalidHexDigit(byte as char);
+                        BitDecodeError::new(kind, self.position)
+                    })?;
+                    return Ok(Some(digit as u8));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Reads a single byte, without buffering, so nothing past what's been asked for is read.
+    fn next_byte(&mut self) -> Option<io::Result<u8>> {
+        let mut byte = [0];
+        loop {
+            return match self.reader.read(&mut byte) {
+                Ok(0) => None,
+                Ok(_) => Some(Ok(byte[0])),
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => Some(Err(e)),
+            };
+        }
+    }
+
+    /// Moves on to the first digit of the next transmission, skipping blank lines. Returns `false` if there are no more.
+    fn start_transmission(&mut self) -> Result<bool, BitDecodeError> {
+        loop {
+            self.digit_bits = 0;
+            self.position = 0;
+            self.line_ended = false;
+            if self.eof {
+                return Ok(false);
+            }
+            if let Some(digit) = self.next_digit()? {
+                self.digit = digit;
+                self.digit_bits = 4;
+                return Ok(true);
+            }
+        }
+    }
+
+    /// Reads the rest of the current transmission, which is padding and has to be all zeroes.
+    fn finish_transmission(&mut self) -> Result<(), BitDecodeError> {
+        loop {
+            match self.read_bit() {
+                Ok(false) => {}
+                Ok(true) => {
+                    let kind = BitDecodeErrorKind::NonZeroPadding;
+                    return Err(BitDecodeError::new(kind, self.position - 1));
+                }
+                Err(e) if e.get_kind() == BitDecodeErrorKind::UnexpectedEnd => return Ok(()),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Throws away the rest of the current transmission, whatever's in it, after it failed to decode.
+    fn skip_transmission(&mut self) -> Result<(), BitDecodeError> {
+        while !self.line_ended {
+            if let Err(e) = self.next_digit() {
+                if let BitDecodeErrorKind::ReadFailed(_) = e.get_kind() {
+                    return Err(e);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Decodes a packet from each line of a reader, as soon as the bits for it have been read.
+///
+/// A transmission that can't be decoded is returned as an error, and decoding carries on with the next line.
+/// Once the reader itself fails, there are no more packets.
+pub struct BitPacketReader<R> {
+    stream: BitStream<R>,
+    failed: bool,
+}
+
+impl<R: Read> BitPacketReader<R> {
+    pub fn new(reader: R) -> Self {
+        BitPacketReader {
+            stream: BitStream::new(reader),
+            failed: false,
+        }
+    }
+
+    fn next_packet(&mut self) -> Result<Option<BitPacket>, BitDecodeError> {
+        if !self.stream.start_transmission()? {
+            return Ok(None);
+        }
+        let packet = BitPacket::from_bit_stream(&mut self.stream)?;
+        self.stream.finish_transmission()?;
+        Ok(Some(packet))
+    }
+}
+
+impl<R: Read> Iterator for BitPacketReader<R> {
+    type Item = Result<BitPacket, BitDecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        match self.next_packet() {
+            Ok(packet) => packet.map(Ok),
+            Err(e) => {
+                let read_failed = matches!(e.get_kind(), BitDecodeErrorKind::ReadFailed(_));
+                self.failed = read_failed || self.stream.skip_transmission().is_err();
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bit_decode_error::BitDecodeErrorKind;
+    use crate::bit_stream::BitPacketReader;
+    use crate::bit_trans::BitTrans;
+    use crate::Day16;
+    use aoc_core::input_resolver::InputResolver;
+    use aoc_core::solution::Solution;
+    use std::cell::Cell;
+    use std::io::{self, Read};
+    use std::rc::Rc;
+    use std::str::FromStr;
+
+    /// Hands out one byte per read, and keeps count of how many it's handed out.
+    struct Trickle {
+        bytes: Vec<u8>,
+        read: Rc<Cell<usize>>,
+    }
+
+    impl Read for Trickle {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let idx = self.read.get();
+            match self.bytes.get(idx) {
+                Some(byte) if !buf.is_empty() => {
+                    buf[0] = *byte;
+                    self.read.set(idx + 1);
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+    }
+
+    /// Fails after handing out everything in it.
+    struct Broken(io::Cursor<Vec<u8>>);
+
+    impl Read for Broken {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.0.read(buf)? {
+                0 => Err(io::Error::other("Broken!")),
+                read => Ok(read),
+            }
+        }
+    }
+
+    #[test]
+    fn matches_bitvec_decoder() {
+        let input = InputResolver::workspace().load(16).unwrap();
+        let mut hexes = Day16::EXAMPLES
+            .iter()
+            .map(|example| example.input)
+            .collect::<Vec<_>>();
+        hexes.push(input.as_string().trim_end());
+        let text = hexes.join("\r\n");
+        let packets = BitPacketReader::new(text.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(packets.len(), hexes.len());
+        for (packet, hex) in packets.iter().zip(hexes) {
+            assert_eq!(packet, BitTrans::from_str(hex).unwrap().get_packet());
+        }
+    }
+
+    #[test]
+    fn reads_no_further_than_needed() {
+        let read = Rc::new(Cell::new(0));
+        let reader = Trickle {
+            bytes: b"D2FE28\n38006F45291200\n".to_vec(),
+            read: read.clone(),
+        };
+        let mut packets = BitPacketReader::new(reader);
+        assert_eq!(packets.next().unwrap().unwrap().to_string(), "2021@6");
+        assert_eq!(read.get(), 7);
+        assert_eq!(
+            packets.next().unwrap().unwrap().to_string(),
+            "lt@1(10@6, 20@2)"
+        );
+        assert!(packets.next().is_none());
+    }
+
+    #[test]
+    fn carries_on_after_errors() {
+        let text = "\nD2FE\n\nD2FE29\nD2XE28\nC200B40A82\n";
+        let results = BitPacketReader::new(text.as_bytes()).collect::<Vec<_>>();
+        assert_eq!(results.len(), 4);
+        let error = results[0].as_ref().unwrap_err();
+        assert_eq!(error.get_kind(), BitDecodeErrorKind::UnexpectedEnd);
+        assert_eq!(error.get_bit(), 16);
+        let error = results[1].as_ref().unwrap_err();
+        assert_eq!(error.get_kind(), BitDecodeErrorKind::NonZeroPadding);
+        assert_eq!(error.get_bit(), 23);
+        let error = results[2].as_ref().unwrap_err();
+        assert_eq!(error.get_kind(), BitDecodeErrorKind::InvalidHexDigit('X'));
+        assert_eq!(error.get_bit(), 8);
+        assert_eq!(results[3].as_ref().unwrap().to_string(), "sum@6(1@6, 2@2)");
+    }
+
+    #[test]
+    fn stops_when_reader_fails() {
+        let reader = Broken(io::Cursor::new(b"D2FE28\n38006F".to_vec()));
+        let mut packets = BitPacketReader::new(reader);
+        assert!(packets.next().unwrap().is_ok());
+        let error = packets.next().unwrap().unwrap_err();
+        assert_eq!(
+            error.get_kind(),
+            BitDecodeErrorKind::ReadFailed(io::ErrorKind::Other)
+        );
+        assert!(packets.next().is_none());
+    }
+}
@@ -1,10 +1,16 @@
-use crate::bit_evaluate::BitEvaluate;
+use crate::bit_decode_error::{BitDecodeError, BitDecodeErrorKind};
+use crate::bit_evaluate::{BitEvaluate, BitEvaluateChecked, BitEvaluateError};
+use crate::bit_number::BitNumber;
 use crate::bit_packet::BitPacket;
 use crate::bit_packet_version::BitPacketVersion;
 use crate::from_bitvec::FromBitvec;
+use crate::to_bitvec::ToBitvec;
 use aoc_core::bit::bit_size::BitSize;
 use aoc_core::bit::bitvec::BitVec;
+use aoc_core::error::ParseError;
+use std::fmt::{Display, Formatter};
 use std::ops::Index;
+use std::str::FromStr;
 
 #[derive(Debug)]
 pub struct BitTrans {
@@ -13,30 +19,92 @@ pub struct BitTrans {
 }
 
 impl BitTrans {
-    pub fn new(bits: BitVec<u8>) -> Self {
-        let packet = BitPacket::from_bitvec(&bits, 0);
-        BitTrans { bits, packet }
+    /// Decodes the outermost packet in `bits`. Anything after it is padding, which has to be all zeroes.
+    pub fn new(bits: BitVec<u8>) -> Result<Self, BitDecodeError> {
+        let packet = BitPacket::from_bitvec(&bits, 0)?;
+        if let Some(set_bit) = (packet.bit_size()..bits.bit_size()).find(|idx| bits[*idx]) {
+            return Err(BitDecodeError::new(
+                BitDecodeErrorKind::NonZeroPadding,
+                set_bit,
+            ));
+        }
+        Ok(BitTrans { bits, packet })
+    }
+
+    pub fn get_packet(&self) -> &BitPacket {
+        &self.packet
     }
 
-    fn chars_to_bitvec(chars: Vec<char>) -> BitVec<u8> {
-        if chars.len() % 2 != 0 {
-            panic!("Must be a multiple of 2!");
+    /// Returns how many bits of padding there are after the outermost packet.
+    pub fn get_padding(&self) -> usize {
+        self.bits.bit_size() - self.packet.bit_size()
+    }
+
+    pub fn get_bits(&self) -> &BitVec<u8> {
+        &self.bits
+    }
+
+    /// Returns the transmission as upper-case hex, two digits per byte, like the puzzle input.
+    pub fn to_hex(&self) -> String {
+        (0..self.bits.bit_size())
+            .step_by(8)
+            .map(|idx| format!("{:02X}", self.bits.get_range_as_int((idx..idx + 8).rev())))
+            .collect()
+    }
+
+    fn hex_to_bitvec(hex: &str) -> Result<BitVec<u8>, ParseError> {
+        if let Some(index) = hex.find(|c: char| !c.is_ascii_hexdigit()) {
+            let end = index + hex[index..].chars().next().map_or(0, char::len_utf8);
+            return Err(ParseError::within(
+                "Expected a hex digit",
+                hex,
+                &hex[index..end],
+            ));
         }
-        let pairs = chars
-            .chunks(2)
-            .map(|win| String::from_iter(win.iter()))
-            .map(|st| u8::from_str_radix(st.as_str(), 16))
-            .map(|res| res.expect("Could not parse!"))
+        if !hex.len().is_multiple_of(2) {
+            return Err(ParseError::new(
+                "Expected an even number of hex digits",
+                hex,
+            ));
+        }
+        let pairs = (0..hex.len())
+            .step_by(2)
+            .map(|idx| {
+                u8::from_str_radix(&hex[idx..idx + 2], 16).expect("Already checked for hex digits!")
+            })
             .map(|val| val.reverse_bits()) // Reverse the order of the bits, we want to read/index left-to-right (index 0 is the *most* significant bit of the first block)
             .collect::<Vec<_>>();
-        let bits = BitVec::from_int(pairs);
-        bits
+
+        Ok(BitVec::from_int(pairs))
     }
 }
 
-impl From<Vec<char>> for BitTrans {
-    fn from(chars: Vec<char>) -> Self {
-        BitTrans::new(BitTrans::chars_to_bitvec(chars))
+impl From<BitPacket> for BitTrans {
+    /// Encodes `packet`, padding the end with zeroes out to a whole number of bytes.
+    fn from(packet: BitPacket) -> Self {
+        let mut bits = BitVec::with_capacity(packet.bit_size());
+        packet.to_bitvec(&mut bits, 0);
+        BitTrans { bits, packet }
+    }
+}
+
+impl Display for BitTrans {
+    /// Writes the outermost packet as an expression, see [`BitPacket`]'s `Display`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.packet, f)
+    }
+}
+
+impl FromStr for BitTrans {
+    type Err = ParseError;
+
+    fn from_str(hex: &str) -> Result<Self, Self::Err> {
+        BitTrans::new(BitTrans::hex_to_bitvec(hex)?).map_err(|e| {
+            // Point at the hex digit the bit is in, or past the end if the transmission ended early.
+            let digit = (e.get_bit() / 4).min(hex.len());
+            let end = (digit + 1).min(hex.len());
+            ParseError::within(e.to_string(), hex, &hex[digit..end])
+        })
     }
 }
 
@@ -64,6 +132,12 @@ impl BitEvaluate for BitTrans {
     }
 }
 
+impl BitEvaluateChecked for BitTrans {
+    fn evaluate_as<N: BitNumber>(&self) -> Result<N, BitEvaluateError> {
+        self.packet.evaluate_as()
+    }
+}
+
 impl BitSize for BitTrans {
     fn bit_size(&self) -> usize {
         self.bits.bit_size()
@@ -71,16 +145,24 @@ impl BitSize for BitTrans {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison, clippy::manual_range_patterns)]
 mod tests {
+    use crate::bit_operator_size_type::BitOperatorSizeType;
+    use crate::bit_operator_type::BitOperatorType;
+    use crate::bit_packet::BitPacket;
+    use crate::bit_packet_data_operator::BitPacketDataOperator;
+    use crate::bit_packet_type::BitPacketType;
     use crate::bit_trans::BitTrans;
-    use crate::BitPacketVersion;
+    use crate::{BitEvaluate, BitPacketVersion, Day16};
     use aoc_core::bit::bit_size::BitSize;
-    use aoc_core::puzzle_input::PuzzleInput;
+    use aoc_core::input_resolver::InputResolver;
+    use aoc_core::solution::Solution;
+    use std::str::FromStr;
 
     #[test]
     fn test_known_input() {
-        let input = PuzzleInput::new("../aoc-16/input.txt");
-        let bits = BitTrans::from(input.as_string().chars().collect::<Vec<_>>());
+        let input = InputResolver::workspace().load(16).unwrap();
+        let bits = BitTrans::from_str(input.as_string()).unwrap();
         // 02 should be (left-to-right is 0-to-7) 00000010
         for i in 0..8usize {
             match i {
@@ -106,23 +188,23 @@ mod tests {
 
     #[test]
     fn bitvec_input() {
-        let input = PuzzleInput::new("../aoc-16/input.txt");
-        let bitvec = BitTrans::chars_to_bitvec(input.as_string().chars().collect());
+        let input = InputResolver::workspace().load(16).unwrap();
+        let bitvec = BitTrans::hex_to_bitvec(input.as_string()).unwrap();
         let first_size = bitvec.get_range_as_int((7..18).rev());
         assert_eq!(first_size, 53);
     }
 
     #[test]
     fn display_bitvec() {
-        let input = PuzzleInput::new("../aoc-16/input.txt");
-        let bitvec = BitTrans::chars_to_bitvec(input.as_string().chars().collect());
+        let input = InputResolver::workspace().load(16).unwrap();
+        let bitvec = BitTrans::hex_to_bitvec(input.as_string()).unwrap();
         println!("{}", bitvec);
     }
 
     #[test]
     fn example_input() {
         let result = bools_from_binary_string("11010010 11111110 00101000");
-        let bitvec = BitTrans::chars_to_bitvec("D2FE28".chars().collect());
+        let bitvec = BitTrans::hex_to_bitvec("D2FE28").unwrap();
         assert_eq!(result.len(), bitvec.bit_size());
         for (idx, val) in result.iter().enumerate() {
             assert_eq!(&bitvec[idx], val);
@@ -139,7 +221,7 @@ mod tests {
         assert_eq!(bitvec[22], false);
         assert_eq!(bitvec[23], false);
 
-        let bittrans = BitTrans::new(bitvec);
+        let bittrans = BitTrans::new(bitvec).unwrap();
         assert_eq!(bittrans.sum_version(), 6);
         assert_eq!(bittrans.bit_size(), 24);
     }
@@ -149,16 +231,113 @@ mod tests {
         let result = bools_from_binary_string(
             "0011 1000 0000 0000 0110 1111 0100 0101 0010 1001 0001 0010 0000 0000",
         );
-        let bitvec = BitTrans::chars_to_bitvec("38006F45291200".chars().collect());
+        let bitvec = BitTrans::hex_to_bitvec("38006F45291200").unwrap();
         assert_eq!(result.len(), bitvec.bit_size());
         for (idx, val) in result.iter().enumerate() {
             assert_eq!(&bitvec[idx], val);
         }
-        let bittrans = BitTrans::new(bitvec);
+        let bittrans = BitTrans::new(bitvec).unwrap();
         assert_eq!(bittrans.packet.get_version().unwrap(), 1);
         assert_eq!(bittrans.sum_version(), 9);
     }
 
+    #[test]
+    fn invalid_hex() {
+        let error = BitTrans::hex_to_bitvec("D2FG28").unwrap_err();
+        assert_eq!(error.get_column(), 4);
+        assert_eq!(error.get_text(), "G");
+        assert!(BitTrans::hex_to_bitvec("D2FE2").is_err());
+    }
+
+    #[test]
+    fn encode_literal() {
+        let bittrans = BitTrans::from(BitPacket::literal(6, 2021));
+        assert_eq!(bittrans.to_hex(), "D2FE28");
+        assert_eq!(BitTrans::from(BitPacket::literal(2, 0)).to_hex(), "5000");
+    }
+
+    #[test]
+    fn encode_operator() {
+        let sub = vec![BitPacket::literal(6, 10), BitPacket::literal(2, 20)];
+        let op = BitPacketDataOperator::with_bit_count(BitOperatorType::LessThan, sub.clone());
+        assert_eq!(op.get_sub_size(), BitOperatorSizeType::Bits(27));
+        let bittrans = BitTrans::from(BitPacket::new(1, BitPacketType::Operator(op)));
+        assert_eq!(bittrans.to_hex(), "38006F45291200");
+
+        let packet = BitPacket::operator(1, BitOperatorType::LessThan, sub);
+        assert_eq!(
+            BitTrans::from_str(&BitTrans::from(packet.clone()).to_hex())
+                .unwrap()
+                .get_packet(),
+            &packet
+        );
+    }
+
+    #[test]
+    fn round_trip_transmissions() {
+        let input = InputResolver::workspace().load(16).unwrap();
+        let examples = Day16::EXAMPLES.iter().map(|example| example.input);
+        for hex in examples.chain([input.as_string().trim_end()]) {
+            let decoded = BitTrans::from_str(hex).unwrap();
+            let encoded = BitTrans::from(decoded.get_packet().clone());
+            assert_eq!(encoded.get_packet(), decoded.get_packet());
+            // Transmissions can be padded with more zeroes than it takes to fill out the last byte.
+            let hex = hex.to_ascii_uppercase();
+            let padding = hex.strip_prefix(&encoded.to_hex()).unwrap();
+            assert!(padding.chars().all(|c| c == '0'), "{}", hex);
+        }
+    }
+
+    #[test]
+    fn round_trip_generated() {
+        let mut rng = Rng(0x2021_1216);
+        for _ in 0..500 {
+            let packet = random_packet(&mut rng, 4);
+            let hex = BitTrans::from(packet.clone()).to_hex();
+            let decoded = BitTrans::from_str(&hex).unwrap();
+            assert_eq!(decoded.get_packet(), &packet, "{}", hex);
+            assert_eq!(decoded.sum_version(), packet.sum_version());
+            assert_eq!(decoded.evaluate(), packet.evaluate());
+        }
+    }
+
+    /// A tiny xorshift generator, so the generated packets are the same every run.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self, bound: u64) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0 % bound
+        }
+    }
+
+    fn random_packet(rng: &mut Rng, depth: u32) -> BitPacket {
+        let version = rng.next(8) as u8;
+        if depth == 0 || rng.next(3) == 0 {
+            // Keep literals small enough that evaluating a product of them can't overflow.
+            let bits = rng.next(12);
+            return BitPacket::literal(version, rng.next(1 << bits));
+        }
+        let op = [0, 1, 2, 3, 5, 6, 7][rng.next(7) as usize];
+        let op = BitOperatorType::try_from(op).unwrap();
+        let count = match op {
+            BitOperatorType::GreaterThan | BitOperatorType::LessThan | BitOperatorType::Equal => 2,
+            BitOperatorType::Product => 1 + rng.next(3),
+            _ => 1 + rng.next(5),
+        };
+        let sub = (0..count)
+            .map(|_| random_packet(rng, depth - 1))
+            .collect::<Vec<_>>();
+        let op = if rng.next(2) == 0 {
+            BitPacketDataOperator::with_bit_count(op, sub)
+        } else {
+            BitPacketDataOperator::with_packet_count(op, sub)
+        };
+        BitPacket::new(version, BitPacketType::Operator(op))
+    }
+
     fn bools_from_binary_string(bin: &str) -> Vec<bool> {
         bin.chars()
             .filter(|c| c == &'1' || c == &'0')
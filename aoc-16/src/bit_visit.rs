@@ -0,0 +1,249 @@
+use crate::bit_packet::BitPacket;
+use crate::bit_packet_type::BitPacketType;
+use aoc_core::bit::bit_size::BitSize;
+
+/// A packet somewhere in a tree of packets, and where it is in that tree.
+#[derive(Debug, Copy, Clone)]
+pub struct BitPacketVisit<'a> {
+    pub packet: &'a BitPacket,
+    /// How many operators the packet is inside of, which is 0 for the outermost packet.
+    pub depth: usize,
+    /// The offset of the packet's first bit, from the start of the outermost packet.
+    pub bit: usize,
+}
+
+impl<'a> BitPacketVisit<'a> {
+    pub fn new(packet: &'a BitPacket) -> Self {
+        BitPacketVisit {
+            packet,
+            depth: 0,
+            bit: 0,
+        }
+    }
+
+    /// Returns each of the packet's sub-packets, and where they are. Literals don't have any.
+    pub fn get_sub_visits(&self) -> Vec<BitPacketVisit<'a>> {
+        let op = match self.packet.get_data() {
+            BitPacketType::Literal(_) => return Vec::new(),
+            BitPacketType::Operator(op) => op,
+        };
+        // Sub-packets start after the version, the type and the length.
+        let mut bit = self.bit + 6 + op.get_sub_size().bit_size();
+        op.get_sub()
+            .iter()
+            .map(|packet| {
+                let visit = BitPacketVisit {
+                    packet,
+                    depth: self.depth + 1,
+                    bit,
+                };
+                bit += packet.bit_size();
+                visit
+            })
+            .collect()
+    }
+}
+
+/// Something that looks at every packet in a tree, like [`BitPacket::walk`] does.
+pub trait BitPacketVisitor {
+    /// Called on each packet before any of its sub-packets.
+    fn enter(&mut self, _visit: &BitPacketVisit<'_>) {}
+
+    /// Called on each packet after all of its sub-packets.
+    fn leave(&mut self, _visit: &BitPacketVisit<'_>) {}
+}
+
+impl BitPacket {
+    /// Walks every packet in the tree, depth first, starting with this one.
+    pub fn walk<V: BitPacketVisitor>(&self, visitor: &mut V) {
+        walk_visit(&BitPacketVisit::new(self), visitor);
+    }
+
+    /// Returns every packet in the tree in pre-order, so each packet comes before its sub-packets.
+    pub fn iter(&self) -> BitPacketIter<'_> {
+        BitPacketIter {
+            stack: vec![BitPacketVisit::new(self)],
+        }
+    }
+
+    /// Folds the tree into a single value in post-order, calling `f` on each packet along with what its sub-packets folded into.
+    pub fn fold<T, F>(&self, mut f: F) -> T
+    where
+        F: FnMut(&BitPacketVisit<'_>, Vec<T>) -> T,
+    {
+        fold_visit(&BitPacketVisit::new(self), &mut f)
+    }
+}
+
+fn walk_visit<V: BitPacketVisitor>(visit: &BitPacketVisit<'_>, visitor: &mut V) {
+    visitor.enter(visit);
+    for sub_visit in visit.get_sub_visits() {
+        walk_visit(&sub_visit, visitor);
+    }
+    visitor.leave(visit);
+}
+
+fn fold_visit<T, F>(visit: &BitPacketVisit<'_>, f: &mut F) -> T
+where
+    F: FnMut(&BitPacketVisit<'_>, Vec<T>) -> T,
+{
+    let sub = visit
+        .get_sub_visits()
+        .iter()
+        .map(|sub_visit| fold_visit(sub_visit, f))
+        .collect();
+    f(visit, sub)
+}
+
+/// An iterator over every packet in a tree, see [`BitPacket::iter`].
+pub struct BitPacketIter<'a> {
+    /// Packets still to be visited, with the next one on top.
+    stack: Vec<BitPacketVisit<'a>>,
+}
+
+impl<'a> Iterator for BitPacketIter<'a> {
+    type Item = BitPacketVisit<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let visit = self.stack.pop()?;
+        self.stack.extend(visit.get_sub_visits().into_iter().rev());
+        Some(visit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bit_operator_type::BitOperatorType;
+    use crate::bit_packet::BitPacket;
+    use crate::bit_packet_type::BitPacketType;
+    use crate::bit_trans::BitTrans;
+    use crate::bit_visit::{BitPacketVisit, BitPacketVisitor};
+    use crate::from_bitvec::FromBitvec;
+    use crate::{BitEvaluate, BitPacketVersion, Day16};
+    use aoc_core::input_resolver::InputResolver;
+    use aoc_core::solution::Solution;
+    use std::collections::HashMap;
+    use std::str::FromStr;
+
+    fn transmissions() -> Vec<BitTrans> {
+        let input = InputResolver::workspace().load(16).unwrap();
+        let examples = Day16::EXAMPLES.iter().map(|example| example.input);
+        examples
+            .chain([input.as_string().trim_end()])
+            .map(|hex| BitTrans::from_str(hex).unwrap())
+            .collect()
+    }
+
+    /// Writes out the packets it enters and leaves, to check the order they're visited in.
+    struct Tracer(Vec<String>);
+
+    impl BitPacketVisitor for Tracer {
+        fn enter(&mut self, visit: &BitPacketVisit<'_>) {
+            self.0.push(format!("+{:#}", visit.packet));
+        }
+
+        fn leave(&mut self, visit: &BitPacketVisit<'_>) {
+            self.0.push(format!("-{:#}", visit.packet));
+        }
+    }
+
+    #[test]
+    fn walk() {
+        let packet = BitPacket::from_str("sum(1, max(2))").unwrap();
+        let mut tracer = Tracer(Vec::new());
+        packet.walk(&mut tracer);
+        assert_eq!(
+            tracer.0,
+            vec![
+                "+sum(1, max(2))",
+                "+1",
+                "-1",
+                "+max(2)",
+                "+2",
+                "-2",
+                "-max(2)",
+                "-sum(1, max(2))"
+            ]
+        );
+    }
+
+    #[test]
+    fn iter() {
+        let bits = BitTrans::from_str("9C0141080250320F1802104A08").unwrap();
+        let visits = bits
+            .get_packet()
+            .iter()
+            .map(|visit| (format!("{:#}", visit.packet), visit.depth))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            visits,
+            vec![
+                ("eq(sum(1, 3), product(2, 2))".to_string(), 0),
+                ("sum(1, 3)".to_string(), 1),
+                ("1".to_string(), 2),
+                ("3".to_string(), 2),
+                ("product(2, 2)".to_string(), 1),
+                ("2".to_string(), 2),
+                ("2".to_string(), 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn bit_offsets() {
+        for bits in transmissions() {
+            for visit in bits.get_packet().iter() {
+                let decoded = BitPacket::from_bitvec(bits.get_bits(), visit.bit).unwrap();
+                assert_eq!(&decoded, visit.packet);
+            }
+        }
+    }
+
+    #[test]
+    fn fold() {
+        for bits in transmissions() {
+            let packet = bits.get_packet();
+            let sum_version = packet.fold(|visit, sub: Vec<u64>| {
+                sub.iter().sum::<u64>() + visit.packet.get_version().unwrap() as u64
+            });
+            assert_eq!(sum_version, bits.sum_version());
+            let count = packet.fold(|_, sub: Vec<usize>| 1 + sub.iter().sum::<usize>());
+            assert_eq!(count, packet.iter().count());
+            let evaluate = packet.fold(|visit, sub: Vec<u64>| match visit.packet.get_data() {
+                BitPacketType::Literal(lit) => lit.get_value(),
+                BitPacketType::Operator(op) => match op.get_op() {
+                    BitOperatorType::Sum => sub.iter().sum(),
+                    BitOperatorType::Product => sub.iter().product(),
+                    BitOperatorType::Minimum => *sub.iter().min().unwrap(),
+                    BitOperatorType::Maximum => *sub.iter().max().unwrap(),
+                    BitOperatorType::GreaterThan => (sub[0] > sub[1]) as u64,
+                    BitOperatorType::LessThan => (sub[0] < sub[1]) as u64,
+                    BitOperatorType::Equal => (sub[0] == sub[1]) as u64,
+                },
+            });
+            assert_eq!(evaluate, bits.evaluate());
+        }
+    }
+
+    #[test]
+    fn stats() {
+        let input = InputResolver::workspace().load(16).unwrap();
+        let bits = BitTrans::from_str(input.as_string().trim_end()).unwrap();
+        let depth = bits.get_packet().iter().map(|visit| visit.depth).max();
+        assert!(depth.unwrap() > 0);
+
+        let mut op_counts = HashMap::new();
+        for visit in bits.get_packet().iter() {
+            if let BitPacketType::Operator(op) = visit.packet.get_data() {
+                *op_counts.entry(op.get_op()).or_insert(0) += 1;
+            }
+        }
+        let literals = bits
+            .get_packet()
+            .iter()
+            .filter(|visit| matches!(visit.packet.get_data(), BitPacketType::Literal(_)))
+            .count();
+        let ops: usize = op_counts.values().sum();
+        assert_eq!(literals + ops, bits.get_packet().iter().count());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::bit_decode_error::{BitDecodeError, BitDecodeErrorKind};
 use aoc_core::bit::bit_size::BitSize;
 use aoc_core::bit::bitty::Bitty;
 use aoc_core::bit::bitvec::BitVec;
@@ -6,7 +7,23 @@ pub trait FromBitvec<B>
 where
     B: Bitty,
 {
-    fn from_bitvec(bitvec: &BitVec<B>, start_bit: usize) -> Self
+    fn from_bitvec(bitvec: &BitVec<B>, start_bit: usize) -> Result<Self, BitDecodeError>
     where
-        Self: BitSize;
+        Self: BitSize + Sized;
+}
+
+/// Reads the `len` bits starting at `start_bit` as an int, where the first bit is the *most* significant.
+/// Returns an error instead of panicking if the bitvec ends first.
+pub fn read_int<B: Bitty>(
+    bitvec: &BitVec<B>,
+    start_bit: usize,
+    len: usize,
+) -> Result<u64, BitDecodeError> {
+    if start_bit + len > bitvec.bit_size() {
+        return Err(BitDecodeError::new(
+            BitDecodeErrorKind::UnexpectedEnd,
+            start_bit,
+        ));
+    }
+    Ok(bitvec.get_range_as_int((start_bit..start_bit + len).rev()))
 }
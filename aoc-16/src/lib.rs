@@ -0,0 +1,76 @@
+pub mod bit_decode_error;
+pub mod bit_evaluate;
+pub mod bit_expression;
+pub mod bit_number;
+pub mod bit_operator_size_type;
+pub mod bit_operator_type;
+pub mod bit_packet;
+pub mod bit_packet_data_literal;
+pub mod bit_packet_data_operator;
+pub mod bit_packet_type;
+pub mod bit_packet_version;
+pub mod bit_stream;
+pub mod bit_trans;
+pub mod bit_visit;
+pub mod from_bitvec;
+pub mod to_bitvec;
+
+use crate::bit_evaluate::BitEvaluate;
+use crate::bit_packet_version::BitPacketVersion;
+use crate::bit_trans::BitTrans;
+use aoc_core::error::ParseError;
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::solution::{Example, Solution};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const EXAMPLES: &'static [Example] = &[
+        Example::part_one("8A004A801A8002F478", "16"),
+        Example::part_one("620080001611562C8802118E34", "12"),
+        Example::part_one("C0015000016115A2E0802F182340", "23"),
+        Example::part_one("A0016C880162017C3686B18A3D4780", "31"),
+        Example::part_two("C200B40A82", "3"),
+        Example::part_two("04005AC33890", "54"),
+        Example::part_two("880086C3E88112", "7"),
+        Example::part_two("CE00C43D881120", "9"),
+        Example::part_two("D8005AC2A8F0", "1"),
+        Example::part_two("F600BC2D8F", "0"),
+        Example::part_two("9C005AC2F8F0", "0"),
+        Example::part_two("9C0141080250320F1802104A08", "1"),
+    ];
+    type Parsed = BitTrans;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+        input.as_string().trim_end().parse()
+    }
+
+    /// Sum of all packet versions.
+    fn part_one(bits: &Self::Parsed) -> Self::PartOne {
+        bits.sum_version()
+    }
+
+    /// Result of evaluating the outermost packet.
+    fn part_two(bits: &Self::Parsed) -> Self::PartTwo {
+        bits.evaluate()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day16;
+    use aoc_core::harness::{assert_answers, assert_examples};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day16>();
+    }
+
+    #[test]
+    fn answers() {
+        assert_answers::<Day16>();
+    }
+}
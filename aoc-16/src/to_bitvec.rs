@@ -0,0 +1,14 @@
+use aoc_core::bit::bit_size::BitSize;
+use aoc_core::bit::bitty::Bitty;
+use aoc_core::bit::bitvec::BitVec;
+
+/// The inverse of [`crate::from_bitvec::FromBitvec`].
+pub trait ToBitvec<B>
+where
+    B: Bitty,
+{
+    /// Writes this to `bitvec`, starting at `start_bit`. The bitvec has to have room for all [`BitSize::bit_size`] bits.
+    fn to_bitvec(&self, bitvec: &mut BitVec<B>, start_bit: usize)
+    where
+        Self: BitSize;
+}
@@ -1,23 +1,40 @@
+use aoc_core::error::ParseError;
+use aoc_core::pattern::Pattern;
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::solution::{Example, Solution};
 use std::ops::RangeInclusive;
+use std::str::FromStr;
 
-const X_MIN: i32 = 117;
-const X_MAX: i32 = 164;
-const Y_MIN: i32 = -140;
-const Y_MAX: i32 = -89;
-
-fn main() {
-    let launcher = ProbeLauncher::new(X_MIN..=X_MAX, Y_MIN..=Y_MAX);
-    let probes = launcher.find_probe_vectors();
-    let highest = probes
-        .iter()
-        .fold(0, |acc, val| if val.max_y > acc { val.max_y } else { acc });
-    println!("Highest possible probe height: {:?}", highest);
-    let count = probes.len();
-    println!("Number of unique initial probe vectors: {:?}", count);
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const EXAMPLES: &'static [Example] =
+        &[Example::both(include_str!("../example.txt"), "45", "112")];
+    type Parsed = ProbeLauncher;
+    type PartOne = i32;
+    type PartTwo = usize;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+        input.as_string().trim_end().parse()
+    }
+
+    /// Highest possible probe height.
+    fn part_one(launcher: &Self::Parsed) -> Self::PartOne {
+        launcher
+            .find_probe_vectors()
+            .iter()
+            .fold(0, |acc, val| if val.max_y > acc { val.max_y } else { acc })
+    }
+
+    /// Number of unique initial probe vectors.
+    fn part_two(launcher: &Self::Parsed) -> Self::PartTwo {
+        launcher.find_probe_vectors().len()
+    }
 }
 
 #[derive(Debug, Copy, Clone)]
-struct ProbeState {
+pub struct ProbeState {
     x: i32,
     y: i32,
     velocity_x: i32,
@@ -37,7 +54,7 @@ impl ProbeState {
     }
 }
 
-struct ProbeLauncher {
+pub struct ProbeLauncher {
     x_bound: RangeInclusive<i32>,
     y_bound: RangeInclusive<i32>,
 }
@@ -86,7 +103,7 @@ impl ProbeLauncher {
                 return Some(state);
             }
             // If we've gone past, we've missed.
-            if state.x > X_MAX || state.y < Y_MIN {
+            if state.x > *self.x_bound.end() || state.y < *self.y_bound.start() {
                 break;
             }
         }
@@ -97,7 +114,7 @@ impl ProbeLauncher {
     /// Returns the first value of x that could possibly hit. Triangle numbers are back again!
     fn get_lowest_velocity_x(&self) -> i32 {
         let mut velocity_x = 1;
-        while (1..=velocity_x).fold(0, |acc, val| acc + val) < *self.x_bound.start() {
+        while (1..=velocity_x).sum::<i32>() < *self.x_bound.start() {
             velocity_x += 1;
         }
         velocity_x
@@ -127,7 +144,7 @@ impl ProbeLauncher {
             } else {
                 misses += 1;
             }
-            if misses > X_MAX - X_MIN {
+            if misses > self.x_bound.end() - self.x_bound.start() {
                 break;
             }
         }
@@ -136,7 +153,7 @@ impl ProbeLauncher {
 
     /// Returns the first value of y that could possibly hit.
     fn get_lowest_velocity_y(&self) -> i32 {
-        Y_MIN
+        *self.y_bound.start()
     }
 
     /// Returns the last value of y that could possibly hit. There is going to eventually be a value of y that skips over the entire y range.
@@ -160,10 +177,47 @@ impl ProbeLauncher {
             } else {
                 misses += 1;
             }
-            if misses > ((Y_MIN - Y_MAX) * -1) {
+            if misses > -(self.y_bound.start() - self.y_bound.end()) {
                 break;
             }
         }
         highest_velocity_y
     }
 }
+
+impl FromStr for ProbeLauncher {
+    type Err = ParseError;
+
+    /// Parses the target area, like `target area: x=20..30, y=-10..-5`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (x_start, x_end, y_start, y_end): (i32, i32, i32, i32) =
+            Pattern::new("target area: x={}..{}, y={}..{}").parse(input)?;
+        if x_start > x_end || y_start > y_end {
+            return Err(ParseError::new("Range starts after it ends", input));
+        }
+        // The launcher only knows how to fire forwards, and into targets below it.
+        if x_start <= 0 || y_end >= 0 {
+            return Err(ParseError::new(
+                "Target area must be ahead of and below the launcher",
+                input,
+            ));
+        }
+        Ok(ProbeLauncher::new(x_start..=x_end, y_start..=y_end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day17;
+    use aoc_core::harness::{assert_answers, assert_examples};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day17>();
+    }
+
+    #[test]
+    fn answers() {
+        assert_answers::<Day17>();
+    }
+}
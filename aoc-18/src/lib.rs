@@ -0,0 +1,95 @@
+use crate::snail_pair::{SnailPair, MAX_SUM};
+use aoc_core::error::ParseError;
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::solution::{Example, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub mod snail_pair;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const EXAMPLES: &'static [Example] = &[Example::both(
+        include_str!("../example.txt"),
+        "4140",
+        "3993",
+    )];
+    type Parsed = Vec<SnailPair>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    /// Turns down lines too big to add to any other line, since every line gets added to every other one.
+    fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+        let snail_pairs: Vec<SnailPair> = input.parse_lines()?;
+        // A reduced number adds up to 144 at most, so a sum can be added to any line too.
+        let max_line_sum = MAX_SUM / 2;
+        for (i, (snail_pair, line)) in snail_pairs.iter().zip(input.to_lines()).enumerate() {
+            if !matches!(snail_pair.get_literal_sum(), Some(sum) if sum <= max_line_sum) {
+                let reason = format!("Expected literals adding up to at most {}", max_line_sum);
+                return Err(ParseError::new(reason, line).offset_lines(i));
+            }
+        }
+        Ok(snail_pairs)
+    }
+
+    /// Magnitude of the final sum.
+    fn part_one(snail_pairs: &Self::Parsed) -> Self::PartOne {
+        let mut snail_pairs = snail_pairs.iter().cloned();
+        let first = snail_pairs.next().unwrap();
+        let snail_sum = snail_pairs.fold(first, |acc, val| {
+            acc.checked_add(val)
+                .expect("Lines were checked to be small enough when parsing!")
+        });
+        snail_sum.get_magnitude().expect("Magnitude overflowed!")
+    }
+
+    /// Greatest magnitude of any single addition of two different numbers.
+    fn part_two(snail_pairs: &Self::Parsed) -> Self::PartTwo {
+        let snail_pairs = snail_pairs.iter().collect::<HashSet<_>>();
+        let mut magnitudes = HashMap::<(&SnailPair, &SnailPair), u64>::new();
+        for left in &snail_pairs {
+            for right in &snail_pairs {
+                if left == right {
+                    continue;
+                }
+                let mag = (*left)
+                    .clone()
+                    .checked_add((*right).clone())
+                    .expect("Lines were checked to be small enough when parsing!")
+                    .get_magnitude()
+                    .expect("Magnitude overflowed!");
+                magnitudes.insert((left, right), mag);
+            }
+        }
+        magnitudes
+            .values()
+            .fold(0u64, |acc, val| if &acc > val { acc } else { *val })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day18;
+    use aoc_core::harness::{assert_answers, assert_examples};
+    use aoc_core::puzzle_input::PuzzleInput;
+    use aoc_core::solution::Solution;
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day18>();
+    }
+
+    #[test]
+    fn answers() {
+        assert_answers::<Day18>();
+    }
+
+    #[test]
+    fn too_big() {
+        let input = PuzzleInput::from("[1,2]\n[[3,500000],4]");
+        let error = Day18::parse(&input).unwrap_err();
+        assert_eq!(error.get_line(), 2);
+        assert!(Day18::parse(&PuzzleInput::from("[1,2]\n[3,499997]")).is_ok());
+    }
+}
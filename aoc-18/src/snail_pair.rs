@@ -1,398 +1,543 @@
-use regex::Regex;
-use std::fmt::{Debug, Formatter};
-use std::ops::Add;
-
+use aoc_core::error::ParseError;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+
+/// The most the literals in a number can add up to and still be reduced.
+///
+/// Reducing moves literals around a half at a time, and there's no shortcut for where they end up, so the bigger they are,
+/// the longer it takes. Numbers that add up to this much reduce in a few milliseconds.
+pub const MAX_SUM: u64 = 1_000_000;
+
+/// A snailfish number, stored as its literals from left to right along with how many pairs each one is inside of.
+///
+/// That's enough to tell where every pair starts and ends, and keeping the literals in one flat list means parsing,
+/// reducing, comparing and dropping a number never recurses, however deeply its pairs are nested.
+///
+/// Literals can be as big as fits in a `u64`, but reducing one that big would take practically forever, so only numbers
+/// whose literals add up to at most [`MAX_SUM`] can be added or reduced. That's why there's [`SnailPair::checked_add`]
+/// rather than `+`.
 #[derive(Eq, PartialEq, Hash, Clone)]
 pub struct SnailPair {
-    lhs: SnailPairElement,
-    rhs: SnailPairElement,
+    literals: Vec<SnailLiteral>,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+struct SnailLiteral {
+    value: u64,
+    /// How many pairs the literal is inside of, so a literal in the outermost pair is at depth 1.
+    depth: usize,
 }
 
 impl SnailPair {
+    /// Returns how long the pair is when written out.
     pub fn str_size(&self) -> usize {
-        self.lhs.str_size() + self.rhs.str_size() + 3
+        let digits: usize = self
+            .literals
+            .iter()
+            .map(|literal| literal.value.to_string().len())
+            .sum();
+        // Every pair has two brackets and a comma, and there's one fewer pair than there are literals.
+        digits + (self.literals.len() - 1) * 3
     }
 
-    pub fn get_magnitude(&self) -> u64 {
-        (self.lhs.get_magnitude() * 3) + (self.rhs.get_magnitude() * 2)
+    /// Returns None if the magnitude doesn't fit in a `u64`.
+    pub fn get_magnitude(&self) -> Option<u64> {
+        // The magnitudes of every complete pair (or lone literal) seen so far, and how deep they are.
+        let mut stack: Vec<(u64, usize)> = Vec::new();
+        for literal in &self.literals {
+            let (mut magnitude, mut depth) = (literal.value, literal.depth);
+            // Whatever's on top of the stack at the same depth is the left side of the same pair.
+            while let Some((lhs, _)) = stack.last().filter(|(_, lhs_depth)| *lhs_depth == depth) {
+                magnitude = lhs.checked_mul(3)?.checked_add(magnitude.checked_mul(2)?)?;
+                depth -= 1;
+                stack.pop();
+            }
+            stack.push((magnitude, depth));
+        }
+        stack.pop().map(|(magnitude, _)| magnitude)
     }
 
-    fn reduce(&mut self) {
-        loop {
-            if self.explode(0).is_some() {
-                continue;
-            }
-            if self.split().is_some() {
-                continue;
-            }
+    /// Returns what every literal in the number adds up to, or None if that doesn't fit in a `u64`.
+    pub fn get_literal_sum(&self) -> Option<u64> {
+        self.literals
+            .iter()
+            .try_fold(0u64, |sum, literal| sum.checked_add(literal.value))
+    }
 
-            break;
-        }
+    /// Adds two numbers and reduces the result, or returns an error if their literals add up to more than [`MAX_SUM`].
+    pub fn checked_add(self, rhs: Self) -> Result<Self, SnailOverflow> {
+        let literals = self
+            .literals
+            .into_iter()
+            .chain(rhs.literals)
+            .map(|literal| SnailLiteral {
+                value: literal.value,
+                depth: literal.depth + 1,
+            })
+            .collect();
+        let mut pair = SnailPair { literals };
+        pair.reduce()?;
+        Ok(pair)
     }
 
-    fn explode(&mut self, depth: u8) -> Option<SnailPairExplodeResult> {
-        let result = match &mut self.lhs {
-            SnailPairElement::Literal(_) => None,
-            SnailPairElement::Pair(p) => {
-                if depth == 3 {
-                    let explode_left = match p.lhs {
-                        SnailPairElement::Literal(val) => val,
-                        _ => panic!("Invalid data!"),
-                    };
-                    let explode_right = match p.rhs {
-                        SnailPairElement::Literal(val) => val,
-                        _ => panic!("Invalid data!"),
-                    };
-                    Some(SnailPairExplodeResult::new(
-                        explode_left,
-                        explode_right,
-                        false,
-                    ))
-                } else {
-                    p.explode(depth + 1).take().map(|mut res| {
-                        res.from_right = false;
-                        res
-                    })
-                }
-            }
+    /// Explodes and splits until there's nothing left to explode or split, however deep the number started out.
+    ///
+    /// Returns an error without changing anything if the literals add up to more than [`MAX_SUM`].
+    /// Exploding and splitting never make them add up to any more, so no literal can overflow along the way.
+    pub fn reduce(&mut self) -> Result<(), SnailOverflow> {
+        match self.get_literal_sum() {
+            Some(sum) if sum <= MAX_SUM => {}
+            _ => return Err(SnailOverflow),
         }
-        .or_else(|| match &mut self.rhs {
-            SnailPairElement::Literal(_) => None,
-            SnailPairElement::Pair(p) => {
-                if depth == 3 {
-                    let explode_left = match p.lhs {
-                        SnailPairElement::Literal(val) => val,
-                        _ => panic!("Invalid data!"),
-                    };
-                    let explode_right = match p.rhs {
-                        SnailPairElement::Literal(val) => val,
-                        _ => panic!("Invalid data!"),
-                    };
-                    Some(SnailPairExplodeResult::new(
-                        explode_left,
-                        explode_right,
-                        true,
-                    ))
-                } else {
-                    p.explode(depth + 1).take().map(|mut res| {
-                        res.from_right = true;
-                        res
-                    })
+        // Splitting never makes a pair that isn't exploded straight away, so every explosion can happen up front.
+        self.explode();
+        self.split();
+        Ok(())
+    }
+
+    /// Explodes every pair of two literals that's nested inside four or more pairs, leftmost first.
+    /// Pairs nested even deeper than that explode from the inside out, one at a time.
+    fn explode(&mut self) {
+        // Everything in `done` is left of where the next explosion could be, so each literal is only looked at a few times.
+        let mut done: Vec<SnailLiteral> = Vec::with_capacity(self.literals.len());
+        // The literals still to look at, backwards, so the next one is at the end.
+        let mut rest: Vec<SnailLiteral> = self.literals.drain(..).rev().collect();
+        // How deep every complete pair (or lone literal) in `done` is, from left to right.
+        let mut shape: Vec<usize> = Vec::new();
+        while let Some(next) = rest.pop() {
+            let depth = next.depth;
+            // If something complete at the same depth comes right before it, it's the right side of a pair.
+            let is_rhs = shape.last() == Some(&depth);
+            let sibling = if is_rhs {
+                done.last().filter(|lhs| lhs.depth == depth)
+            } else {
+                rest.last().filter(|rhs| rhs.depth == depth)
+            };
+            if sibling.is_none() || depth <= 4 {
+                done.push(next);
+                shape.push(depth);
+                while shape.len() > 1 && shape[shape.len() - 2] == shape[shape.len() - 1] {
+                    shape.pop();
+                    *shape.last_mut().unwrap() -= 1;
                 }
+                continue;
             }
-        });
-
-        if let Some(mut res) = result {
-            if res.should_remove {
-                if res.from_right {
-                    self.rhs = SnailPairElement::Literal(0);
-                    self.lhs.add_left(res.left.take().unwrap());
-                } else {
-                    self.lhs = SnailPairElement::Literal(0);
-                    self.rhs.add_right(res.right.take().unwrap());
-                }
-                res.should_remove = false;
+
+            let (lhs, rhs) = if is_rhs {
+                shape.pop();
+                (done.pop().unwrap(), next)
             } else {
-                if res.from_right {
-                    if let Some(left_val) = res.left.take() {
-                        self.lhs.add_left(left_val);
-                    }
-                } else {
-                    if let Some(right_val) = res.right.take() {
-                        self.rhs.add_right(right_val);
-                    }
-                }
+                (next, rest.pop().unwrap())
+            };
+            if let Some(left) = done.last_mut() {
+                left.value += lhs.value;
             }
-            return Some(res);
+            if let Some(right) = rest.last_mut() {
+                right.value += rhs.value;
+            }
+            rest.push(SnailLiteral {
+                value: 0,
+                depth: depth - 1,
+            });
         }
-        None
+        self.literals = done;
     }
 
-    fn split(&mut self) -> Option<SnailPairSplitResult> {
-        let result = match &mut self.lhs {
-            SnailPairElement::Literal(val) => {
-                if *val >= 10 {
-                    Some(SnailPairSplitResult::new(*val, false))
-                } else {
-                    None
-                }
+    /// Splits the leftmost literal of 10 or more until there are none left, once nothing is nested deep enough to explode.
+    /// A literal nested inside four pairs splits into a pair that explodes straight away, which happens in one step.
+    fn split(&mut self) {
+        let mut i = 0;
+        while let Some(literal) = self.literals.get(i).copied() {
+            if literal.value < 10 {
+                i += 1;
+                continue;
             }
-            SnailPairElement::Pair(p) => p.split(),
-        }
-        .or_else(|| match &mut self.rhs {
-            SnailPairElement::Literal(val) => {
-                if *val >= 10 {
-                    Some(SnailPairSplitResult::new(*val, true))
-                } else {
-                    None
-                }
+            let lhs = literal.value / 2;
+            let rhs = literal.value - lhs;
+            if literal.depth < 4 {
+                let depth = literal.depth + 1;
+                self.literals[i] = SnailLiteral { value: lhs, depth };
+                self.literals
+                    .insert(i + 1, SnailLiteral { value: rhs, depth });
+                continue;
+            }
+
+            self.literals[i].value = 0;
+            if let Some(right) = self.literals.get_mut(i + 1) {
+                right.value += rhs;
+            }
+            // The literal to the left got bigger, so it might need splitting now.
+            if i > 0 {
+                self.literals[i - 1].value += lhs;
+                i -= 1;
             }
-            SnailPairElement::Pair(p) => p.split(),
-        });
-        if let Some(mut res) = result {
-            if let Some(val) = res.value.take() {
-                let next_left = val / 2;
-                let next_right = next_left + (val % 2);
-                let next = SnailPairElement::Pair(Box::new(SnailPair {
-                    lhs: SnailPairElement::Literal(next_left),
-                    rhs: SnailPairElement::Literal(next_right),
-                }));
-                if res.from_right {
-                    self.rhs = next;
-                } else {
-                    self.lhs = next;
-                };
-            };
-            return Some(res);
         }
-        None
     }
 }
 
 impl Debug for SnailPair {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str(format!("[{:?},{:?}]", self.lhs, self.rhs).as_str())?;
-        Ok(())
-    }
-}
-
-impl From<&str> for SnailPair {
-    fn from(input: &str) -> Self {
-        let exp = Regex::new("\\[(.*)]").unwrap();
-        let cap = exp.captures(input).unwrap();
-        let inner = cap.get(1).unwrap().as_str();
-        let lhs_first = inner.chars().next().unwrap().to_digit(10);
-        let lhs = match lhs_first {
-            None => SnailPairElement::Pair(Box::new(inner.into())),
-            Some(digit) => SnailPairElement::Literal(digit as u8),
-        };
-        let rhs_start = 1 + lhs.str_size();
-        let rhs_first = inner.chars().nth(rhs_start).unwrap().to_digit(10);
-        let rhs = match rhs_first {
-            None => SnailPairElement::Pair(Box::new(inner[rhs_start..].into())),
-            Some(digit) => SnailPairElement::Literal(digit as u8),
-        };
-        SnailPair { lhs, rhs }
+        Display::fmt(self, f)
     }
 }
 
-impl Add for SnailPair {
-    type Output = Self;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        let mut pair = SnailPair {
-            lhs: SnailPairElement::Pair(Box::new(self)),
-            rhs: SnailPairElement::Pair(Box::new(rhs)),
-        };
-        pair.reduce();
-        pair
-    }
-}
-
-#[derive(Eq, PartialEq, Hash, Clone)]
-enum SnailPairElement {
-    Literal(u8),
-    Pair(Box<SnailPair>),
-}
-
-impl SnailPairElement {
-    fn str_size(&self) -> usize {
-        match self {
-            SnailPairElement::Literal(_) => 1,
-            SnailPairElement::Pair(p) => p.str_size(),
+impl Display for SnailPair {
+    /// Writes the pair the same way it's parsed, like `[1,[23,4]]`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // For each pair that's been opened but not closed, whether its left side has been written yet.
+        let mut open: Vec<bool> = Vec::new();
+        for literal in &self.literals {
+            while open.len() < literal.depth {
+                f.write_str("[")?;
+                open.push(false);
+            }
+            write!(f, "{}", literal.value)?;
+            while let Some(wrote_lhs) = open.last_mut() {
+                if !*wrote_lhs {
+                    f.write_str(",")?;
+                    *wrote_lhs = true;
+                    break;
+                }
+                f.write_str("]")?;
+                open.pop();
+            }
         }
+        Ok(())
     }
+}
 
-    fn get_magnitude(&self) -> u64 {
-        match self {
-            SnailPairElement::Literal(val) => *val as u64,
-            SnailPairElement::Pair(p) => p.get_magnitude(),
-        }
+impl SnailPair {
+    /// Parses a literal of any number of digits starting at `pos`, returning it along with the position right after it ends.
+    fn parse_literal(input: &str, pos: usize) -> Result<(u64, usize), ParseError> {
+        let len = input[pos..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(input.len() - pos);
+        let digits = &input[pos..pos + len];
+        let value = digits.parse().map_err(|_| {
+            ParseError::within("Expected a number that fits in 64 bits", input, digits)
+        })?;
+        Ok((value, pos + len))
     }
 
-    fn add_left(&mut self, other: u8) {
-        match self {
-            SnailPairElement::Literal(val) => *val += other,
-            SnailPairElement::Pair(p) => p.rhs.add_left(other),
+    fn check_char(input: &str, pos: usize, expected: char) -> Result<usize, ParseError> {
+        match input[pos..].chars().next() {
+            Some(c) if c == expected => Ok(pos + 1),
+            _ => Err(SnailPair::error_at(
+                input,
+                pos,
+                format!("Expected {:?}", expected),
+            )),
         }
     }
 
-    fn add_right(&mut self, other: u8) {
-        match self {
-            SnailPairElement::Literal(val) => *val += other,
-            SnailPairElement::Pair(p) => p.lhs.add_right(other),
-        }
+    fn error_at<S: Into<String>>(input: &str, pos: usize, reason: S) -> ParseError {
+        let end = pos + input[pos..].chars().next().map_or(0, char::len_utf8);
+        ParseError::within(reason, input, &input[pos..end])
     }
 }
 
-impl Debug for SnailPairElement {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            SnailPairElement::Literal(val) => f.write_str(format!("{}", val).as_str())?,
-            SnailPairElement::Pair(p) => p.fmt(f)?,
+impl FromStr for SnailPair {
+    type Err = ParseError;
+
+    /// Parses a whole pair like `[1,[23,4]]`, with literals of any size that fits in a `u64` and pairs nested as deep as they like.
+    /// Only numbers whose literals add up to at most [`MAX_SUM`] can be reduced, though.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut literals = Vec::new();
+        // For each pair that's been opened but not closed, whether its left side has been parsed yet.
+        let mut open = vec![false];
+        let mut pos = SnailPair::check_char(input, 0, '[')?;
+        'element: loop {
+            match input[pos..].chars().next() {
+                Some('[') => {
+                    open.push(false);
+                    pos += 1;
+                    continue;
+                }
+                Some(c) if c.is_ascii_digit() => {
+                    let (value, end) = SnailPair::parse_literal(input, pos)?;
+                    let depth = open.len();
+                    literals.push(SnailLiteral { value, depth });
+                    pos = end;
+                }
+                _ => {
+                    return Err(SnailPair::error_at(
+                        input,
+                        pos,
+                        "Expected a number or a pair",
+                    ))
+                }
+            }
+            // Close every pair that's just had its right side parsed, until one is still waiting for its right side.
+            while let Some(parsed_lhs) = open.last_mut() {
+                if !*parsed_lhs {
+                    pos = SnailPair::check_char(input, pos, ',')?;
+                    *parsed_lhs = true;
+                    continue 'element;
+                }
+                pos = SnailPair::check_char(input, pos, ']')?;
+                open.pop();
+            }
+            break;
         }
-        Ok(())
-    }
-}
-
-struct SnailPairExplodeResult {
-    left: Option<u8>,
-    right: Option<u8>,
-    should_remove: bool,
-    from_right: bool,
-}
-
-impl SnailPairExplodeResult {
-    fn new(left: u8, right: u8, from_right: bool) -> Self {
-        SnailPairExplodeResult {
-            left: Some(left),
-            right: Some(right),
-            from_right,
-            should_remove: true,
+        if pos != input.len() {
+            return Err(SnailPair::error_at(
+                input,
+                pos,
+                "Expected the end of the line",
+            ));
         }
+        Ok(SnailPair { literals })
     }
 }
 
-struct SnailPairSplitResult {
-    value: Option<u8>,
-    from_right: bool,
-}
+/// The literals in a number added up to more than [`MAX_SUM`], too much to reduce.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct SnailOverflow;
 
-impl SnailPairSplitResult {
-    fn new(value: u8, from_right: bool) -> Self {
-        SnailPairSplitResult {
-            value: Some(value),
-            from_right,
-        }
+impl Display for SnailOverflow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Literals add up to more than {}, too much to reduce",
+            MAX_SUM
+        )
     }
 }
 
+impl Error for SnailOverflow {}
+
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
-    use crate::snail_pair::{SnailPair, SnailPairElement};
-    use aoc_core::puzzle_input::PuzzleInput;
-    use regex::Regex;
+    use crate::snail_pair::{SnailLiteral, SnailOverflow, SnailPair, MAX_SUM};
+    use aoc_core::input_resolver::InputResolver;
     use std::collections::HashMap;
+    use std::str::FromStr;
 
     #[test]
     fn can_parse_known_input() {
         // Input: [[5,[[8,5],8]],[[9,3],[0,3]]]
-        let input = PuzzleInput::new("../aoc-18/input.txt");
+        let input = InputResolver::workspace().load(18).unwrap();
         let mut lines = input.to_lines();
-        let first = SnailPair::from(lines.next().unwrap());
-        // Should've picked a shorter input geez
+        let first = SnailPair::from_str(lines.next().unwrap()).unwrap();
         let known = SnailPair {
-            lhs: SnailPairElement::Pair(Box::new(SnailPair {
-                lhs: SnailPairElement::Literal(5),
-                rhs: SnailPairElement::Pair(Box::new(SnailPair {
-                    lhs: SnailPairElement::Pair(Box::new(SnailPair {
-                        lhs: SnailPairElement::Literal(8),
-                        rhs: SnailPairElement::Literal(5),
-                    })),
-                    rhs: SnailPairElement::Literal(8),
-                })),
-            })),
-            rhs: SnailPairElement::Pair(Box::new(SnailPair {
-                lhs: SnailPairElement::Pair(Box::new({
-                    SnailPair {
-                        lhs: SnailPairElement::Literal(9),
-                        rhs: SnailPairElement::Literal(3),
-                    }
-                })),
-                rhs: SnailPairElement::Pair(Box::new({
-                    SnailPair {
-                        lhs: SnailPairElement::Literal(0),
-                        rhs: SnailPairElement::Literal(3),
-                    }
-                })),
-            })),
+            literals: [
+                (5, 2),
+                (8, 4),
+                (5, 4),
+                (8, 3),
+                (9, 3),
+                (3, 3),
+                (0, 3),
+                (3, 3),
+            ]
+            .into_iter()
+            .map(|(value, depth)| SnailLiteral { value, depth })
+            .collect(),
         };
         assert_eq!(first, known);
-        while let Some(line) = lines.next() {
-            SnailPair::from(line); // Just ensure no panics
+        for line in lines {
+            assert!(SnailPair::from_str(line).is_ok());
         }
     }
     #[test]
     fn can_parse_all_input() {
-        let input = PuzzleInput::new("../aoc-18/input.txt");
+        let input = InputResolver::workspace().load(18).unwrap();
         let lines = input.to_lines();
         let lines_count = input.to_lines().count();
-        let pairs = lines.map(SnailPair::from).collect::<Vec<_>>();
+        let pairs = lines.map(SnailPair::from_str).collect::<Vec<_>>();
         assert_eq!(lines_count, pairs.len());
     }
 
     #[test]
-    fn regex_capture() {
-        let input = PuzzleInput::new("../aoc-18/input.txt");
-        let mut lines = input.to_lines();
-        let exp = Regex::new("\\[(.*)]").unwrap();
-        let cap = exp.captures(lines.next().unwrap()).unwrap();
-        assert_eq!(cap.get(1).unwrap().as_str(), "[5,[[8,5],8]],[[9,3],[0,3]]");
+    fn cannot_parse_invalid_input() {
+        let error = SnailPair::from_str("[[1,2],x]").unwrap_err();
+        assert_eq!(error.get_column(), 8);
+        assert_eq!(error.get_text(), "x");
+
+        let error = SnailPair::from_str("[[1,2],3").unwrap_err();
+        assert_eq!(error.get_column(), 9);
+
+        assert!(SnailPair::from_str("[1,2]]").is_err());
+        assert!(SnailPair::from_str("[1 ,2]").is_err());
+        assert!(SnailPair::from_str("1").is_err());
+        assert!(SnailPair::from_str("").is_err());
+
+        let error = SnailPair::from_str("[1,99999999999999999999]").unwrap_err();
+        assert_eq!(error.get_column(), 4);
+        assert_eq!(error.get_text(), "99999999999999999999");
+
+        let line = format!("{}1,1]{}", "[".repeat(200_000), ",1]".repeat(199_998));
+        let error = SnailPair::from_str(&line).unwrap_err();
+        assert_eq!(error.get_column(), line.len() + 1);
+    }
+
+    #[test]
+    fn display_round_trips() {
+        let input = InputResolver::workspace().load(18).unwrap();
+        let lines = [
+            "[123,[[[[[[4,56],7],8],9],10],0]]",
+            "[0,18446744073709551615]",
+            "[[1,2],[[3,4],5]]",
+        ];
+        for line in input.to_lines().chain(lines) {
+            let pair = SnailPair::from_str(line).unwrap();
+            assert_eq!(pair.to_string(), line);
+            assert_eq!(pair.str_size(), line.len());
+        }
     }
 
     #[test]
     fn explode() {
-        let mut example_1 = SnailPair::from("[[[[[9,8],1],2],3],4]");
-        example_1.explode(0);
-        assert_eq!(example_1, SnailPair::from("[[[[0,9],2],3],4]"));
+        let mut example_1 = SnailPair::from_str("[[[[[9,8],1],2],3],4]").unwrap();
+        example_1.explode();
+        assert_eq!(example_1, SnailPair::from_str("[[[[0,9],2],3],4]").unwrap());
 
-        let mut example_2 = SnailPair::from("[7,[6,[5,[4,[3,2]]]]]");
-        example_2.explode(0);
-        assert_eq!(example_2, SnailPair::from("[7,[6,[5,[7,0]]]]"));
+        let mut example_2 = SnailPair::from_str("[7,[6,[5,[4,[3,2]]]]]").unwrap();
+        example_2.explode();
+        assert_eq!(example_2, SnailPair::from_str("[7,[6,[5,[7,0]]]]").unwrap());
 
-        let mut example_3 = SnailPair::from("[[6,[5,[4,[3,2]]]],1]");
-        example_3.explode(0);
-        assert_eq!(example_3, SnailPair::from("[[6,[5,[7,0]]],3]"));
+        let mut example_3 = SnailPair::from_str("[[6,[5,[4,[3,2]]]],1]").unwrap();
+        example_3.explode();
+        assert_eq!(example_3, SnailPair::from_str("[[6,[5,[7,0]]],3]").unwrap());
 
-        let mut example_4 = SnailPair::from("[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]");
-        example_4.explode(0);
+        // Both pairs explode, one after the other.
+        let mut example_4 = SnailPair::from_str("[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]").unwrap();
+        example_4.explode();
         assert_eq!(
             example_4,
-            SnailPair::from("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]")
+            SnailPair::from_str("[[3,[2,[8,0]]],[9,[5,[7,0]]]]").unwrap()
         );
 
-        let mut example_5 = SnailPair::from("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]");
-        example_5.explode(0);
-        assert_eq!(example_5, SnailPair::from("[[3,[2,[8,0]]],[9,[5,[7,0]]]]"));
+        let mut example_5 = SnailPair::from_str("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]").unwrap();
+        example_5.explode();
+        assert_eq!(
+            example_5,
+            SnailPair::from_str("[[3,[2,[8,0]]],[9,[5,[7,0]]]]").unwrap()
+        );
     }
 
     #[test]
     fn split() {
-        let mut example_1 = SnailPair::from("[[[[0,7],4],[7,[[8,4],9]]],[1,1]]");
-        example_1.explode(0);
-        example_1.split();
+        let mut example_1 = SnailPair::from_str("[[[[0,7],4],[7,[[8,4],9]]],[1,1]]").unwrap();
+        example_1.explode();
+        assert_eq!(example_1.to_string(), "[[[[0,7],4],[15,[0,13]]],[1,1]]");
         example_1.split();
         assert_eq!(
             example_1,
-            SnailPair::from("[[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]")
+            SnailPair::from_str("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]").unwrap()
         );
+
+        // Splitting a literal nested inside four pairs explodes the new pair straight away.
+        let mut example_2 = SnailPair::from_str("[[[[1,11],1],1],1]").unwrap();
+        example_2.split();
+        assert_eq!(example_2.to_string(), "[[[[6,0],7],1],1]");
+        let mut example_3 = SnailPair::from_str("[21,1]").unwrap();
+        example_3.split();
+        assert_eq!(example_3.to_string(), "[[[5,5],[5,6]],1]");
     }
 
     #[test]
     fn reduce() {
-        let mut example_1 = SnailPair::from("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]");
-        example_1.reduce();
+        let mut example_1 = SnailPair::from_str("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]").unwrap();
+        example_1.reduce().unwrap();
         assert_eq!(
             example_1,
-            SnailPair::from("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]")
+            SnailPair::from_str("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]").unwrap()
+        );
+    }
+
+    #[test]
+    fn reduce_big_literals() {
+        // The step right before splitting, from the example that walks through a whole addition.
+        let mut example_1 = SnailPair::from_str("[[[[0,7],4],[15,[0,13]]],[1,1]]").unwrap();
+        example_1.reduce().unwrap();
+        assert_eq!(example_1.to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+
+        let mut example_2 = SnailPair::from_str("[1000,1]").unwrap();
+        example_2.reduce().unwrap();
+        assert!(is_reduced(&example_2));
+    }
+
+    #[test]
+    fn overflow() {
+        let line = "[18446744073709551615,[[[[1,1],0],0],0]]";
+        let mut example_1 = SnailPair::from_str(line).unwrap();
+        assert_eq!(example_1.get_literal_sum(), None);
+        assert_eq!(example_1.reduce(), Err(SnailOverflow));
+        assert_eq!(example_1.to_string(), line);
+
+        let example_2 = SnailPair::from_str("[18446744073709551615,9]").unwrap();
+        assert_eq!(example_2.get_magnitude(), None);
+        let example_3 = SnailPair::from_str("[[9,1],[1,9]]").unwrap();
+        assert_eq!(example_3.get_magnitude(), Some(129));
+    }
+
+    #[test]
+    fn reduce_too_big() {
+        // Too big to reduce in any reasonable time, so it's turned down straight away instead.
+        let line = "[[1,[2,18446744073709551614]],[3,4]]";
+        let mut example_1 = SnailPair::from_str(line).unwrap();
+        assert_eq!(example_1.reduce(), Err(SnailOverflow));
+        assert_eq!(example_1.to_string(), line);
+        let example_2 = SnailPair::from_str(&format!("[1,{}]", MAX_SUM)).unwrap();
+        assert_eq!(
+            example_2.checked_add(SnailPair::from_str("[0,0]").unwrap()),
+            Err(SnailOverflow)
         );
+
+        // The biggest that can be reduced, in a few milliseconds.
+        let line = format!("[[1,[2,{}]],[3,4]]", MAX_SUM - 10);
+        let mut example_3 = SnailPair::from_str(&line).unwrap();
+        assert_eq!(example_3.get_literal_sum(), Some(MAX_SUM));
+        example_3.reduce().unwrap();
+        assert!(is_reduced(&example_3));
+    }
+
+    #[test]
+    fn reduce_deep_pairs() {
+        // [1,2] explodes first, since it's the leftmost pair of two literals, then [0,5] is.
+        let mut example_1 = SnailPair::from_str("[[[[[[1,2],3],4],5],6],7]").unwrap();
+        example_1.reduce().unwrap();
+        assert_eq!(example_1.to_string(), "[[[[0,9],5],6],7]");
+
+        let line = format!("{}[1,2]{}", "[".repeat(30), ",3]".repeat(30));
+        let mut example_2 = SnailPair::from_str(&line).unwrap();
+        example_2.reduce().unwrap();
+        assert!(is_reduced(&example_2));
+
+        // Nothing recurses once per pair, so a line can be nested as deep as it likes.
+        let line = format!("{}1,1]{}", "[".repeat(200_000), ",1]".repeat(199_999));
+        let example_3 = SnailPair::from_str(&line).unwrap();
+        assert_eq!(example_3.to_string(), line);
+        let sum = example_3.clone().checked_add(example_3).unwrap();
+        assert!(is_reduced(&sum));
+        assert!(sum.get_magnitude().is_some());
+    }
+
+    /// Checks that no pair is nested inside four pairs, and no literal is 10 or more.
+    fn is_reduced(pair: &SnailPair) -> bool {
+        pair.literals
+            .iter()
+            .all(|literal| literal.value < 10 && literal.depth <= 4)
     }
 
     #[test]
     fn hash() {
         let mut set = HashMap::<SnailPair, bool>::new();
         set.insert(
-            SnailPair::from("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]"),
+            SnailPair::from_str("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]").unwrap(),
             true,
         );
         let prev = set.insert(
-            SnailPair::from("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]"),
+            SnailPair::from_str("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]").unwrap(),
             false,
         );
         assert_eq!(set.len(), 1);
         assert_eq!(prev.unwrap(), true);
         assert_eq!(
-            set.get(&SnailPair::from("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]"))
+            set.get(&SnailPair::from_str("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]").unwrap())
                 .unwrap(),
             &false
         );
@@ -1,5 +1,8 @@
 use crate::rotation::{Rotation, RotationAxis};
+use aoc_core::error::ParseError;
+use aoc_core::pattern::Pattern;
 use std::fmt::{Debug, Formatter};
+use std::str::FromStr;
 
 #[derive(PartialEq, Eq, Hash, Copy, Clone)]
 pub struct Beacon {
@@ -44,21 +47,21 @@ impl Beacon {
         match axis {
             RotationAxis::A(flip) => {
                 if *flip {
-                    self.a * -1
+                    -self.a
                 } else {
                     self.a
                 }
             }
             RotationAxis::B(flip) => {
                 if *flip {
-                    self.b * -1
+                    -self.b
                 } else {
                     self.b
                 }
             }
             RotationAxis::C(flip) => {
                 if *flip {
-                    self.c * -1
+                    -self.c
                 } else {
                     self.c
                 }
@@ -67,13 +70,12 @@ impl Beacon {
     }
 }
 
-impl From<&str> for Beacon {
-    fn from(input: &str) -> Self {
-        let mut split = input.split(",");
-        let a = split.next().unwrap().parse().unwrap();
-        let b = split.next().unwrap().parse().unwrap();
-        let c = split.next().unwrap().parse().unwrap();
-        Beacon::new(a, b, c)
+impl FromStr for Beacon {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (a, b, c) = Pattern::new("{},{},{}").parse(input)?;
+        Ok(Beacon::new(a, b, c))
     }
 }
 
@@ -1,7 +1,8 @@
 use crate::scanner::Scanner;
+use aoc_core::error::ParseError;
+use aoc_core::puzzle_input::PuzzleInput;
 use std::collections::{HashSet, VecDeque};
 use std::fmt::{Debug, Formatter};
-use std::str::Lines;
 
 pub struct BeaconMap {
     /// Our source of truth scanner. We will attempt to transform each unassociated scanner
@@ -26,7 +27,7 @@ impl BeaconMap {
 
     pub fn associate_all(&mut self) {
         let mut retry = self.unassociated.len();
-        while self.unassociated.len() > 0 {
+        while !self.unassociated.is_empty() {
             if retry == 0 {
                 // I added this because I thought this may loop forever. Just turned out that the program wasn't very efficient.
                 panic!("Went through the whole queue and never found a match!");
@@ -65,20 +66,19 @@ impl BeaconMap {
     }
 }
 
-impl From<Lines<'_>> for BeaconMap {
-    fn from(mut input: Lines) -> Self {
-        let mut scanners: VecDeque<Scanner> = VecDeque::new();
-        loop {
-            if input.next().is_none() {
-                break;
-            }
-            // Pass Scanner::from a mutable reference, so it can consumer the iterator until it hits a terminator.
-            // This loop will pick up where it leaves off.
-            let scanner = Scanner::from(&mut input);
-            scanners.push_back(scanner);
+impl TryFrom<&PuzzleInput> for BeaconMap {
+    type Error = ParseError;
+
+    fn try_from(input: &PuzzleInput) -> Result<Self, Self::Error> {
+        let scanners = input
+            .sections()
+            .map(Scanner::try_from)
+            .collect::<Result<VecDeque<_>, _>>()?;
+        if scanners.is_empty() {
+            return Err(ParseError::new("Expected at least one scanner", ""));
         }
 
-        BeaconMap::new(scanners)
+        Ok(BeaconMap::new(scanners))
     }
 }
 
@@ -102,12 +102,13 @@ impl Debug for BeaconMap {
 #[cfg(test)]
 mod tests {
     use crate::beacon_map::BeaconMap;
+    use aoc_core::input_resolver::InputResolver;
     use aoc_core::puzzle_input::PuzzleInput;
 
     #[test]
     fn parse_example() {
-        let input = PuzzleInput::new("../aoc-19/example.txt");
-        let map = BeaconMap::from(input.to_lines());
+        let input = PuzzleInput::from(include_str!("../example.txt"));
+        let map = BeaconMap::try_from(&input).unwrap();
         assert_eq!(map.unassociated.len(), 4);
         assert_eq!(map.authority.count(), 25);
         assert_eq!(map.unassociated[3].count(), 26);
@@ -115,15 +116,15 @@ mod tests {
 
     #[test]
     fn parse_input() {
-        let input = PuzzleInput::new("../aoc-19/input.txt");
-        let map = BeaconMap::from(input.to_lines());
+        let input = InputResolver::workspace().load(19).unwrap();
+        let map = BeaconMap::try_from(&input).unwrap();
         assert_eq!(map.unassociated.len(), 37);
     }
 
     #[test]
     fn debug() {
-        let input = PuzzleInput::new("../aoc-19/example.txt");
-        let map = BeaconMap::from(input.to_lines());
+        let input = PuzzleInput::from(include_str!("../example.txt"));
+        let map = BeaconMap::try_from(&input).unwrap();
         print!("{:?}", map);
     }
 }
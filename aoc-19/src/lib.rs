@@ -0,0 +1,53 @@
+pub mod beacon;
+pub mod beacon_map;
+pub mod rotation;
+pub mod scanner;
+
+use crate::beacon_map::BeaconMap;
+use aoc_core::error::ParseError;
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::solution::{Example, Solution};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const EXAMPLES: &'static [Example] =
+        &[Example::both(include_str!("../example.txt"), "79", "3621")];
+    type Parsed = BeaconMap;
+    type PartOne = usize;
+    type PartTwo = i32;
+
+    /// Associating every scanner is by far the slowest step, and both parts need it, so it's done once up front.
+    fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+        let mut map = BeaconMap::try_from(input)?;
+        map.associate_all();
+        Ok(map)
+    }
+
+    /// Final beacon count.
+    fn part_one(map: &Self::Parsed) -> Self::PartOne {
+        map.count()
+    }
+
+    /// Maximum distance between any two scanners.
+    fn part_two(map: &Self::Parsed) -> Self::PartTwo {
+        map.find_max_distance()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day19;
+    use aoc_core::harness::{assert_answers, assert_examples};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day19>();
+    }
+
+    #[test]
+    fn answers() {
+        assert_answers::<Day19>();
+    }
+}
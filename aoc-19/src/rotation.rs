@@ -84,21 +84,21 @@ mod tests {
         match rotate {
             RotationAxis::A(flip) => {
                 if *flip {
-                    initial.0 * -1
+                    -initial.0
                 } else {
                     initial.0
                 }
             }
             RotationAxis::B(flip) => {
                 if *flip {
-                    initial.1 * -1
+                    -initial.1
                 } else {
                     initial.1
                 }
             }
             RotationAxis::C(flip) => {
                 if *flip {
-                    initial.2 * -1
+                    -initial.2
                 } else {
                     initial.2
                 }
@@ -1,8 +1,9 @@
 use crate::beacon::Beacon;
 use crate::rotation::{Rotation, ROTATIONS};
+use aoc_core::error::{parse_lines, ParseError};
+use aoc_core::puzzle_input::Section;
 use std::collections::HashSet;
 use std::fmt::{Debug, Formatter};
-use std::str::Lines;
 
 #[derive(Clone)]
 pub struct Scanner {
@@ -39,8 +40,7 @@ impl Scanner {
                     }
                 }
                 if match_count >= 12 {
-                    let scanner_location =
-                        (translation.0 * -1, translation.1 * -1, translation.2 * -1);
+                    let scanner_location = (-translation.0, -translation.1, -translation.2);
                     return Some((other_translated, scanner_location));
                 }
             }
@@ -82,17 +82,19 @@ impl Scanner {
     }
 }
 
-impl From<&mut Lines<'_>> for Scanner {
-    fn from(input: &mut Lines<'_>) -> Self {
-        let mut beacons: HashSet<Beacon> = HashSet::new();
-        while let Some(line) = input.next() {
-            if line == "" {
-                break;
-            }
-            let beacon = Beacon::from(line);
-            beacons.insert(beacon);
+impl TryFrom<Section<'_>> for Scanner {
+    type Error = ParseError;
+
+    /// Parses a `--- scanner N ---` header, followed by a beacon on each line.
+    fn try_from(section: Section<'_>) -> Result<Self, Self::Error> {
+        let mut lines = section.to_lines();
+        let header = lines.next().unwrap_or_default();
+        if !header.starts_with("--- scanner") {
+            return Err(section.locate(ParseError::new("Expected a scanner header", header)));
         }
-        Scanner::new(beacons)
+        let beacons: Vec<Beacon> =
+            parse_lines(lines).map_err(|e| section.locate(e.offset_lines(1)))?;
+        Ok(Scanner::new(beacons.into_iter().collect()))
     }
 }
 
@@ -114,12 +116,10 @@ mod tests {
     // This helped me a ton, to be able to look at the current output of the scanners at each intermediate step and ask "okay, what now?".
     #[test]
     fn is_match() {
-        let input = PuzzleInput::new("../aoc-19/example.txt");
-        let mut lines = input.to_lines();
-        lines.next();
-        let authority = Scanner::from(&mut lines);
-        lines.next();
-        let first = Scanner::from(&mut lines);
+        let input = PuzzleInput::from(include_str!("../example.txt"));
+        let mut sections = input.sections();
+        let authority = Scanner::try_from(sections.next().unwrap()).unwrap();
+        let first = Scanner::try_from(sections.next().unwrap()).unwrap();
         assert_eq!(authority.count(), 25);
         assert_eq!(first.count(), 25);
         let first_transformed = authority.is_match(&first).unwrap();
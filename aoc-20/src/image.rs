@@ -1,92 +1,68 @@
 use crate::image_algorithm::ImageAlgorithm;
-use crate::image_point::ImagePoint;
-use std::collections::BTreeSet;
+use aoc_core::error::ParseError;
+use aoc_core::grid::Grid;
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::sparse_grid::{SparseGrid, SparsePoint};
 use std::fmt::{Display, Formatter};
-use std::str::Lines;
 
+#[derive(Clone)]
 pub struct Image {
     alg: ImageAlgorithm,
-    /// The set of all _lit_ pixels. We're not really using any particular property
-    /// of the BTreeSet anymore, but originally this was a BTreeMap because we did
-    /// care about the order of key-value pairs (which is why Ord is implemented for ImagePoint).
-    pix: BTreeSet<ImagePoint>,
-    /// Top left corner and bottom right corner
-    bounds: (ImagePoint, ImagePoint),
+    /// Every pixel of the infinite image. The background is usually dark, but an algorithm that lights up
+    /// dark surroundings will flip it back and forth between dark and light on every enhancement.
+    pix: SparseGrid<bool>,
     enhanced_count: usize,
 }
 
 impl Image {
     pub fn enhance(&mut self) {
-        let next = self.calculate_enhance();
+        // Every pixel outside the bounds is surrounded by nothing but background, so they all enhance the same way.
+        let background_index = if *self.pix.get_background() { 511 } else { 0 };
+        let mut next = SparseGrid::new(self.alg.get(background_index));
+        if let Some((min, max)) = self.pix.get_bounds() {
+            for y in (min.1 - 1)..=(max.1 + 1) {
+                for x in (min.0 - 1)..=(max.0 + 1) {
+                    next.set((x, y), self.get_enhancement_at_point((x, y)));
+                }
+            }
+        }
         self.pix = next;
-        self.bounds = (
-            ImagePoint::new(self.bounds.0.x - 1, self.bounds.0.y - 1),
-            ImagePoint::new(self.bounds.1.x + 1, self.bounds.1.y + 1),
-        );
         self.enhanced_count += 1;
     }
 
-    pub fn count_light(&self) -> usize {
-        self.pix.len()
+    /// Returns the number of lit pixels, or `None` if the background is lit and there are infinitely many of them.
+    pub fn count_light(&self) -> Option<usize> {
+        match self.pix.get_background() {
+            true => None,
+            false => Some(self.pix.len()),
+        }
     }
 
     pub fn get_enhance_count(&self) -> usize {
         self.enhanced_count
     }
 
-    fn get_point(&self, point: &ImagePoint) -> bool {
-        if point.x < self.bounds.0.x
-            || point.y < self.bounds.0.y
-            || point.x > self.bounds.1.x
-            || point.y > self.bounds.1.y
-        {
-            // Every point outside the bounds is deterministic. It is not always dark, but it does always alternate between dark and light.
-            self.enhanced_count % 2 == 1
-        } else {
-            self.pix.contains(point)
-        }
-    }
-
-    fn calculate_enhance(&self) -> BTreeSet<ImagePoint> {
-        let mut next = BTreeSet::new();
-
-        for y in (self.bounds.0.y - 1)..=(self.bounds.1.y + 1) {
-            for x in (self.bounds.0.x - 1)..=(self.bounds.1.x + 1) {
-                let point = ImagePoint::new(x, y);
-                let enhanced = self.get_enhancement_at_point(&point);
-                if enhanced {
-                    next.insert(point);
-                }
-            }
-        }
-
-        next
-    }
-
-    fn get_enhancement_at_point(&self, point: &ImagePoint) -> bool {
+    fn get_enhancement_at_point(&self, point: SparsePoint) -> bool {
         self.alg.get(self.get_enhancement_index(point))
     }
 
-    fn get_enhancement_index(&self, point: &ImagePoint) -> usize {
-        let points_around = point.get_points_around();
-        let mut enhancement_index = 0usize;
-        for point in points_around {
-            enhancement_index <<= 1;
-            let value = if self.get_point(&point) { 1 } else { 0 };
-            enhancement_index |= value;
-        }
-        enhancement_index
+    fn get_enhancement_index(&self, point: SparsePoint) -> usize {
+        self.pix
+            .get_window(point)
+            .into_iter()
+            .fold(0usize, |index, is_lit| (index << 1) | *is_lit as usize)
     }
 }
 
 impl Display for Image {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let padding = 100 - (self.enhanced_count as i64 * 2);
+        let (min, max) = self.pix.get_bounds().unwrap_or(((0, 0), (0, 0)));
 
-        for y in (self.bounds.0.y - (padding / 2))..=(self.bounds.1.y + (padding / 2)) {
+        for y in (min.1 - (padding / 2))..=(max.1 + (padding / 2)) {
             let mut current = String::new();
-            for x in (self.bounds.0.x - (padding / 2))..=(self.bounds.1.x + (padding / 2)) {
-                let char = match self.get_point(&ImagePoint::new(x, y)) {
+            for x in (min.0 - (padding / 2))..=(max.0 + (padding / 2)) {
+                let char = match self.pix.get((x, y)) {
                     true => "#",
                     false => ".",
                 };
@@ -99,61 +75,67 @@ impl Display for Image {
     }
 }
 
-impl From<Lines<'_>> for Image {
-    fn from(mut input: Lines<'_>) -> Self {
-        let alg_str = input.next().unwrap();
-        let alg = ImageAlgorithm::from(alg_str);
-        input.next();
-
-        let mut pix = BTreeSet::new();
-        let mut y_upper = 0;
-        let mut x_upper = 0;
-        for (y, line) in input.enumerate() {
-            for (x, char) in line.chars().enumerate() {
-                if char == '#' {
-                    pix.insert(ImagePoint::new(x as i64, y as i64));
-                }
-                if x as i64 > x_upper {
-                    x_upper = x as i64;
-                }
-            }
-            y_upper = y as i64;
-        }
-        Image {
-            pix,
+impl TryFrom<&PuzzleInput> for Image {
+    type Error = ParseError;
+
+    /// Parses the enhancement algorithm, then the image, with a blank line between them.
+    fn try_from(input: &PuzzleInput) -> Result<Self, Self::Error> {
+        let mut sections = input.sections();
+        let alg = sections
+            .next()
+            .ok_or_else(|| ParseError::new("Expected an image enhancement algorithm", ""))?
+            .parse()?;
+        let image = sections
+            .next()
+            .ok_or_else(|| ParseError::new("Expected an image", ""))?;
+
+        let grid = Grid::parse_with(image.to_lines(), ImageAlgorithm::parse_pixel)
+            .map_err(|e| image.locate(e))?;
+        Ok(Image {
+            pix: SparseGrid::from_grid(&grid, false),
             alg,
-            bounds: (ImagePoint::new(0, 0), ImagePoint::new(x_upper, y_upper)),
             enhanced_count: 0,
-        }
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::Image;
-    use aoc_core::puzzle_input::PuzzleInput;
+    use aoc_core::input_resolver::InputResolver;
 
     #[test]
     fn image_display() {
-        let input = PuzzleInput::new("../aoc-20/input.txt");
-        let image = Image::from(input.to_lines());
+        let input = InputResolver::workspace().load(20).unwrap();
+        let image = Image::try_from(&input).unwrap();
         print!("{}", image);
     }
 
     #[test]
     fn image_display_enhance() {
-        let input = PuzzleInput::new("../aoc-20/input.txt");
-        let mut image = Image::from(input.to_lines());
+        let input = InputResolver::workspace().load(20).unwrap();
+        let mut image = Image::try_from(&input).unwrap();
         image.enhance();
         print!("{}", image);
     }
 
     #[test]
     fn image_display_enhance_2() {
-        let input = PuzzleInput::new("../aoc-20/input.txt");
-        let mut image = Image::from(input.to_lines());
+        let input = InputResolver::workspace().load(20).unwrap();
+        let mut image = Image::try_from(&input).unwrap();
         image.enhance();
         image.enhance();
         print!("{}", image);
     }
+
+    #[test]
+    fn background_flips() {
+        let input = InputResolver::workspace().load(20).unwrap();
+        let mut image = Image::try_from(&input).unwrap();
+        // This algorithm lights up dark surroundings, and darkens light ones.
+        image.enhance();
+        assert_eq!(image.count_light(), None);
+        image.enhance();
+        assert_eq!(image.count_light(), Some(5583));
+    }
 }
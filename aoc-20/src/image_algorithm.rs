@@ -1,5 +1,8 @@
 use aoc_core::bit::bitvec::BitVec;
+use aoc_core::error::ParseError;
+use std::str::FromStr;
 
+#[derive(Clone)]
 pub struct ImageAlgorithm {
     bits: BitVec<u32>,
 }
@@ -10,17 +13,32 @@ impl ImageAlgorithm {
     }
 }
 
-impl From<&str> for ImageAlgorithm {
-    fn from(input: &str) -> Self {
+impl ImageAlgorithm {
+    /// Parses a single pixel, where `#` is lit and `.` is dark.
+    pub fn parse_pixel(char: char) -> Result<bool, ParseError> {
+        match char {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(ParseError::new("Expected # or .", &char.to_string())),
+        }
+    }
+}
+
+impl FromStr for ImageAlgorithm {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
         let mut bits = BitVec::with_capacity(512);
-        for (index, char) in input.chars().enumerate() {
-            let bit = match char {
-                '#' => true,
-                '.' => false,
-                _ => panic!("Invalid value: {}", char),
-            };
+        for (index, char) in input.char_indices() {
+            let bit = ImageAlgorithm::parse_pixel(char).map_err(|e| e.offset_columns(index))?;
             bits.set(index, bit);
         }
-        ImageAlgorithm { bits }
+        if input.len() != 512 {
+            return Err(ParseError::new(
+                "Expected an algorithm 512 pixels long",
+                input,
+            ));
+        }
+        Ok(ImageAlgorithm { bits })
     }
 }
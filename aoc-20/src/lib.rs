@@ -0,0 +1,59 @@
+use crate::image::Image;
+use aoc_core::error::ParseError;
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::solution::{Example, Solution};
+
+pub mod image;
+pub mod image_algorithm;
+pub mod image_point;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const EXAMPLES: &'static [Example] =
+        &[Example::both(include_str!("../example.txt"), "35", "3351")];
+    type Parsed = Image;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+        Image::try_from(input)
+    }
+
+    /// Count of light pixels after enhancing twice.
+    fn part_one(image: &Self::Parsed) -> Self::PartOne {
+        count_light_after(image, 2)
+    }
+
+    /// Count of light pixels after enhancing 50 times.
+    fn part_two(image: &Self::Parsed) -> Self::PartTwo {
+        count_light_after(image, 50)
+    }
+}
+
+fn count_light_after(image: &Image, enhance_count: usize) -> usize {
+    let mut image = image.clone();
+    while image.get_enhance_count() < enhance_count {
+        image.enhance();
+    }
+    image
+        .count_light()
+        .expect("Infinitely many pixels are lit!")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day20;
+    use aoc_core::harness::{assert_answers, assert_examples};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day20>();
+    }
+
+    #[test]
+    fn answers() {
+        assert_answers::<Day20>();
+    }
+}
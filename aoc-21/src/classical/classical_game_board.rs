@@ -1,5 +1,6 @@
 use crate::classical::deterministic_die::DeterministicDie;
 use crate::pawn::Pawn;
+use aoc_core::error::ParseError;
 use std::str::Lines;
 
 pub struct ClassicalGameBoard {
@@ -10,6 +11,15 @@ pub struct ClassicalGameBoard {
 }
 
 impl ClassicalGameBoard {
+    pub fn new(p1: Pawn, p2: Pawn) -> Self {
+        ClassicalGameBoard {
+            p1,
+            p2,
+            turn: 0,
+            die: DeterministicDie::new(),
+        }
+    }
+
     pub fn play(&mut self) {
         loop {
             let next_roll = self.roll_thrice();
@@ -43,15 +53,11 @@ impl ClassicalGameBoard {
     }
 }
 
-impl From<Lines<'_>> for ClassicalGameBoard {
-    fn from(mut input: Lines<'_>) -> Self {
-        let p1 = Pawn::from(input.next().unwrap());
-        let p2 = Pawn::from(input.next().unwrap());
-        ClassicalGameBoard {
-            p1,
-            p2,
-            turn: 0,
-            die: DeterministicDie::new(),
-        }
+impl TryFrom<Lines<'_>> for ClassicalGameBoard {
+    type Error = ParseError;
+
+    fn try_from(input: Lines<'_>) -> Result<Self, Self::Error> {
+        let (p1, p2) = Pawn::parse_players(input)?;
+        Ok(ClassicalGameBoard::new(p1, p2))
     }
 }
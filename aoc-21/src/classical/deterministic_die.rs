@@ -3,6 +3,12 @@ pub struct DeterministicDie {
     roll_count: u32,
 }
 
+impl Default for DeterministicDie {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl DeterministicDie {
     pub fn new() -> Self {
         DeterministicDie {
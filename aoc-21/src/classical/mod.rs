@@ -1,2 +1,2 @@
 pub mod classical_game_board;
-mod deterministic_die;
+pub mod deterministic_die;
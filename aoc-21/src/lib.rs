@@ -0,0 +1,64 @@
+pub mod classical;
+pub mod pawn;
+pub mod quantum;
+
+use crate::classical::classical_game_board::ClassicalGameBoard;
+use crate::pawn::Pawn;
+use crate::quantum::quantum_game_board::QuantumGameBoard;
+use aoc_core::error::ParseError;
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::solution::{Example, Solution};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const EXAMPLES: &'static [Example] = &[Example::both(
+        include_str!("../example.txt"),
+        "739785",
+        "444356092776315",
+    )];
+    type Parsed = (Pawn, Pawn);
+    type PartOne = u32;
+    type PartTwo = u64;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+        Pawn::parse_players(input.to_lines())
+    }
+
+    /// Product of the loser's score and the number of times the die was rolled.
+    fn part_one(pawns: &Self::Parsed) -> Self::PartOne {
+        let mut board = ClassicalGameBoard::new(pawns.0, pawns.1);
+        board.play();
+        let loser = match board.get_p1().get_score() > board.get_p2().get_score() {
+            true => board.get_p2(),
+            false => board.get_p1(),
+        };
+        let rolls = board.get_die().get_roll_count();
+        loser.get_score() as u32 * rolls
+    }
+
+    /// Number of universes the player who wins in more universes wins in.
+    fn part_two(pawns: &Self::Parsed) -> Self::PartTwo {
+        let mut board = QuantumGameBoard::new(pawns.0, pawns.1);
+        board.play();
+        let counts = board.count_winners();
+        counts.0.max(counts.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day21;
+    use aoc_core::harness::{assert_answers, assert_examples};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day21>();
+    }
+
+    #[test]
+    fn answers() {
+        assert_answers::<Day21>();
+    }
+}
@@ -1,3 +1,6 @@
+use aoc_core::error::{parse_number, ParseError};
+use std::str::{FromStr, Lines};
+
 #[derive(Copy, Clone, Debug)]
 pub struct Pawn {
     position: u8,
@@ -17,13 +20,32 @@ impl Pawn {
     pub fn get_score(&self) -> u16 {
         self.score
     }
+
+    /// Parses the starting positions of both players, one per line.
+    pub fn parse_players(mut input: Lines<'_>) -> Result<(Pawn, Pawn), ParseError> {
+        let p1 = input
+            .next()
+            .ok_or_else(|| ParseError::new("Expected a starting position for player 1", ""))?
+            .parse()?;
+        let p2 = input
+            .next()
+            .ok_or_else(|| {
+                ParseError::new("Expected a starting position for player 2", "").offset_lines(1)
+            })?
+            .parse()
+            .map_err(|e: ParseError| e.offset_lines(1))?;
+        Ok((p1, p2))
+    }
 }
 
-impl From<&str> for Pawn {
-    fn from(input: &str) -> Self {
-        Pawn {
-            position: input.parse().unwrap(),
-            score: 0,
+impl FromStr for Pawn {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let position = parse_number(input)?;
+        if !(1..=10).contains(&position) {
+            return Err(ParseError::new("Expected a position from 1 to 10", input));
         }
+        Ok(Pawn { position, score: 0 })
     }
 }
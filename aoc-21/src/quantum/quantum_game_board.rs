@@ -1,5 +1,6 @@
 use crate::pawn::Pawn;
 use crate::quantum::quantum_game_state::QuantumGameState;
+use aoc_core::error::ParseError;
 use std::str::Lines;
 
 pub struct QuantumGameBoard {
@@ -8,6 +9,13 @@ pub struct QuantumGameBoard {
 }
 
 impl QuantumGameBoard {
+    pub fn new(p1: Pawn, p2: Pawn) -> Self {
+        QuantumGameBoard {
+            base: QuantumGameState::new(p1, p2),
+            winners: (0, 0),
+        }
+    }
+
     pub fn play(&mut self) {
         self.play_state(self.base)
     }
@@ -19,7 +27,7 @@ impl QuantumGameBoard {
     fn play_state(&mut self, state: QuantumGameState) {
         for next in state.step() {
             if let Some(winner) = next.get_winner() {
-                if winner == true {
+                if winner {
                     self.winners.1 += next.get_weight();
                 } else {
                     self.winners.0 += next.get_weight();
@@ -31,14 +39,11 @@ impl QuantumGameBoard {
     }
 }
 
-impl From<Lines<'_>> for QuantumGameBoard {
-    fn from(mut input: Lines<'_>) -> Self {
-        let p1 = Pawn::from(input.next().unwrap());
-        let p2 = Pawn::from(input.next().unwrap());
-        let first_state = QuantumGameState::new(p1, p2);
-        QuantumGameBoard {
-            base: first_state,
-            winners: (0, 0),
-        }
+impl TryFrom<Lines<'_>> for QuantumGameBoard {
+    type Error = ParseError;
+
+    fn try_from(input: Lines<'_>) -> Result<Self, Self::Error> {
+        let (p1, p2) = Pawn::parse_players(input)?;
+        Ok(QuantumGameBoard::new(p1, p2))
     }
 }
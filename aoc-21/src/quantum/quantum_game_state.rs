@@ -25,7 +25,7 @@ impl QuantumGameState {
     }
 
     fn clone_advance(&self, count: u16, weight: u64) -> Self {
-        let mut next = self.clone();
+        let mut next = *self;
         next.weight *= weight;
         match next.turn {
             false => next.p1.advance(count),
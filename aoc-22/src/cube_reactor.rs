@@ -1,9 +1,11 @@
 use crate::cube_reactor_instruction::CubeReactorInstruction;
 use crate::cuboid_set::CuboidSet;
 use crate::cuboid_state::CuboidState;
+use aoc_core::error::{parse_lines, ParseError};
 use std::collections::VecDeque;
 use std::str::Lines;
 
+#[derive(Clone)]
 pub struct CubeReactor {
     instructions: VecDeque<CubeReactorInstruction>,
     reactor: CuboidSet,
@@ -14,8 +16,13 @@ impl CubeReactor {
         self.reactor.volume()
     }
 
+    /// Runs the initialization procedure, which is every instruction up to the first that reaches outside of -50..=50 on any axis.
     pub fn reboot_init(&mut self) {
-        for _ in 0..20 {
+        while self
+            .instructions
+            .front()
+            .is_some_and(|inst| inst.cube.is_within(-50..51))
+        {
             self.step();
         }
     }
@@ -27,7 +34,7 @@ impl CubeReactor {
     }
 
     fn has_next_step(&self) -> bool {
-        self.instructions.len() > 0
+        !self.instructions.is_empty()
     }
 
     fn step(&mut self) {
@@ -43,19 +50,17 @@ impl CubeReactor {
     }
 }
 
-impl From<Lines<'_>> for CubeReactor {
-    fn from(input: Lines<'_>) -> Self {
-        let mut instructions = VecDeque::new();
-        for line in input {
-            let inst = CubeReactorInstruction::from(line);
-            instructions.push_back(inst);
-        }
+impl TryFrom<Lines<'_>> for CubeReactor {
+    type Error = ParseError;
+
+    fn try_from(input: Lines<'_>) -> Result<Self, Self::Error> {
+        let instructions: VecDeque<CubeReactorInstruction> = parse_lines(input)?.into();
         let reactor = CuboidSet::new(vec![]);
 
-        CubeReactor {
+        Ok(CubeReactor {
             instructions,
             reactor,
-        }
+        })
     }
 }
 
@@ -82,6 +87,6 @@ mod tests {
                             on x=11..13,y=11..13,z=11..13
                             off x=9..11,y=9..11,z=9..11
                             on x=10..10,y=10..10,z=10..10"#;
-        CubeReactor::from(input.lines())
+        CubeReactor::try_from(input.lines()).unwrap()
     }
 }
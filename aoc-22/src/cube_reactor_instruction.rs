@@ -1,35 +1,71 @@
 use crate::cuboid_cube::CuboidCube;
 use crate::cuboid_state::CuboidState;
+use aoc_core::error::{parse_number, ParseError};
 use std::fmt::{Display, Formatter};
+use std::ops::Range;
+use std::str::FromStr;
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub struct CubeReactorInstruction {
     pub state: CuboidState,
     pub cube: CuboidCube,
 }
 
-impl From<&str> for CubeReactorInstruction {
-    fn from(input: &str) -> Self {
-        let mut split = input.trim().split(' ');
-        let state = CuboidState::from(split.next().unwrap());
-        let mut bounds = split.next().unwrap().split(',').map(|bound| {
-            let without_prefix = &bound[2..];
-            let mut bound_split = without_prefix.split("..");
-            let left: i32 = bound_split.next().unwrap().parse().unwrap();
-            let right: i32 = bound_split.next().unwrap().parse().unwrap();
-            if left > right {
-                panic!("Unexpected input! I don't think this happens but I sure do want to know if it does.")
+impl CubeReactorInstruction {
+    /// Parses a bound like `x=10..12`, where `axis` is the expected `x=` prefix. Bounds in the input are inclusive, so the range returned ends one later.
+    fn parse_bound(bound: &str, axis: &str) -> Result<Range<i32>, ParseError> {
+        let without_prefix = bound.strip_prefix(axis).ok_or_else(|| {
+            ParseError::new(format!("Expected a bound starting with {}", axis), bound)
+        })?;
+        let (left, right_text) = without_prefix.split_once("..").ok_or_else(|| {
+            ParseError::within("Expected a range like 1..2", bound, without_prefix)
+        })?;
+        let left: i32 = parse_number(left).map_err(|e| e.located_in(bound, left))?;
+        let right: i32 = parse_number(right_text).map_err(|e| e.located_in(bound, right_text))?;
+        if left > right {
+            return Err(ParseError::within(
+                "Range starts after it ends",
+                bound,
+                without_prefix,
+            ));
+        }
+        let end = right
+            .checked_add(1)
+            .ok_or_else(|| ParseError::within("Range ends too high to store", bound, right_text))?;
+        Ok(left..end)
+    }
+}
+
+impl FromStr for CubeReactorInstruction {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let trimmed = input.trim();
+        let (state, bounds) = trimmed
+            .split_once(' ')
+            .ok_or_else(|| ParseError::within("Expected a state and bounds", input, trimmed))?;
+        let state: CuboidState = state
+            .parse()
+            .map_err(|e: ParseError| e.located_in(input, state))?;
+        let bounds = bounds.split(',').collect::<Vec<_>>();
+        let (x, y, z) = match bounds[..] {
+            [x, y, z] => (x, y, z),
+            _ => {
+                return Err(ParseError::within(
+                    "Expected bounds for x, y and z",
+                    input,
+                    trimmed,
+                ))
             }
-            left..(right+1)
-        });
-        let x = bounds.next().unwrap();
-        let y = bounds.next().unwrap();
-        let z = bounds.next().unwrap();
+        };
+        let x = CubeReactorInstruction::parse_bound(x, "x=").map_err(|e| e.located_in(input, x))?;
+        let y = CubeReactorInstruction::parse_bound(y, "y=").map_err(|e| e.located_in(input, y))?;
+        let z = CubeReactorInstruction::parse_bound(z, "z=").map_err(|e| e.located_in(input, z))?;
 
-        CubeReactorInstruction {
+        Ok(CubeReactorInstruction {
             state,
             cube: CuboidCube::new(x, y, z),
-        }
+        })
     }
 }
 
@@ -53,11 +89,13 @@ mod tests {
     use crate::cube_reactor_instruction::CubeReactorInstruction;
     use crate::cuboid_cube::CuboidCube;
     use crate::cuboid_state::CuboidState;
+    use std::str::FromStr;
 
     #[test]
     fn from() {
         let actual =
-            CubeReactorInstruction::from("on x=61051..78708,y=39294..50128,z=-20827..1285");
+            CubeReactorInstruction::from_str("on x=61051..78708,y=39294..50128,z=-20827..1285")
+                .unwrap();
         let expected = CubeReactorInstruction {
             state: CuboidState::On,
             cube: CuboidCube::new(61051..78709, 39294..50129, -20827..1286),
@@ -65,11 +103,33 @@ mod tests {
         assert_eq!(actual, expected);
 
         let actual =
-            CubeReactorInstruction::from("off x=-27246..8755,y=4459..30303,z=-93666..-72766");
+            CubeReactorInstruction::from_str("off x=-27246..8755,y=4459..30303,z=-93666..-72766")
+                .unwrap();
         let expected = CubeReactorInstruction {
             state: CuboidState::Off,
             cube: CuboidCube::new(-27246..8756, 4459..30304, -93666..-72765),
         };
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn from_invalid() {
+        let error = CubeReactorInstruction::from_str("on x=1..2,y=3..4,z=5..x").unwrap_err();
+        assert_eq!(error.get_column(), 23);
+        assert_eq!(error.get_text(), "x");
+
+        let error = CubeReactorInstruction::from_str("of x=1..2,y=3..4,z=5..6").unwrap_err();
+        assert_eq!(error.get_column(), 1);
+
+        let error = CubeReactorInstruction::from_str("on x=1..2,y=4..3,z=5..6").unwrap_err();
+        assert_eq!(error.get_column(), 13);
+
+        let error =
+            CubeReactorInstruction::from_str("on x=1..2,y=3..4,z=5..2147483647").unwrap_err();
+        assert_eq!(error.get_column(), 23);
+        assert_eq!(error.get_text(), "2147483647");
+
+        assert!(CubeReactorInstruction::from_str("on x=1..2,y=3..4").is_err());
+        assert!(CubeReactorInstruction::from_str("on x=1..2,z=3..4,y=5..6").is_err());
+    }
 }
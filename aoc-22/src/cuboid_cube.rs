@@ -49,6 +49,13 @@ impl CuboidCube {
             .filter(|cube| cube.volume() > 0)
     }
 
+    /// Returns true if every point of `self` is inside `bounds` on all three axes.
+    pub fn is_within(&self, bounds: Range<i32>) -> bool {
+        [&self.x, &self.y, &self.z]
+            .iter()
+            .all(|axis| axis.start >= bounds.start && axis.end <= bounds.end)
+    }
+
     #[cfg(debug_assertions)]
     pub fn contains(&self, other: &CuboidCube) -> bool {
         (self.x.contains(&other.x.start) || self.x.contains(&(other.x.end - 1)))
@@ -1,5 +1,6 @@
 use crate::cuboid_cube::CuboidCube;
 
+#[derive(Clone)]
 pub struct CuboidSet {
     cubes: Vec<CuboidCube>,
 }
@@ -1,4 +1,6 @@
+use aoc_core::error::ParseError;
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
 pub enum CuboidState {
@@ -6,12 +8,14 @@ pub enum CuboidState {
     Off,
 }
 
-impl From<&str> for CuboidState {
-    fn from(input: &str) -> Self {
+impl FromStr for CuboidState {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
         match input {
-            "on" => CuboidState::On,
-            "off" => CuboidState::Off,
-            _ => panic!("Invalid input: {}", input),
+            "on" => Ok(CuboidState::On),
+            "off" => Ok(CuboidState::Off),
+            _ => Err(ParseError::new("Expected on or off", input)),
         }
     }
 }
@@ -0,0 +1,55 @@
+use crate::cube_reactor::CubeReactor;
+use aoc_core::error::ParseError;
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::solution::{Example, Solution};
+
+pub mod cube_reactor;
+pub mod cube_reactor_instruction;
+pub mod cuboid_cube;
+pub mod cuboid_set;
+pub mod cuboid_state;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    const EXAMPLES: &'static [Example] =
+        &[Example::both(include_str!("../example.txt"), "39", "39")];
+    type Parsed = CubeReactor;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+        CubeReactor::try_from(input.to_lines())
+    }
+
+    /// Cubes enabled after the reboot initialization sequence.
+    fn part_one(reactor: &Self::Parsed) -> Self::PartOne {
+        let mut reactor = reactor.clone();
+        reactor.reboot_init();
+        reactor.count()
+    }
+
+    /// Cubes enabled after the full reboot.
+    fn part_two(reactor: &Self::Parsed) -> Self::PartTwo {
+        let mut reactor = reactor.clone();
+        reactor.reboot();
+        reactor.count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day22;
+    use aoc_core::harness::{assert_answers, assert_examples};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day22>();
+    }
+
+    #[test]
+    fn answers() {
+        assert_answers::<Day22>();
+    }
+}
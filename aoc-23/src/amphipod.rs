@@ -1,3 +1,4 @@
+use aoc_core::error::ParseError;
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug, Eq, PartialEq, Hash, Copy, Clone, Ord, PartialOrd)]
@@ -28,6 +29,20 @@ impl Amphipod {
     }
 }
 
+impl TryFrom<char> for Amphipod {
+    type Error = ParseError;
+
+    fn try_from(input: char) -> Result<Self, Self::Error> {
+        match input {
+            'A' => Ok(Amphipod::Amber),
+            'B' => Ok(Amphipod::Bronze),
+            'C' => Ok(Amphipod::Copper),
+            'D' => Ok(Amphipod::Desert),
+            _ => Err(ParseError::new("Expected an amphipod", &input.to_string())),
+        }
+    }
+}
+
 impl Display for Amphipod {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.write_str(self.to_str())
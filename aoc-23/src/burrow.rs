@@ -1,7 +1,7 @@
 use crate::burrow_map::{MAP, MAP_LARGE, MAP_PATHS, MAP_PATHS_LARGE};
 use crate::burrow_room::BurrowRoom;
 use crate::burrow_state::BurrowState;
-use std::collections::{BinaryHeap, HashSet};
+use aoc_core::search::{dijkstra, SearchPath};
 
 #[derive(Debug)]
 pub struct Burrow {
@@ -25,33 +25,29 @@ impl Burrow {
         Burrow::new(&MAP_LARGE, &MAP_PATHS_LARGE)
     }
 
-    pub fn go<'a>(&self, initial: BurrowState, complete: BurrowState) -> Option<BurrowState> {
-        let mut states = BinaryHeap::new();
-        let mut seen: HashSet<BurrowState> = HashSet::new();
-        states.push(initial);
-
-        while let Some(state) = states.pop() {
-            if seen.contains(&state) {
-                continue;
-            }
-
-            if state == complete {
-                return Some(state);
-            }
-
-            states.extend(self.generate_next_states(&state));
-            seen.insert(state);
-        }
+    pub fn get_rooms(&self) -> &'static [BurrowRoom] {
+        self.rooms
+    }
 
-        None
+    /// Finds the least energy way to get from the `initial` state to the `complete` state, along with every state in between.
+    pub fn go(
+        &self,
+        initial: BurrowState,
+        complete: BurrowState,
+    ) -> Option<SearchPath<BurrowState, u32>> {
+        dijkstra(
+            initial,
+            |state| self.generate_next_states(state).collect::<Vec<_>>(),
+            |state| *state == complete,
+        )
     }
 
     /// Using our static list of possible paths, we use the state to filter down to the set that would be allowed by the rules.
-    /// Then, we apply each of those paths to the state to get the set of next states.
+    /// Then, we apply each of those paths to the state to get the set of next states, and the energy it takes to reach each of them.
     fn generate_next_states<'a>(
         &'a self,
         state: &'a BurrowState,
-    ) -> impl Iterator<Item = BurrowState> + '_ {
+    ) -> impl Iterator<Item = (BurrowState, u32)> + 'a {
         self.paths
             .iter()
             .flat_map(|path| path.1.iter().map(|to| (path.0, *to)))
@@ -70,11 +66,7 @@ impl Burrow {
                     return None;
                 }
                 let is_valid = state.is_path_valid(&state.amphipods[&path.0], &path.0, &path.1);
-                if let Some(size) = is_valid {
-                    Some((path.0, path.1, size))
-                } else {
-                    None
-                }
+                is_valid.map(|size| (path.0, path.1, size))
             })
             .filter(Option::is_some)
             .map(|path| state.apply_path(path.unwrap()))
@@ -45,7 +45,7 @@ const fn targ(room: BurrowRoom) -> [BurrowRoom; 14] {
 }
 
 /// Similar to `MAP`, we can statically define the set of all possible from->to paths.
-pub const MAP_PATHS: [(BurrowRoom, &'static [BurrowRoom]); 15] = [
+pub const MAP_PATHS: [(BurrowRoom, &[BurrowRoom]); 15] = [
     (BurrowRoom::new(0, 0), &targ(BurrowRoom::new(0, 0))),
     (BurrowRoom::new(1, 0), &targ(BurrowRoom::new(1, 0))),
     (BurrowRoom::new(2, 1), &targ(BurrowRoom::new(2, 1))),
@@ -108,7 +108,7 @@ const fn targ_lg(room: BurrowRoom) -> [BurrowRoom; 22] {
     targets
 }
 
-pub const MAP_PATHS_LARGE: [(BurrowRoom, &'static [BurrowRoom]); 23] = [
+pub const MAP_PATHS_LARGE: [(BurrowRoom, &[BurrowRoom]); 23] = [
     (BurrowRoom::new(0, 0), &targ_lg(BurrowRoom::new(0, 0))),
     (BurrowRoom::new(1, 0), &targ_lg(BurrowRoom::new(1, 0))),
     (BurrowRoom::new(2, 1), &targ_lg(BurrowRoom::new(2, 1))),
@@ -1,99 +1,69 @@
 use crate::amphipod::Amphipod;
 use crate::burrow_room::BurrowRoom;
-use std::cmp::Ordering;
+use aoc_core::error::ParseError;
 use std::collections::BTreeMap;
 use std::hash::{Hash, Hasher};
+use std::str::Lines;
 
 #[derive(Debug, Clone)]
 pub struct BurrowState {
     pub amphipods: BTreeMap<BurrowRoom, Amphipod>,
-    pub cost: u32,
     y_max: u8,
 }
 
 impl BurrowState {
-    pub fn from_input() -> Self {
-        let mut amphipods: BTreeMap<BurrowRoom, Amphipod> = BTreeMap::new();
-        amphipods.insert(BurrowRoom::new(2, 1), Amphipod::Copper);
-        amphipods.insert(BurrowRoom::new(2, 2), Amphipod::Bronze);
-        amphipods.insert(BurrowRoom::new(4, 1), Amphipod::Bronze);
-        amphipods.insert(BurrowRoom::new(4, 2), Amphipod::Copper);
-        amphipods.insert(BurrowRoom::new(6, 1), Amphipod::Amber);
-        amphipods.insert(BurrowRoom::new(6, 2), Amphipod::Desert);
-        amphipods.insert(BurrowRoom::new(8, 1), Amphipod::Desert);
-        amphipods.insert(BurrowRoom::new(8, 2), Amphipod::Amber);
-        BurrowState {
-            amphipods,
-            cost: 0,
-            y_max: 2,
-        }
+    pub fn get_y_max(&self) -> u8 {
+        self.y_max
     }
 
-    pub fn from_complete() -> Self {
+    /// Returns the state where every amphipod is in its own home, for a burrow of the same depth.
+    pub fn to_complete(&self) -> Self {
         let mut amphipods: BTreeMap<BurrowRoom, Amphipod> = BTreeMap::new();
-        amphipods.insert(BurrowRoom::new(2, 1), Amphipod::Amber);
-        amphipods.insert(BurrowRoom::new(2, 2), Amphipod::Amber);
-        amphipods.insert(BurrowRoom::new(4, 1), Amphipod::Bronze);
-        amphipods.insert(BurrowRoom::new(4, 2), Amphipod::Bronze);
-        amphipods.insert(BurrowRoom::new(6, 1), Amphipod::Copper);
-        amphipods.insert(BurrowRoom::new(6, 2), Amphipod::Copper);
-        amphipods.insert(BurrowRoom::new(8, 1), Amphipod::Desert);
-        amphipods.insert(BurrowRoom::new(8, 2), Amphipod::Desert);
+        for x in [2, 4, 6, 8] {
+            for y in 1..=self.y_max {
+                let room = BurrowRoom::new(x, y);
+                amphipods.insert(room, room.is_home().unwrap());
+            }
+        }
         BurrowState {
             amphipods,
-            cost: 0,
-            y_max: 2,
+            y_max: self.y_max,
         }
     }
 
-    pub fn from_input_2() -> Self {
-        let mut amphipods: BTreeMap<BurrowRoom, Amphipod> = BTreeMap::new();
-        amphipods.insert(BurrowRoom::new(2, 1), Amphipod::Copper);
+    /// Unfolds the diagram, inserting the two extra rows of amphipods that were hidden under the fold.
+    pub fn unfold(&self) -> Self {
+        let mut amphipods: BTreeMap<BurrowRoom, Amphipod> = self
+            .amphipods
+            .iter()
+            .map(|(room, a)| match room.y {
+                0 | 1 => (*room, *a),
+                _ => (BurrowRoom::new(room.x, room.y + 2), *a),
+            })
+            .collect();
         amphipods.insert(BurrowRoom::new(2, 2), Amphipod::Desert);
         amphipods.insert(BurrowRoom::new(2, 3), Amphipod::Desert);
-        amphipods.insert(BurrowRoom::new(2, 4), Amphipod::Bronze);
-        amphipods.insert(BurrowRoom::new(4, 1), Amphipod::Bronze);
         amphipods.insert(BurrowRoom::new(4, 2), Amphipod::Copper);
         amphipods.insert(BurrowRoom::new(4, 3), Amphipod::Bronze);
-        amphipods.insert(BurrowRoom::new(4, 4), Amphipod::Copper);
-        amphipods.insert(BurrowRoom::new(6, 1), Amphipod::Amber);
         amphipods.insert(BurrowRoom::new(6, 2), Amphipod::Bronze);
         amphipods.insert(BurrowRoom::new(6, 3), Amphipod::Amber);
-        amphipods.insert(BurrowRoom::new(6, 4), Amphipod::Desert);
-        amphipods.insert(BurrowRoom::new(8, 1), Amphipod::Desert);
         amphipods.insert(BurrowRoom::new(8, 2), Amphipod::Amber);
         amphipods.insert(BurrowRoom::new(8, 3), Amphipod::Copper);
-        amphipods.insert(BurrowRoom::new(8, 4), Amphipod::Amber);
         BurrowState {
             amphipods,
-            cost: 0,
-            y_max: 4,
+            y_max: self.y_max + 2,
         }
     }
 
-    pub fn from_complete_2() -> Self {
-        let mut r = BurrowState::from_complete();
-        r.amphipods.insert(BurrowRoom::new(2, 3), Amphipod::Amber);
-        r.amphipods.insert(BurrowRoom::new(2, 4), Amphipod::Amber);
-        r.amphipods.insert(BurrowRoom::new(4, 3), Amphipod::Bronze);
-        r.amphipods.insert(BurrowRoom::new(4, 4), Amphipod::Bronze);
-        r.amphipods.insert(BurrowRoom::new(6, 3), Amphipod::Copper);
-        r.amphipods.insert(BurrowRoom::new(6, 4), Amphipod::Copper);
-        r.amphipods.insert(BurrowRoom::new(8, 3), Amphipod::Desert);
-        r.amphipods.insert(BurrowRoom::new(8, 4), Amphipod::Desert);
-        r.y_max = 4;
-        r
-    }
-
-    pub fn apply_path(&self, path: (BurrowRoom, BurrowRoom, u32)) -> Self {
+    /// Moves an amphipod along a path of `(from, to, steps)`, returning the next state and the energy it took to get there.
+    pub fn apply_path(&self, path: (BurrowRoom, BurrowRoom, u32)) -> (Self, u32) {
         let mut next = self.clone();
         let amphi = next
             .amphipods
             .remove(&path.0)
             .expect("Tried to move from a room that didn't have anyone in it!");
-        next.cost += path.2 * amphi.cost();
         next.amphipods.insert(path.1, amphi);
-        next
+        (next, path.2 * amphi.cost())
     }
 
     /// Returns whether or not moving `from` a room `to` another room is valid.
@@ -117,7 +87,7 @@ impl BurrowState {
         // For each step we have to take, we can't cross another amphipod.
         let steps = self.get_steps(from, to);
         for step in &steps {
-            if self.amphipods.contains_key(&step) {
+            if self.amphipods.contains_key(step) {
                 return None;
             }
         }
@@ -196,14 +166,55 @@ impl Hash for BurrowState {
     }
 }
 
-impl PartialOrd for BurrowState {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
+impl TryFrom<Lines<'_>> for BurrowState {
+    type Error = ParseError;
 
-impl Ord for BurrowState {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.cost.cmp(&self.cost)
+    /// Parses a diagram of the burrow, stopping at the first empty line.
+    /// The first two lines are the top wall and the hallway, every line after that is a row of rooms.
+    fn try_from(input: Lines<'_>) -> Result<Self, Self::Error> {
+        let mut amphipods: BTreeMap<BurrowRoom, Amphipod> = BTreeMap::new();
+        let mut rows = 0u8;
+        for (y, line) in input.take_while(|line| !line.is_empty()).enumerate() {
+            rows += 1;
+            for (x, char) in line.char_indices() {
+                let error = |reason: &str| {
+                    let text = &line[x..x + char.len_utf8()];
+                    ParseError::within(reason, line, text).offset_lines(y)
+                };
+                match char {
+                    '#' | '.' | ' ' => continue,
+                    _ => {}
+                }
+                let amphipod = Amphipod::try_from(char)
+                    .map_err(|_| error("Expected an amphipod, a wall, or an empty space"))?;
+                // Column 0 is the outer wall, row 0 is the top wall.
+                let in_hallway = y == 1 && (1..=11).contains(&x);
+                let in_room = y >= 2 && [3, 5, 7, 9].contains(&x);
+                if !in_hallway && !in_room {
+                    return Err(error("Amphipods must be in the hallway or a room"));
+                }
+                let room = BurrowRoom::new(x as u8 - 1, y as u8 - 1);
+                amphipods.insert(room, amphipod);
+            }
+        }
+        if rows < 4 {
+            return Err(ParseError::new(
+                "Expected a burrow with rooms at least 1 deep",
+                "",
+            ));
+        }
+        let y_max = rows - 3; // Top wall, hallway, bottom wall
+        for amphipod in [
+            Amphipod::Amber,
+            Amphipod::Bronze,
+            Amphipod::Copper,
+            Amphipod::Desert,
+        ] {
+            if amphipods.values().filter(|a| **a == amphipod).count() != y_max as usize {
+                let reason = format!("Expected {} of each amphipod", y_max);
+                return Err(ParseError::new(reason, amphipod.to_str()));
+            }
+        }
+        Ok(BurrowState { amphipods, y_max })
     }
 }
@@ -0,0 +1,76 @@
+pub mod amphipod;
+pub mod burrow;
+pub mod burrow_map;
+pub mod burrow_room;
+pub mod burrow_state;
+
+use crate::burrow::Burrow;
+use crate::burrow_state::BurrowState;
+use aoc_core::error::ParseError;
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::solution::{Example, Solution};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    const EXAMPLES: &'static [Example] = &[Example::both(
+        include_str!("../example.txt"),
+        "12521",
+        "44169",
+    )];
+    type Parsed = BurrowState;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+        let initial = BurrowState::try_from(input.to_lines())?;
+        // The second part unfolds the diagram to make the rooms 4 deep, and those are the only two burrows there are maps for.
+        if initial.get_y_max() != 2 {
+            return Err(ParseError::new(
+                "Expected a folded diagram, with rooms 2 deep",
+                "",
+            ));
+        }
+        Ok(initial)
+    }
+
+    /// Least energy required to sort all amphipods.
+    fn part_one(initial: &Self::Parsed) -> Self::PartOne {
+        organize(initial.clone())
+    }
+
+    /// Least energy required to sort all amphipods, once the diagram is unfolded.
+    fn part_two(initial: &Self::Parsed) -> Self::PartTwo {
+        organize(initial.unfold())
+    }
+}
+
+fn organize(initial: BurrowState) -> u32 {
+    let burrow = match initial.get_y_max() {
+        2 => Burrow::from_map(),
+        4 => Burrow::from_map_large(),
+        y_max => panic!("No map for a burrow with rooms {} deep!", y_max),
+    };
+    let complete = initial.to_complete();
+    burrow
+        .go(initial, complete)
+        .expect("No way to sort the amphipods!")
+        .cost
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day23;
+    use aoc_core::harness::{assert_answers, assert_examples};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day23>();
+    }
+
+    #[test]
+    fn answers() {
+        assert_answers::<Day23>();
+    }
+}
@@ -0,0 +1,270 @@
+use crate::instruction::Instruction;
+use crate::register::Register;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Runs ALU programs for real, one instruction at a time, with `w`, `x`, `y` and `z` all starting at 0.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Alu {
+    /// `w`, `x`, `y` and `z`, in that order.
+    registers: [i64; 4],
+}
+
+/// An instruction that was just run, and what the registers looked like after it was.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct AluStep {
+    pub index: usize,
+    pub instruction: Instruction,
+    pub alu: Alu,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AluErrorKind {
+    /// `div` by zero.
+    DivideByZero,
+    /// `mod` with a negative number, or by a number that isn't positive.
+    InvalidMod,
+    /// An `inp` after the input has run out.
+    MissingInput,
+    /// A result that doesn't fit in an `i64`.
+    Overflow,
+}
+
+/// Why a program stopped early, and the index of the instruction it stopped at.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct AluError {
+    pub kind: AluErrorKind,
+    pub index: usize,
+}
+
+impl Alu {
+    pub fn new() -> Self {
+        Alu::default()
+    }
+
+    /// Starts with `z` already set, for running a program part way through.
+    pub fn with_z(z: i64) -> Self {
+        Alu {
+            registers: [0, 0, 0, z],
+        }
+    }
+
+    /// Starts with every register already set, in the order `w`, `x`, `y`, `z`.
+    pub fn with_registers(registers: [i64; 4]) -> Self {
+        Alu { registers }
+    }
+
+    /// Returns `w`, `x`, `y` and `z`, in that order.
+    pub fn get_registers(&self) -> [i64; 4] {
+        self.registers
+    }
+
+    /// Returns the value of `register`, or the number itself if it's a number.
+    pub fn get(&self, register: Register) -> i64 {
+        match register {
+            Register::Value(v) => v,
+            _ => self.registers[register.get_index().expect("Only numbers have no index!")],
+        }
+    }
+
+    fn set(&mut self, register: Register, value: i64) {
+        let idx = register.get_index().expect("Can't store into a number!");
+        self.registers[idx] = value;
+    }
+
+    /// Runs a single instruction, taking a value from `input` if it's an `inp`.
+    pub fn step<I>(&mut self, instruction: &Instruction, input: &mut I) -> Result<(), AluErrorKind>
+    where
+        I: Iterator<Item = i64>,
+    {
+        let (a, result) = match *instruction {
+            Instruction::Inp(a) => (a, Some(input.next().ok_or(AluErrorKind::MissingInput)?)),
+            Instruction::Add((a, b)) => (a, self.get(a).checked_add(self.get(b))),
+            Instruction::Mul((a, b)) => (a, self.get(a).checked_mul(self.get(b))),
+            Instruction::Div((a, b)) => {
+                if self.get(b) == 0 {
+                    return Err(AluErrorKind::DivideByZero);
+                }
+                // Only i64::MIN / -1 overflows.
+                (a, self.get(a).checked_div(self.get(b)))
+            }
+            Instruction::Mod((a, b)) => {
+                if self.get(a) < 0 || self.get(b) <= 0 {
+                    return Err(AluErrorKind::InvalidMod);
+                }
+                (a, Some(self.get(a) % self.get(b)))
+            }
+            Instruction::Eql((a, b)) => (a, Some((self.get(a) == self.get(b)) as i64)),
+        };
+        self.set(a, result.ok_or(AluErrorKind::Overflow)?);
+        Ok(())
+    }
+
+    /// Runs every instruction in `program`, stopping at the first one that fails.
+    pub fn run<I>(&mut self, program: &[Instruction], input: I) -> Result<(), AluError>
+    where
+        I: IntoIterator<Item = i64>,
+    {
+        self.run_traced(program, input, |_| {})
+    }
+
+    /// Like [`Alu::run`], but calls `trace` after every instruction is run.
+    pub fn run_traced<I, F>(
+        &mut self,
+        program: &[Instruction],
+        input: I,
+        mut trace: F,
+    ) -> Result<(), AluError>
+    where
+        I: IntoIterator<Item = i64>,
+        F: FnMut(&AluStep),
+    {
+        let mut input = input.into_iter();
+        for (index, instruction) in program.iter().enumerate() {
+            self.step(instruction, &mut input)
+                .map_err(|kind| AluError { kind, index })?;
+            trace(&AluStep {
+                index,
+                instruction: *instruction,
+                alu: *self,
+            });
+        }
+        Ok(())
+    }
+
+    /// Runs `program` over the digits of a model number, which is valid if `z` ends up as 0.
+    pub fn is_valid_model_number(program: &[Instruction], digits: &[i8]) -> Result<bool, AluError> {
+        let mut alu = Alu::new();
+        alu.run(program, digits.iter().map(|digit| *digit as i64))?;
+        Ok(alu.get(Register::Z) == 0)
+    }
+}
+
+impl Display for Alu {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let [w, x, y, z] = self.registers;
+        write!(f, "w={} x={} y={} z={}", w, x, y, z)
+    }
+}
+
+impl Display for AluStep {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:>4}: {:<12}{}",
+            self.index,
+            self.instruction.to_string(),
+            self.alu
+        )
+    }
+}
+
+impl Display for AluErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            AluErrorKind::DivideByZero => "Divided by zero",
+            AluErrorKind::InvalidMod => {
+                "Mod of a negative number, or by a number that isn't positive"
+            }
+            AluErrorKind::MissingInput => "Ran out of input",
+            AluErrorKind::Overflow => "Overflowed",
+        })
+    }
+}
+
+impl Display for AluError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at instruction {}", self.kind, self.index)
+    }
+}
+
+impl Error for AluError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::alu::{Alu, AluError, AluErrorKind};
+    use crate::instruction::Instruction;
+    use crate::register::Register;
+    use crate::Day24;
+    use aoc_core::error::parse_lines;
+    use aoc_core::input_resolver::InputResolver;
+    use aoc_core::solution::Solution;
+
+    fn program(text: &str) -> Vec<Instruction> {
+        parse_lines(text.lines()).unwrap()
+    }
+
+    #[test]
+    fn negate() {
+        let mut alu = Alu::new();
+        alu.run(&program("inp x\nmul x -1"), [7]).unwrap();
+        assert_eq!(alu.get(Register::X), -7);
+    }
+
+    #[test]
+    fn to_binary() {
+        let program = program(
+            "inp w\nadd z w\nmod z 2\ndiv w 2\nadd y w\nmod y 2\ndiv w 2\nadd x w\nmod x 2\ndiv w 2\nmod w 2",
+        );
+        let mut alu = Alu::new();
+        alu.run(&program, [13]).unwrap();
+        assert_eq!(alu.to_string(), "w=1 x=1 y=0 z=1");
+    }
+
+    #[test]
+    fn errors() {
+        let error = Alu::new().run(&program("inp x\ndiv x 0"), [1]).unwrap_err();
+        assert_eq!(
+            error,
+            AluError {
+                kind: AluErrorKind::DivideByZero,
+                index: 1
+            }
+        );
+        let error = Alu::new()
+            .run(&program("inp x\nmod x 5"), [-1])
+            .unwrap_err();
+        assert_eq!(error.kind, AluErrorKind::InvalidMod);
+        let error = Alu::new().run(&program("mod x 0"), []).unwrap_err();
+        assert_eq!(error.kind, AluErrorKind::InvalidMod);
+        let error = Alu::new().run(&program("inp x\ninp y"), [1]).unwrap_err();
+        assert_eq!(error.to_string(), "Ran out of input at instruction 1");
+        let error = Alu::with_z(i64::MAX)
+            .run(&program("add z 1"), [])
+            .unwrap_err();
+        assert_eq!(error.kind, AluErrorKind::Overflow);
+    }
+
+    #[test]
+    fn trace() {
+        let mut steps = Vec::new();
+        Alu::new()
+            .run_traced(&program("inp z\ninp x\nmul z 3\neql z x"), [2, 6], |step| {
+                steps.push(step.to_string())
+            })
+            .unwrap();
+        assert_eq!(
+            steps,
+            vec![
+                "   0: inp z       w=0 x=0 y=0 z=2",
+                "   1: inp x       w=0 x=6 y=0 z=2",
+                "   2: mul z 3     w=0 x=6 y=0 z=6",
+                "   3: eql z x     w=0 x=6 y=0 z=1",
+            ]
+        );
+    }
+
+    #[test]
+    fn model_numbers() {
+        let input = InputResolver::workspace().load(24).unwrap();
+        let alu = Day24::parse(&input).unwrap();
+        let (max, min) = alu.solve().unwrap();
+        let program = alu.get_program();
+        assert_eq!(Alu::is_valid_model_number(program, &max), Ok(true));
+        assert_eq!(Alu::is_valid_model_number(program, &min), Ok(true));
+        let mut invalid = max;
+        invalid[13] = if invalid[13] == 9 { 8 } else { 9 };
+        assert_eq!(Alu::is_valid_model_number(program, &invalid), Ok(false));
+        assert_eq!(Alu::is_valid_model_number(program, &[9; 14]), Ok(false));
+    }
+}
@@ -0,0 +1,261 @@
+use crate::alu::Alu;
+use crate::instruction::Instruction;
+use crate::register::Register;
+use crate::value_range::ValueRange;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+/// Finds the largest and smallest valid model numbers of any ALU program, not just ones shaped like MONAD.
+///
+/// The program is split into blocks at each `inp`, and each block is run over every digit from every state the block before it could end in.
+/// Registers that won't be read again are forgotten, so states that only differ in those are merged,
+/// and states that can't end with `z` at 0 whatever digits come next are thrown away, by running the rest of the program over [`ValueRange`]s.
+pub struct Analyser<'a> {
+    program: &'a [Instruction],
+    /// Where each block starts. Every block but the first starts with an `inp`, the first is whatever comes before that.
+    starts: Vec<usize>,
+    /// Which registers might be read before they're written, from the start of each block, and from the end of the program.
+    live: Vec<[bool; 4]>,
+}
+
+/// The largest and smallest digits that lead to a state.
+type Prefixes = (Vec<i8>, Vec<i8>);
+
+impl<'a> Analyser<'a> {
+    pub fn new(program: &'a [Instruction]) -> Self {
+        let mut starts = vec![0];
+        starts.extend(
+            program
+                .iter()
+                .enumerate()
+                .filter(|(_, instruction)| matches!(instruction, Instruction::Inp(_)))
+                .map(|(idx, _)| idx),
+        );
+        let live = Analyser::find_live(program, &starts);
+        Analyser {
+            program,
+            starts,
+            live,
+        }
+    }
+
+    /// Returns how many digits a model number has, which is how many `inp`s there are.
+    pub fn get_digit_count(&self) -> usize {
+        self.starts.len() - 1
+    }
+
+    /// Returns the largest and smallest valid model numbers, or None if there aren't any.
+    pub fn solve(&self) -> Option<(Vec<i8>, Vec<i8>)> {
+        let mut alu = Alu::new();
+        alu.run(self.get_block(0), []).ok()?;
+        let alu = self.forget_dead(1, alu);
+        if !self.can_finish(1, &alu) {
+            return None;
+        }
+        let mut states: HashMap<Alu, Prefixes> = HashMap::from([(alu, (Vec::new(), Vec::new()))]);
+
+        for block in 1..self.starts.len() {
+            let mut next: HashMap<Alu, Prefixes> = HashMap::new();
+            for (alu, (max, min)) in states.iter() {
+                for digit in 1..=9 {
+                    let mut alu = *alu;
+                    if alu.run(self.get_block(block), [digit as i64]).is_err() {
+                        continue;
+                    }
+                    let alu = self.forget_dead(block + 1, alu);
+                    let max = [max.as_slice(), &[digit]].concat();
+                    let min = [min.as_slice(), &[digit]].concat();
+                    match next.entry(alu) {
+                        Entry::Vacant(entry) => {
+                            entry.insert((max, min));
+                        }
+                        Entry::Occupied(mut entry) => {
+                            let prefixes = entry.get_mut();
+                            if max > prefixes.0 {
+                                prefixes.0 = max;
+                            }
+                            if min < prefixes.1 {
+                                prefixes.1 = min;
+                            }
+                        }
+                    }
+                }
+            }
+            next.retain(|alu, _| self.can_finish(block + 1, alu));
+            states = next;
+        }
+
+        states
+            .into_iter()
+            .filter(|(alu, _)| alu.get(Register::Z) == 0)
+            .map(|(_, prefixes)| prefixes)
+            .reduce(|a, b| (a.0.max(b.0), a.1.min(b.1)))
+    }
+
+    /// Returns the instructions in `block`. Block 0 is whatever comes before the first `inp`, and every block after that starts with one.
+    pub fn get_block(&self, block: usize) -> &[Instruction] {
+        let end = self.get_start(block + 1);
+        &self.program[self.starts[block]..end]
+    }
+
+    /// Returns where `block` starts, or the end of the program if there are no more blocks.
+    fn get_start(&self, block: usize) -> usize {
+        self.starts
+            .get(block)
+            .copied()
+            .unwrap_or(self.program.len())
+    }
+
+    /// Zeroes every register that isn't read from the start of `block` on, since it can't make a difference any more.
+    pub fn forget_dead(&self, block: usize, alu: Alu) -> Alu {
+        let mut registers = alu.get_registers();
+        for (value, live) in registers.iter_mut().zip(self.live[block]) {
+            if !live {
+                *value = 0;
+            }
+        }
+        Alu::with_registers(registers)
+    }
+
+    /// Runs the program from the start of `block` over ranges, with every digit from 1 to 9,
+    /// to find out whether `z` could possibly end up at 0.
+    pub fn can_finish(&self, block: usize, alu: &Alu) -> bool {
+        let mut ranges = alu.get_registers().map(ValueRange::exactly);
+        for instruction in &self.program[self.get_start(block)..] {
+            match Analyser::step_ranges(&ranges, instruction) {
+                Some(next) => ranges = next,
+                None => return false,
+            }
+        }
+        ranges[3].contains(0)
+    }
+
+    /// Runs a single instruction over ranges, returning None if the instruction always fails.
+    fn step_ranges(ranges: &[ValueRange; 4], instruction: &Instruction) -> Option<[ValueRange; 4]> {
+        let get = |register: Register| match register {
+            Register::Value(v) => ValueRange::exactly(v),
+            _ => ranges[register.get_index().expect("Only numbers have no index!")],
+        };
+        let (a, result) = match *instruction {
+            Instruction::Inp(a) => (a, ValueRange::new(1, 9)),
+            Instruction::Add((a, b)) => (a, get(a).checked_add(&get(b))?),
+            Instruction::Mul((a, b)) => (a, get(a).checked_mul(&get(b))?),
+            Instruction::Div((a, b)) => (a, get(a).checked_div(&get(b))?),
+            Instruction::Mod((a, b)) => (a, get(a).checked_rem(&get(b))?),
+            Instruction::Eql((a, b)) => (a, get(a).eql(&get(b))),
+        };
+        let mut ranges = *ranges;
+        ranges[a.get_index().expect("Can't store into a number!")] = result;
+        Some(ranges)
+    }
+
+    /// Works backwards from the end of the program, where only `z` is read, to find the live registers at the start of each block.
+    fn find_live(program: &[Instruction], starts: &[usize]) -> Vec<[bool; 4]> {
+        let mut live = [false, false, false, true];
+        let mut result = vec![live; starts.len() + 1];
+        let mut block = starts.len();
+        for (idx, instruction) in program.iter().enumerate().rev() {
+            match *instruction {
+                Instruction::Inp(a) | Instruction::Mul((a, Register::Value(0))) => {
+                    live[a.get_index().expect("Can't store into a number!")] = false;
+                }
+                Instruction::Add((a, b))
+                | Instruction::Mul((a, b))
+                | Instruction::Div((a, b))
+                | Instruction::Mod((a, b))
+                | Instruction::Eql((a, b)) => {
+                    // Even if a isn't read after this, the instruction could still fail because of what's in it.
+                    for register in [a, b] {
+                        if let Some(register) = register.get_index() {
+                            live[register] = true;
+                        }
+                    }
+                }
+            }
+            while block > 0 && starts[block - 1] == idx {
+                block -= 1;
+                result[block] = live;
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::alu::Alu;
+    use crate::analyser::Analyser;
+    use crate::ari_logi_uni::AriLogiUni;
+    use crate::instruction::Instruction;
+    use crate::Day24;
+    use aoc_core::error::parse_lines;
+    use aoc_core::input_resolver::InputResolver;
+    use aoc_core::solution::Solution;
+
+    fn program(text: &str) -> Vec<Instruction> {
+        parse_lines(text.lines()).unwrap()
+    }
+
+    /// Tries every model number, from smallest to largest.
+    fn brute_force(program: &[Instruction], digits: usize) -> Option<(Vec<i8>, Vec<i8>)> {
+        let mut valid = Vec::new();
+        for n in 0..9usize.pow(digits as u32) {
+            let number = (0..digits)
+                .rev()
+                .map(|place| (n / 9usize.pow(place as u32) % 9) as i8 + 1)
+                .collect::<Vec<_>>();
+            if Alu::is_valid_model_number(program, &number) == Ok(true) {
+                valid.push(number);
+            }
+        }
+        Some((valid.last()?.clone(), valid.first()?.clone()))
+    }
+
+    #[test]
+    fn small_programs() {
+        let programs = [
+            // Pushes two digits in base 10, then pops them off again.
+            "inp w\nadd z w\ninp w\nmul z 10\nadd z w\nadd z 3\ninp w\nmod z 10\neql z w\neql z 0",
+            // Divides the first digit by the second minus 5, which fails when it's 5.
+            "inp x\ninp y\nadd y -5\ndiv x y\ninp w\neql x w\neql x 0\nadd z x",
+            // Only valid when the two digits add up to 10, with setup before the first input.
+            "add y -10\ninp w\nadd y w\ninp w\nadd y w\nadd z y",
+            // Mods by the digit minus 4, which fails for anything up to 4.
+            "inp w\nadd w -4\ninp x\nmod x w\nadd z x\nadd z -1",
+            // Never valid, since z always ends up positive.
+            "inp w\nadd z w",
+        ];
+        for text in programs {
+            let program = program(text);
+            let analyser = Analyser::new(&program);
+            let digits = analyser.get_digit_count();
+            assert_eq!(analyser.solve(), brute_force(&program, digits), "{}", text);
+        }
+    }
+
+    #[test]
+    fn matches_solve() {
+        let input = InputResolver::workspace().load(24).unwrap();
+        let alu = Day24::parse(&input).unwrap();
+        let (max, min) = alu.solve().unwrap();
+        let analyser = Analyser::new(alu.get_program());
+        assert_eq!(analyser.get_digit_count(), 14);
+        assert_eq!(analyser.solve(), Some((max.to_vec(), min.to_vec())));
+    }
+
+    #[test]
+    fn programs_solve_cant_handle() {
+        let input = InputResolver::workspace().load(24).unwrap();
+        let alu = Day24::parse(&input).unwrap();
+        let (max, min) = alu.solve().unwrap();
+        // Adding a no-op after every input moves the constants solve() looks for.
+        let text = input.as_string().replace("inp w", "inp w\nmul w 1");
+        assert!(AriLogiUni::try_from(text.lines())
+            .unwrap()
+            .solve()
+            .is_none());
+        let program = program(&text);
+        let analyser = Analyser::new(&program);
+        assert_eq!(analyser.solve(), Some((max.to_vec(), min.to_vec())));
+    }
+}
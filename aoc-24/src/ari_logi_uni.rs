@@ -1,37 +1,82 @@
+use crate::instruction::Instruction;
 use crate::instruction_set::InstructionSet;
 use crate::instruction_set_pair::InstructionSetPair;
+use crate::model_numbers::ModelNumbers;
+use aoc_core::error::{parse_lines, ParseError};
 
 use std::str::Lines;
 
 pub struct AriLogiUni {
-    instructions: Vec<InstructionSet>,
+    program: Vec<Instruction>,
+    /// Only there when the program is shaped like MONAD, see [`AriLogiUni::find_instruction_sets`].
+    instructions: Option<Vec<InstructionSet>>,
 }
 
 impl AriLogiUni {
-    pub fn solve(&self) -> ([i8; 14], [i8; 14]) {
-        let mut instructions = self.instructions.clone();
-        let mut pairs: Vec<InstructionSetPair> = Vec::with_capacity(7);
-        while instructions.len() > 0 {
-            let copy = instructions.clone();
-            for (idx, slc) in copy.windows(2).enumerate() {
-                if slc[0].add_1 > 0 && slc[1].add_1 <= 0 {
-                    let pair = InstructionSetPair::from(&slc[0], &slc[1]);
-                    pairs.push(pair);
-                    instructions.remove(idx);
-                    instructions.remove(idx);
-                    break;
-                }
-            }
-        }
+    /// Returns every instruction, as they were parsed.
+    pub fn get_program(&self) -> &[Instruction] {
+        &self.program
+    }
+
+    /// Returns the constants from each block of the program, in order, or None if the program isn't shaped like MONAD.
+    pub fn get_instruction_sets(&self) -> Option<&[InstructionSet]> {
+        self.instructions.as_deref()
+    }
+
+    /// Returns the largest and smallest valid model numbers, or None if the program isn't shaped like MONAD
+    /// or the blocks don't pair off, see [`AriLogiUni::get_pairs`]. [`Analyser`](crate::analyser::Analyser) works on any program.
+    pub fn solve(&self) -> Option<([i8; 14], [i8; 14])> {
         let mut max = [0i8; 14];
         let mut min = [0i8; 14];
-        for pair in pairs {
+        for pair in self.get_pairs()? {
             max[pair.pos_index] = pair.max_pos_value;
             max[pair.neg_index] = pair.max_neg_value;
             min[pair.pos_index] = pair.min_pos_value;
             min[pair.neg_index] = pair.min_neg_value;
         }
-        (max, min)
+        Some((max, min))
+    }
+
+    /// Returns every valid model number, see [`ModelNumbers`], or None if the program isn't shaped like MONAD or the blocks don't pair off.
+    pub fn model_numbers(&self) -> Option<ModelNumbers> {
+        self.get_pairs().map(ModelNumbers::new)
+    }
+
+    /// Pairs off each block that pushes with the block that pops it again, see instruction_set.rs.
+    ///
+    /// Returns None if a block neither always pushes nor pops, if the pushes and pops don't match up,
+    /// or if no digits can make a pair cancel out.
+    fn get_pairs(&self) -> Option<Vec<InstructionSetPair>> {
+        let mut pushed: Vec<&InstructionSet> = Vec::with_capacity(7);
+        let mut pairs: Vec<InstructionSetPair> = Vec::with_capacity(7);
+        for set in self.instructions.as_ref()? {
+            match set.div {
+                // A digit can never match add_1 when it's more than 9, so these blocks always push.
+                1 if set.add_1 > 9 => pushed.push(set),
+                26 => pairs.push(InstructionSetPair::from(pushed.pop()?, set)?),
+                _ => return None,
+            }
+        }
+        if pushed.is_empty() {
+            Some(pairs)
+        } else {
+            None
+        }
+    }
+
+    /// Splits the program into 14 blocks of 18 instructions, if every one of them looks like a block of MONAD.
+    fn find_instruction_sets(program: &[Instruction]) -> Option<Vec<InstructionSet>> {
+        if program.len() != 14 * 18 {
+            return None;
+        }
+        program
+            .chunks(18)
+            .enumerate()
+            .map(|(i, block)| {
+                let set = block.try_into().expect("Chunks are all 18 long!");
+                InstructionSet::new(set, i)
+            })
+            .collect()
     }
 
     // Leaving this here because I want to remember about from_fn.
@@ -56,48 +101,116 @@ impl AriLogiUni {
     // }
 }
 
-impl From<Lines<'_>> for AriLogiUni {
-    fn from(mut lines: Lines<'_>) -> Self {
-        let mut instructions = Vec::with_capacity(14);
-        for i in 0..14usize {
-            let set = [
-                lines.next().unwrap().into(),
-                lines.next().unwrap().into(),
-                lines.next().unwrap().into(),
-                lines.next().unwrap().into(),
-                lines.next().unwrap().into(),
-                lines.next().unwrap().into(),
-                lines.next().unwrap().into(),
-                lines.next().unwrap().into(),
-                lines.next().unwrap().into(),
-                lines.next().unwrap().into(),
-                lines.next().unwrap().into(),
-                lines.next().unwrap().into(),
-                lines.next().unwrap().into(),
-                lines.next().unwrap().into(),
-                lines.next().unwrap().into(),
-                lines.next().unwrap().into(),
-                lines.next().unwrap().into(),
-                lines.next().unwrap().into(),
-            ];
-            let r = InstructionSet::new(set, i);
-            instructions.push(r);
+impl TryFrom<Lines<'_>> for AriLogiUni {
+    type Error = ParseError;
+
+    fn try_from(lines: Lines<'_>) -> Result<Self, Self::Error> {
+        let lines: Vec<&str> = lines.collect();
+        let program: Vec<Instruction> = parse_lines(lines.iter().copied())?;
+        let inputs: Vec<usize> = program
+            .iter()
+            .enumerate()
+            .filter(|(_, instruction)| matches!(instruction, Instruction::Inp(_)))
+            .map(|(idx, _)| idx)
+            .collect();
+        if inputs.is_empty() {
+            return Err(ParseError::new(
+                "Expected at least one inp instruction",
+                lines.last().copied().unwrap_or_default(),
+            )
+            .offset_lines(lines.len().saturating_sub(1)));
         }
-        AriLogiUni { instructions }
+        // Any more digits than this, and the model number might not fit in a u64.
+        if let Some(idx) = inputs.get(19) {
+            return Err(
+                ParseError::new("Expected at most 19 inp instructions", lines[*idx])
+                    .offset_lines(*idx),
+            );
+        }
+        let instructions = AriLogiUni::find_instruction_sets(&program);
+        Ok(AriLogiUni {
+            program,
+            instructions,
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::analyser::Analyser;
     use crate::AriLogiUni;
-    use aoc_core::puzzle_input::PuzzleInput;
+    use aoc_core::input_resolver::InputResolver;
+
+    /// Builds a program out of MONAD blocks, each with its own `div z`, `add x` and `add y` constants.
+    fn program(blocks: &[(i64, i64, i64)]) -> AriLogiUni {
+        let text: Vec<String> = blocks
+            .iter()
+            .map(|(div, add_1, add_2)| {
+                format!(
+                    "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\nmul y 0\n\
+                     add y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\nmul y x\nadd z y",
+                    div, add_1, add_2
+                )
+            })
+            .collect();
+        AriLogiUni::try_from(text.join("\n").as_str().lines()).unwrap()
+    }
 
     #[test]
     fn input() {
-        let input = PuzzleInput::new("../aoc-24/input.txt");
-        let alu = AriLogiUni::from(input.to_lines());
-        for inst in alu.instructions {
+        let input = InputResolver::workspace().load(24).unwrap();
+        let alu = AriLogiUni::try_from(input.to_lines()).unwrap();
+        for inst in alu.instructions.unwrap() {
             println!("{:?}", inst);
         }
     }
+
+    #[test]
+    fn pairs() {
+        let input = InputResolver::workspace().load(24).unwrap();
+        let alu = AriLogiUni::try_from(input.to_lines()).unwrap();
+        let pairs = alu.get_pairs().unwrap();
+        assert_eq!(pairs.len(), 7);
+        assert!(pairs.iter().all(|pair| pair.pos_index < pair.neg_index));
+
+        let mut blocks = [(1, 11, 3), (1, 12, 5), (26, -7, 0), (26, -2, 0)].repeat(3);
+        blocks.extend([(1, 10, 0), (26, -4, 0)]);
+        let alu = program(&blocks);
+        let (max, min) = alu.solve().unwrap();
+        assert_eq!(max, [8, 9, 7, 9, 8, 9, 7, 9, 8, 9, 7, 9, 9, 5]);
+        assert_eq!(min, [1, 3, 1, 2, 1, 3, 1, 2, 1, 3, 1, 2, 5, 1]);
+        let found = Analyser::new(alu.get_program()).solve();
+        assert_eq!(found, Some((max.to_vec(), min.to_vec())));
+    }
+
+    #[test]
+    fn pairs_that_dont_work() {
+        let push = (1, 11, 3);
+        let pop = (26, -4, 0);
+        // Nothing ever pops, which never finished pairing off before.
+        let alu = program(&[push; 14]);
+        assert!(alu.solve().is_none());
+        assert!(alu.model_numbers().is_none());
+        assert!(Analyser::new(alu.get_program()).solve().is_none());
+
+        // Pops before anything is pushed.
+        let mut blocks = [push, pop].repeat(7);
+        blocks.swap(0, 1);
+        assert!(program(&blocks).solve().is_none());
+
+        // Digits can't be 9 apart.
+        let alu = program(&[push, (26, 6, 0)].repeat(7));
+        assert!(alu.solve().is_none());
+        assert!(Analyser::new(alu.get_program()).solve().is_none());
+
+        // Might not push, depending on the digit.
+        assert!(program(&[(1, 5, 3), pop].repeat(7)).solve().is_none());
+
+        // 14 blocks of 18, but one of them isn't shaped like MONAD.
+        let input = InputResolver::workspace().load(24).unwrap();
+        let text = input.as_string().replacen("mul x 0", "mul x 1", 1);
+        let alu = AriLogiUni::try_from(text.lines()).unwrap();
+        assert!(alu.get_instruction_sets().is_none());
+        assert!(alu.solve().is_none());
+    }
 }
@@ -0,0 +1,262 @@
+use crate::alu::{Alu, AluErrorKind};
+use crate::analyser::Analyser;
+use crate::instruction::Instruction;
+use crate::register::Register;
+use std::collections::HashSet;
+
+/// A single instruction, specialized for its operands, that runs over `w`, `x`, `y` and `z` and the digit its block reads.
+type CompiledStep = Box<dyn Fn(&mut [i64; 4], i64) -> Result<(), AluErrorKind>>;
+
+/// An ALU program compiled down to a chain of closures per block, for running the same program over a lot of model numbers.
+///
+/// Instructions that can't do anything, like `add x 0` and `div z 1`, are left out,
+/// and each closure already knows which registers it reads and whether its second operand is a number.
+pub struct CompiledProgram<'a> {
+    /// Splits the program into the same blocks, and knows which registers matter and which states can still end at 0.
+    analyser: Analyser<'a>,
+    blocks: Vec<Vec<CompiledStep>>,
+}
+
+impl<'a> CompiledProgram<'a> {
+    pub fn new(program: &'a [Instruction]) -> Self {
+        let analyser = Analyser::new(program);
+        let blocks = (0..=analyser.get_digit_count())
+            .map(|block| {
+                analyser
+                    .get_block(block)
+                    .iter()
+                    .filter_map(CompiledProgram::compile)
+                    .collect()
+            })
+            .collect();
+        CompiledProgram { analyser, blocks }
+    }
+
+    /// Runs the program over the digits of a model number, which is valid if `z` ends up as 0.
+    pub fn is_valid_model_number(&self, digits: &[i8]) -> Result<bool, AluErrorKind> {
+        let mut registers = [0; 4];
+        self.run_block(0, &mut registers, 0)?;
+        for block in 1..self.blocks.len() {
+            let digit = digits.get(block - 1).ok_or(AluErrorKind::MissingInput)?;
+            self.run_block(block, &mut registers, *digit as i64)?;
+        }
+        Ok(registers[3] == 0)
+    }
+
+    /// Searches for the largest valid model number, trying digits from 9 down.
+    pub fn find_largest(&self) -> Option<Vec<i8>> {
+        self.search(&[9, 8, 7, 6, 5, 4, 3, 2, 1])
+    }
+
+    /// Searches for the smallest valid model number, trying digits from 1 up.
+    pub fn find_smallest(&self) -> Option<Vec<i8>> {
+        self.search(&[1, 2, 3, 4, 5, 6, 7, 8, 9])
+    }
+
+    /// Searches every model number depth first, trying digits in `order`, so the first valid one found is the answer.
+    fn search(&self, order: &[i8]) -> Option<Vec<i8>> {
+        let mut registers = [0; 4];
+        self.run_block(0, &mut registers, 0).ok()?;
+        let mut visited = HashSet::new();
+        let mut digits = Vec::with_capacity(self.blocks.len() - 1);
+        if self.search_from(1, registers, order, &mut visited, &mut digits) {
+            Some(digits)
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether some digits from `block` on lead to `z` ending at 0, leaving those digits on the end of `digits` if so.
+    fn search_from(
+        &self,
+        block: usize,
+        registers: [i64; 4],
+        order: &[i8],
+        visited: &mut HashSet<(usize, Alu)>,
+        digits: &mut Vec<i8>,
+    ) -> bool {
+        // Only registers that are read again can make a difference, which for MONAD leaves just z.
+        let alu = self
+            .analyser
+            .forget_dead(block, Alu::with_registers(registers));
+        if block == self.blocks.len() {
+            return alu.get(Register::Z) == 0;
+        }
+        // Anything that's been visited before can't have worked out, or the search would be over.
+        if !visited.insert((block, alu)) || !self.analyser.can_finish(block, &alu) {
+            return false;
+        }
+        for digit in order {
+            let mut next = alu.get_registers();
+            if self.run_block(block, &mut next, *digit as i64).is_err() {
+                continue;
+            }
+            digits.push(*digit);
+            if self.search_from(block + 1, next, order, visited, digits) {
+                return true;
+            }
+            digits.pop();
+        }
+        false
+    }
+
+    fn run_block(
+        &self,
+        block: usize,
+        registers: &mut [i64; 4],
+        digit: i64,
+    ) -> Result<(), AluErrorKind> {
+        for step in self.blocks[block].iter() {
+            step(registers, digit)?;
+        }
+        Ok(())
+    }
+
+    /// Compiles an instruction into a closure, or returns None if it can't change anything.
+    fn compile(instruction: &Instruction) -> Option<CompiledStep> {
+        let index = |register: Register| register.get_index().expect("Can't store into a number!");
+        let step: CompiledStep = match *instruction {
+            Instruction::Inp(a) => {
+                let a = index(a);
+                Box::new(move |registers, digit| {
+                    registers[a] = digit;
+                    Ok(())
+                })
+            }
+            Instruction::Add((_, Register::Value(0)))
+            | Instruction::Mul((_, Register::Value(1)))
+            | Instruction::Div((_, Register::Value(1))) => return None,
+            Instruction::Mul((a, Register::Value(0))) => {
+                let a = index(a);
+                Box::new(move |registers, _| {
+                    registers[a] = 0;
+                    Ok(())
+                })
+            }
+            Instruction::Add((a, b)) => {
+                CompiledProgram::binary(a, b, |a, b| a.checked_add(b).ok_or(AluErrorKind::Overflow))
+            }
+            Instruction::Mul((a, b)) => {
+                CompiledProgram::binary(a, b, |a, b| a.checked_mul(b).ok_or(AluErrorKind::Overflow))
+            }
+            Instruction::Div((a, b)) => CompiledProgram::binary(a, b, |a, b| match b {
+                0 => Err(AluErrorKind::DivideByZero),
+                _ => a.checked_div(b).ok_or(AluErrorKind::Overflow),
+            }),
+            Instruction::Mod((a, b)) => CompiledProgram::binary(a, b, |a, b| {
+                if a < 0 || b <= 0 {
+                    Err(AluErrorKind::InvalidMod)
+                } else {
+                    Ok(a % b)
+                }
+            }),
+            Instruction::Eql((a, b)) => CompiledProgram::binary(a, b, |a, b| Ok((a == b) as i64)),
+        };
+        Some(step)
+    }
+
+    /// Builds a closure that applies `op` to `a` and `b`, specialized for whether `b` is a number or another register.
+    fn binary<F>(a: Register, b: Register, op: F) -> CompiledStep
+    where
+        F: Fn(i64, i64) -> Result<i64, AluErrorKind> + 'static,
+    {
+        let a = a.get_index().expect("Can't store into a number!");
+        match b {
+            Register::Value(v) => Box::new(move |registers, _| {
+                registers[a] = op(registers[a], v)?;
+                Ok(())
+            }),
+            _ => {
+                let b = b.get_index().expect("Only numbers have no index!");
+                Box::new(move |registers, _| {
+                    registers[a] = op(registers[a], registers[b])?;
+                    Ok(())
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::alu::{Alu, AluErrorKind};
+    use crate::analyser::Analyser;
+    use crate::compiled_program::CompiledProgram;
+    use crate::instruction::Instruction;
+    use crate::Day24;
+    use aoc_core::error::parse_lines;
+    use aoc_core::input_resolver::InputResolver;
+    use aoc_core::solution::Solution;
+
+    fn program(text: &str) -> Vec<Instruction> {
+        parse_lines(text.lines()).unwrap()
+    }
+
+    #[test]
+    fn matches_alu() {
+        let input = InputResolver::workspace().load(24).unwrap();
+        let alu = Day24::parse(&input).unwrap();
+        let compiled = CompiledProgram::new(alu.get_program());
+        let (max, min) = alu.solve().unwrap();
+        // Walks through a spread of model numbers, valid and not.
+        let mut digits = [1i8; 14];
+        for n in 0..500usize {
+            for (idx, digit) in digits.iter_mut().enumerate() {
+                *digit = ((n * 7 + idx * 13 + n / (idx + 1)) % 9) as i8 + 1;
+            }
+            for digits in [digits, max, min] {
+                let expected = Alu::is_valid_model_number(alu.get_program(), &digits);
+                assert_eq!(
+                    compiled.is_valid_model_number(&digits),
+                    expected.map_err(|e| e.kind)
+                );
+            }
+        }
+        assert_eq!(
+            compiled.is_valid_model_number(&max[..13]),
+            Err(AluErrorKind::MissingInput)
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let program = program("inp x\ninp y\nadd y -5\ndiv x y\nmod x y");
+        let compiled = CompiledProgram::new(&program);
+        assert_eq!(
+            compiled.is_valid_model_number(&[1, 5]),
+            Err(AluErrorKind::DivideByZero)
+        );
+        assert_eq!(
+            compiled.is_valid_model_number(&[1, 4]),
+            Err(AluErrorKind::InvalidMod)
+        );
+        assert_eq!(compiled.is_valid_model_number(&[1, 7]), Ok(true));
+    }
+
+    #[test]
+    fn search_matches_solve() {
+        let input = InputResolver::workspace().load(24).unwrap();
+        let alu = Day24::parse(&input).unwrap();
+        let (max, min) = alu.solve().unwrap();
+        let compiled = CompiledProgram::new(alu.get_program());
+        assert_eq!(compiled.find_largest(), Some(max.to_vec()));
+        assert_eq!(compiled.find_smallest(), Some(min.to_vec()));
+    }
+
+    #[test]
+    fn search_matches_analyser() {
+        let programs = [
+            "inp w\nadd z w\ninp w\nmul z 10\nadd z w\nadd z 3\ninp w\nmod z 10\neql z w\neql z 0",
+            "inp x\ninp y\nadd y -5\ndiv x y\ninp w\neql x w\neql x 0\nadd z x",
+            "inp w\nadd w -4\ninp x\nmod x w\nadd z x\nadd z -1",
+            "inp w\nadd z w",
+        ];
+        for text in programs {
+            let program = program(text);
+            let compiled = CompiledProgram::new(&program);
+            let expected = Analyser::new(&program).solve();
+            let found = compiled.find_largest().zip(compiled.find_smallest());
+            assert_eq!(found, expected, "{}", text);
+        }
+    }
+}
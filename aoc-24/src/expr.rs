@@ -0,0 +1,330 @@
+use crate::register::Register;
+use crate::value_range::ValueRange;
+use std::fmt::{Display, Formatter};
+
+/// An expression tree built up out of ALU instructions, for reading rather than running.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Expr {
+    Value(i64),
+    /// The digit read by the nth `inp`.
+    Input(usize),
+    /// Whatever is in a register when the expression is evaluated. Never a [`Register::Value`].
+    Register(Register),
+    /// The top of the stack kept in `z`, which is `z % 26`. Only found in [`crate::stack_op::StackOp`] conditions.
+    Top,
+    Op(ExprOp, Box<Expr>, Box<Expr>),
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ExprOp {
+    Add,
+    Mul,
+    Div,
+    Mod,
+    Eql,
+    /// `eql` followed by `eql 0`, which is how the ALU spells "not equal".
+    Neq,
+}
+
+impl ExprOp {
+    /// Applies the operation the same way [`crate::alu::Alu`] does, returning None where the ALU would fail.
+    pub fn apply(&self, a: i64, b: i64) -> Option<i64> {
+        match self {
+            ExprOp::Add => a.checked_add(b),
+            ExprOp::Mul => a.checked_mul(b),
+            ExprOp::Div => a.checked_div(b),
+            ExprOp::Mod if a < 0 || b <= 0 => None,
+            ExprOp::Mod => Some(a % b),
+            ExprOp::Eql => Some((a == b) as i64),
+            ExprOp::Neq => Some((a != b) as i64),
+        }
+    }
+
+    /// How tightly the operation binds when written out, higher binding tighter.
+    fn precedence(&self) -> u8 {
+        match self {
+            ExprOp::Eql | ExprOp::Neq => 0,
+            ExprOp::Add => 1,
+            ExprOp::Mul | ExprOp::Div | ExprOp::Mod => 2,
+        }
+    }
+
+    fn symbol(&self) -> &'static str {
+        match self {
+            ExprOp::Add => "+",
+            ExprOp::Mul => "*",
+            ExprOp::Div => "/",
+            ExprOp::Mod => "%",
+            ExprOp::Eql => "==",
+            ExprOp::Neq => "!=",
+        }
+    }
+}
+
+impl Expr {
+    pub fn op(op: ExprOp, a: Expr, b: Expr) -> Self {
+        Expr::Op(op, Box::new(a), Box::new(b))
+    }
+
+    /// Adds up every term, or returns 0 if there aren't any.
+    pub fn sum(terms: Vec<Expr>) -> Self {
+        terms
+            .into_iter()
+            .reduce(|a, b| Expr::op(ExprOp::Add, a, b))
+            .unwrap_or(Expr::Value(0))
+    }
+
+    /// Splits a chain of additions back up into the terms being added.
+    pub fn get_terms(&self) -> Vec<&Expr> {
+        match self {
+            Expr::Op(ExprOp::Add, a, b) => {
+                let mut terms = a.get_terms();
+                terms.extend(b.get_terms());
+                terms
+            }
+            _ => vec![self],
+        }
+    }
+
+    /// Returns which of `w`, `x`, `y` and `z` the expression reads, in that order.
+    pub fn get_reads(&self) -> [bool; 4] {
+        let mut reads = [false; 4];
+        self.visit(&mut |expr| {
+            if let Expr::Register(register) = expr {
+                reads[register.get_index().expect("Only numbers have no index!")] = true;
+            }
+        });
+        reads
+    }
+
+    /// Returns how many times `register` is read.
+    pub fn count_reads(&self, register: Register) -> usize {
+        let mut count = 0;
+        self.visit(&mut |expr| count += (*expr == Expr::Register(register)) as usize);
+        count
+    }
+
+    /// Returns whether `part` shows up anywhere in the expression.
+    pub fn contains(&self, part: &Expr) -> bool {
+        let mut found = false;
+        self.visit(&mut |expr| found |= expr == part);
+        found
+    }
+
+    /// Swaps every `from` in the expression for `to`.
+    pub fn replace(&self, from: &Expr, to: &Expr) -> Expr {
+        if self == from {
+            return to.clone();
+        }
+        match self {
+            Expr::Op(op, a, b) => Expr::op(*op, a.replace(from, to), b.replace(from, to)),
+            _ => self.clone(),
+        }
+    }
+
+    /// Swaps every register read for what's in `registers`, in the order `w`, `x`, `y`, `z`.
+    pub fn substitute_all(&self, registers: &[Expr; 4]) -> Expr {
+        match self {
+            Expr::Register(register) => {
+                registers[register.get_index().expect("Only numbers have no index!")].clone()
+            }
+            Expr::Op(op, a, b) => Expr::op(
+                *op,
+                a.substitute_all(registers),
+                b.substitute_all(registers),
+            ),
+            _ => self.clone(),
+        }
+    }
+
+    /// Works out the value of the expression, returning None where the ALU would fail.
+    pub fn evaluate(&self, registers: &[i64; 4], inputs: &[i64]) -> Option<i64> {
+        match self {
+            Expr::Value(v) => Some(*v),
+            Expr::Input(n) => inputs.get(*n).copied(),
+            Expr::Register(register) => {
+                Some(registers[register.get_index().expect("Only numbers have no index!")])
+            }
+            Expr::Top => ExprOp::Mod.apply(registers[3], 26),
+            Expr::Op(op, a, b) => op.apply(
+                a.evaluate(registers, inputs)?,
+                b.evaluate(registers, inputs)?,
+            ),
+        }
+    }
+
+    /// Returns every value the expression might have, given what might be in each register. Inputs are digits from 1 to 9.
+    pub fn get_range(&self, registers: &[ValueRange; 4]) -> ValueRange {
+        let range = match self {
+            Expr::Value(v) => Some(ValueRange::exactly(*v)),
+            Expr::Input(_) => Some(ValueRange::new(1, 9)),
+            Expr::Register(register) => {
+                Some(registers[register.get_index().expect("Only numbers have no index!")])
+            }
+            Expr::Top => Some(ValueRange::new(0, 25)),
+            Expr::Op(op, a, b) => {
+                let (a, b) = (a.get_range(registers), b.get_range(registers));
+                match op {
+                    ExprOp::Add => a.checked_add(&b),
+                    ExprOp::Mul => a.checked_mul(&b),
+                    ExprOp::Div => a.checked_div(&b),
+                    ExprOp::Mod => a.checked_rem(&b),
+                    ExprOp::Eql => Some(a.eql(&b)),
+                    ExprOp::Neq => {
+                        let eql = a.eql(&b);
+                        Some(ValueRange::new(1 - eql.get_hi(), 1 - eql.get_lo()))
+                    }
+                }
+            }
+        };
+        // Something that always fails could be anything, as far as anyone reading it is concerned.
+        range.unwrap_or_else(ValueRange::full)
+    }
+
+    /// Folds away everything that can be worked out ahead of time, given what might be in each register.
+    ///
+    /// Doesn't keep track of what might fail, so `x * 0` is 0 even if working out `x` would have failed.
+    pub fn simplify(&self, registers: &[ValueRange; 4]) -> Expr {
+        let simplified = match self {
+            Expr::Op(op, a, b) => {
+                Expr::simplify_op(*op, a.simplify(registers), b.simplify(registers), registers)
+            }
+            _ => self.clone(),
+        };
+        match simplified.get_range(registers).as_value() {
+            Some(value) => Expr::Value(value),
+            None => simplified,
+        }
+    }
+
+    fn simplify_op(op: ExprOp, a: Expr, b: Expr, registers: &[ValueRange; 4]) -> Expr {
+        use Expr::{Op, Value};
+        use ExprOp::*;
+        if let (Value(a), Value(b)) = (&a, &b) {
+            if let Some(value) = op.apply(*a, *b) {
+                return Value(value);
+            }
+        }
+        match (op, a, b) {
+            (Add, a, Value(0)) | (Add, Value(0), a) => a,
+            (Mul, a, Value(1)) | (Mul, Value(1), a) | (Div, a, Value(1)) => a,
+            // Constants go on the right, so they can be combined, and so `x + -4` can be written `x - 4`.
+            (Add, Value(c), a) => Expr::simplify_op(Add, a, Value(c), registers),
+            (Add, Op(Add, a, b), Value(c2)) => match *b {
+                Value(c1) if c1.checked_add(c2).is_some() => {
+                    Expr::simplify_op(Add, *a, Value(c1 + c2), registers)
+                }
+                b => Expr::op(Add, Op(Add, a, Box::new(b)), Value(c2)),
+            },
+            (Add, a, Op(Add, b, c)) => {
+                let ab = Expr::simplify_op(Add, a, *b, registers);
+                Expr::simplify_op(Add, ab, *c, registers)
+            }
+            (Mod, a, Value(m))
+                if a.get_range(registers).get_lo() >= 0 && a.get_range(registers).get_hi() < m =>
+            {
+                a
+            }
+            (Eql, Op(Eql, a, b), Value(0)) => Expr::Op(Neq, a, b),
+            (Eql, Op(Neq, a, b), Value(0)) => Expr::Op(Eql, a, b),
+            (Eql, a @ Op(Eql | Neq, _, _), Value(1)) => a,
+            (op, a, b) => Expr::op(op, a, b),
+        }
+    }
+
+    fn visit<F: FnMut(&Expr)>(&self, f: &mut F) {
+        f(self);
+        if let Expr::Op(_, a, b) = self {
+            a.visit(f);
+            b.visit(f);
+        }
+    }
+
+    fn precedence(&self) -> u8 {
+        match self {
+            Expr::Op(op, _, _) => op.precedence(),
+            _ => 3,
+        }
+    }
+}
+
+impl Display for Expr {
+    /// Writes the expression out in infix, with only as many parentheses as it needs.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (op, a, b) = match self {
+            Expr::Value(v) => return write!(f, "{}", v),
+            Expr::Input(n) => return write!(f, "input[{}]", n),
+            Expr::Register(register) => return write!(f, "{}", register),
+            Expr::Top => return f.write_str("top"),
+            Expr::Op(op, a, b) => (op, a, b),
+        };
+        // Everything groups to the left, except comparisons, which don't group at all.
+        let precedence = op.precedence();
+        if a.precedence() < precedence || a.precedence() == 0 && precedence == 0 {
+            write!(f, "({})", a)?;
+        } else {
+            write!(f, "{}", a)?;
+        }
+        match (op, b.as_ref()) {
+            (ExprOp::Add, Expr::Value(v)) if *v < 0 => write!(f, " - {}", v.unsigned_abs()),
+            (_, b) if b.precedence() <= precedence => write!(f, " {} ({})", op.symbol(), b),
+            (_, b) => write!(f, " {} {}", op.symbol(), b),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expr::{Expr, ExprOp};
+    use crate::register::Register;
+    use crate::value_range::ValueRange;
+
+    fn z() -> Expr {
+        Expr::Register(Register::Z)
+    }
+
+    #[test]
+    fn display() {
+        let top = Expr::op(ExprOp::Mod, z(), Expr::Value(26));
+        let check = Expr::op(ExprOp::Add, top, Expr::Value(-4));
+        let check = Expr::op(ExprOp::Neq, check, Expr::Input(3));
+        assert_eq!(check.to_string(), "z % 26 - 4 != input[3]");
+        let scale = Expr::op(ExprOp::Mul, Expr::Value(25), check.clone());
+        let scale = Expr::op(ExprOp::Add, scale, Expr::Value(1));
+        let popped = Expr::op(ExprOp::Div, z(), Expr::Value(26));
+        let scaled = Expr::op(ExprOp::Mul, popped, scale);
+        assert_eq!(
+            scaled.to_string(),
+            "z / 26 * (25 * (z % 26 - 4 != input[3]) + 1)"
+        );
+        let nested = Expr::op(ExprOp::Eql, check, Expr::Value(0));
+        assert_eq!(nested.to_string(), "(z % 26 - 4 != input[3]) == 0");
+    }
+
+    #[test]
+    fn simplify() {
+        let unknown = [ValueRange::full(); 4];
+        let top = Expr::op(ExprOp::Mod, z(), Expr::Value(26));
+        // z % 26 + 11 can't be a digit, so they're never equal.
+        let check = Expr::op(ExprOp::Add, top.clone(), Expr::Value(11));
+        let check = Expr::op(ExprOp::Eql, check, Expr::Input(0));
+        assert_eq!(check.simplify(&unknown), Expr::Value(0));
+
+        let check = Expr::op(ExprOp::Add, top, Expr::Value(-4));
+        let check = Expr::op(ExprOp::Eql, check, Expr::Input(0));
+        let not = Expr::op(ExprOp::Eql, check, Expr::Value(0));
+        assert_eq!(not.simplify(&unknown).to_string(), "z % 26 - 4 != input[0]");
+
+        let sum = Expr::op(ExprOp::Add, Expr::Value(3), Expr::Input(1));
+        let sum = Expr::op(ExprOp::Add, z(), sum);
+        let sum = Expr::op(ExprOp::Add, sum, Expr::Value(-3));
+        assert_eq!(sum.simplify(&unknown).to_string(), "z + input[1]");
+
+        let mut known = unknown;
+        known[1] = ValueRange::exactly(0);
+        let product = Expr::op(ExprOp::Mul, z(), Expr::Register(Register::X));
+        assert_eq!(product.simplify(&known), Expr::Value(0));
+        let remainder = Expr::op(ExprOp::Mod, Expr::Input(2), Expr::Value(26));
+        assert_eq!(remainder.simplify(&unknown), Expr::Input(2));
+    }
+}
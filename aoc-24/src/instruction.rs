@@ -1,4 +1,7 @@
 use crate::register::Register;
+use aoc_core::error::ParseError;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum Instruction {
@@ -10,17 +13,115 @@ pub enum Instruction {
     Eql((Register, Register)),
 }
 
-impl From<&str> for Instruction {
-    fn from(input: &str) -> Self {
+impl Instruction {
+    /// Parses the operand of an instruction. Only the second operand of an instruction can be a number,
+    /// the first operand is always where the result is stored.
+    fn parse_operand(
+        input: &str,
+        operand: Option<&str>,
+        is_first: bool,
+    ) -> Result<Register, ParseError> {
+        let operand = operand.ok_or_else(|| ParseError::new("Missing an operand", input))?;
+        let register: Register = operand
+            .parse()
+            .map_err(|e: ParseError| e.located_in(input, operand))?;
+        if is_first && register.is_value() {
+            return Err(ParseError::within("Expected w, x, y or z", input, operand));
+        }
+        Ok(register)
+    }
+}
+
+impl Display for Instruction {
+    /// Writes the instruction the same way it's parsed, like `add z -12`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (op, (a, b)) = match self {
+            Instruction::Inp(a) => return write!(f, "inp {}", a),
+            Instruction::Add(operands) => ("add", operands),
+            Instruction::Mul(operands) => ("mul", operands),
+            Instruction::Div(operands) => ("div", operands),
+            Instruction::Mod(operands) => ("mod", operands),
+            Instruction::Eql(operands) => ("eql", operands),
+        };
+        write!(f, "{} {} {}", op, a, b)
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
         let mut split = input.split(' ');
-        match split.next().unwrap() {
-            "inp" => Instruction::Inp(split.next().unwrap().into()),
-            "add" => Instruction::Add((split.next().unwrap().into(), split.next().unwrap().into())),
-            "mul" => Instruction::Mul((split.next().unwrap().into(), split.next().unwrap().into())),
-            "div" => Instruction::Div((split.next().unwrap().into(), split.next().unwrap().into())),
-            "mod" => Instruction::Mod((split.next().unwrap().into(), split.next().unwrap().into())),
-            "eql" => Instruction::Eql((split.next().unwrap().into(), split.next().unwrap().into())),
-            _ => panic!("Invalid value!"),
+        let op = split.next().unwrap_or_default();
+        let a = Instruction::parse_operand(input, split.next(), true)?;
+        let instruction = match op {
+            "inp" => Instruction::Inp(a),
+            "add" | "mul" | "div" | "mod" | "eql" => {
+                let b = Instruction::parse_operand(input, split.next(), false)?;
+                match op {
+                    "add" => Instruction::Add((a, b)),
+                    "mul" => Instruction::Mul((a, b)),
+                    "div" => Instruction::Div((a, b)),
+                    "mod" => Instruction::Mod((a, b)),
+                    _ => Instruction::Eql((a, b)),
+                }
+            }
+            _ => {
+                return Err(ParseError::new(
+                    "Expected inp, add, mul, div, mod or eql",
+                    op,
+                ))
+            }
+        };
+        if let Some(extra) = split.next() {
+            return Err(ParseError::within("Too many operands", input, extra));
+        }
+        Ok(instruction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::instruction::Instruction;
+    use crate::register::Register;
+    use std::str::FromStr;
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            Instruction::from_str("inp w"),
+            Ok(Instruction::Inp(Register::W))
+        );
+        assert_eq!(
+            Instruction::from_str("add z -12"),
+            Ok(Instruction::Add((Register::Z, Register::Value(-12))))
+        );
+        assert_eq!(
+            Instruction::from_str("eql x w"),
+            Ok(Instruction::Eql((Register::X, Register::W)))
+        );
+    }
+
+    #[test]
+    fn display() {
+        for line in ["inp w", "add z -12", "eql x w", "mod x 26"] {
+            assert_eq!(Instruction::from_str(line).unwrap().to_string(), line);
         }
     }
+
+    #[test]
+    fn from_invalid_str() {
+        let error = Instruction::from_str("sub x 1").unwrap_err();
+        assert_eq!((error.get_column(), error.get_text()), (1, "sub"));
+
+        let error = Instruction::from_str("mul x q").unwrap_err();
+        assert_eq!((error.get_column(), error.get_text()), (7, "q"));
+
+        let error = Instruction::from_str("add 1 x").unwrap_err();
+        assert_eq!(error.get_column(), 5);
+
+        assert!(Instruction::from_str("inp w x").is_err());
+        assert!(Instruction::from_str("div x").is_err());
+        assert!(Instruction::from_str("").is_err());
+    }
 }
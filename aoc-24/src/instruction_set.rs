@@ -1,31 +1,64 @@
 use crate::instruction::Instruction;
 use crate::register::Register;
 
+/// Every instruction in a block of MONAD, apart from the 5th, 6th and 16th, which have constants that change from block to block.
+const BLOCK: [Option<Instruction>; 18] = [
+    Some(Instruction::Inp(Register::W)),
+    Some(Instruction::Mul((Register::X, Register::Value(0)))),
+    Some(Instruction::Add((Register::X, Register::Z))),
+    Some(Instruction::Mod((Register::X, Register::Value(26)))),
+    None,
+    None,
+    Some(Instruction::Eql((Register::X, Register::W))),
+    Some(Instruction::Eql((Register::X, Register::Value(0)))),
+    Some(Instruction::Mul((Register::Y, Register::Value(0)))),
+    Some(Instruction::Add((Register::Y, Register::Value(25)))),
+    Some(Instruction::Mul((Register::Y, Register::X))),
+    Some(Instruction::Add((Register::Y, Register::Value(1)))),
+    Some(Instruction::Mul((Register::Z, Register::Y))),
+    Some(Instruction::Mul((Register::Y, Register::Value(0)))),
+    Some(Instruction::Add((Register::Y, Register::W))),
+    None,
+    Some(Instruction::Mul((Register::Y, Register::X))),
+    Some(Instruction::Add((Register::Z, Register::Y))),
+];
+
 #[derive(Debug, Copy, Clone)]
 pub struct InstructionSet {
-    // div: i64,
+    pub div: i64,
     pub add_1: i8,
     pub add_2: i8,
     pub idx: usize,
 }
 
 impl InstructionSet {
-    pub fn new(instructions: [Instruction; 18], idx: usize) -> Self {
+    /// Returns None if the instructions don't look like a block of MONAD.
+    pub fn new(instructions: [Instruction; 18], idx: usize) -> Option<Self> {
+        let matches = instructions
+            .iter()
+            .zip(BLOCK)
+            .all(|(instruction, expected)| expected.is_none_or(|e| *instruction == e));
+        if !matches {
+            return None;
+        }
+        let div = match instructions[4] {
+            Instruction::Div((Register::Z, Register::Value(v))) => v,
+            _ => return None,
+        };
         let add_1 = match instructions[5] {
-            Instruction::Add((_, r2)) => match r2 {
-                Register::Value(v) => v,
-                _ => panic!("Unexpected input!"),
-            },
-            _ => panic!("Unexpected input!"),
-        } as i8;
+            Instruction::Add((Register::X, Register::Value(v))) => i8::try_from(v).ok()?,
+            _ => return None,
+        };
         let add_2 = match instructions[15] {
-            Instruction::Add((_, r2)) => match r2 {
-                Register::Value(v) => v,
-                _ => panic!("Unexpected input!"),
-            },
-            _ => panic!("Unexpected input!"),
-        } as i8;
-        InstructionSet { add_1, add_2, idx }
+            Instruction::Add((Register::Y, Register::Value(v))) => i8::try_from(v).ok()?,
+            _ => return None,
+        };
+        Some(InstructionSet {
+            div,
+            add_1,
+            add_2,
+            idx,
+        })
     }
 
     // pub fn run(&self, current_z: i64, input: i64) -> i64 {
@@ -79,6 +112,7 @@ impl InstructionSet {
     // For the 4th step, input[4] = input[3] + 1 - 4 = input[3] - 3
     // And that means!!! oh my god. this doesn't need a computer at all. Since these pairs of operations need to cancel,
     // we can just pair them all off, they "disappear" once they're paired. We want the maximum value for each pair, and since 9 is the upper limit for any input, that's easy to find (input[4] = 6 since input[3] = 9 is the highest value we can use).
-    // The pairing (using vec.windows(2)) is done in ari_logi_uni.rs, and the computation of values is done in instruction_set_pair.rs.
+    // The pairing (now with a stack, since vec.windows(2) never finished on programs that don't pair off) is done in ari_logi_uni.rs, and the computation of values is done in instruction_set_pair.rs.
+    // Listing (listing.rs) now does the reducing above for any program, and prints each block as a push or pop, see StackOp.
     // }
 }
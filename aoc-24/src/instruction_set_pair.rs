@@ -1,5 +1,6 @@
 use crate::instruction_set::InstructionSet;
 
+#[derive(Debug, Copy, Clone)]
 pub struct InstructionSetPair {
     pub pos_index: usize,
     pub neg_index: usize, // should always be greater than pos_index
@@ -10,25 +11,30 @@ pub struct InstructionSetPair {
 }
 
 impl InstructionSetPair {
-    pub fn from(pos: &InstructionSet, neg: &InstructionSet) -> Self {
-        let offset = pos.add_2 + neg.add_1; // input[neg_index] = input[pos_index] + offset, see InstructionSet for the painful journey here
-        let mut max_pos_value = 9i8; // We start with this value, since it is more significant, we want it as high as possible.
-        while max_pos_value + offset > 9 {
-            max_pos_value -= 1; // Find the first valid pos valid that can also make neg value valid.
-            debug_assert_ne!(max_pos_value, 0);
+    /// Returns None if no pair of digits from 1 to 9 can cancel out, see [`InstructionSet`].
+    pub fn from(pos: &InstructionSet, neg: &InstructionSet) -> Option<Self> {
+        let offset = pos.add_2.checked_add(neg.add_1)?; // input[neg_index] = input[pos_index] + offset, see InstructionSet for the painful journey here
+        if !(-8..=8).contains(&offset) {
+            return None; // Two digits from 1 to 9 can't be further apart than this.
         }
-        let mut min_pos_value = 1i8;
-        while min_pos_value + offset < 1 {
-            min_pos_value += 1;
-            debug_assert_ne!(min_pos_value, 10);
+        let max_pos_value = 9.min(9 - offset); // Since pos value is more significant, we want it as high as possible while neg value is still valid.
+        let min_pos_value = 1.max(1 - offset);
+        // The pos value is pushed as a digit base 26, so it has to stay one for the neg value to see it.
+        if pos.add_2 < -min_pos_value || pos.add_2 > 25 - max_pos_value {
+            return None;
         }
-        InstructionSetPair {
+        Some(InstructionSetPair {
             pos_index: pos.idx,
             neg_index: neg.idx,
             max_pos_value,
             max_neg_value: max_pos_value + offset,
             min_pos_value,
             min_neg_value: min_pos_value + offset,
-        }
+        })
+    }
+
+    /// Returns how much bigger the digit at `neg_index` has to be than the one at `pos_index`, which can be negative.
+    pub fn get_offset(&self) -> i8 {
+        self.max_neg_value - self.max_pos_value
     }
 }
@@ -0,0 +1,102 @@
+use crate::ari_logi_uni::AriLogiUni;
+use crate::compiled_program::CompiledProgram;
+use aoc_core::error::ParseError;
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::solution::Solution;
+
+pub mod alu;
+pub mod analyser;
+pub mod ari_logi_uni;
+pub mod compiled_program;
+pub mod expr;
+pub mod instruction;
+pub mod instruction_set;
+pub mod instruction_set_pair;
+pub mod listing;
+pub mod model_numbers;
+pub mod register;
+pub mod stack_op;
+pub mod statement;
+pub mod value_range;
+
+pub struct Day24;
+
+/// The explanation for this insanity is in instruction_set.rs, where I originally tried to parse and execute all the instructions,
+/// and then realized there were parts that repeated that I could maybe optimize,
+/// and then by reducing those realized that testing inputs was totally 100% the wrong approach.
+///
+/// There's a lot of dead code in here because of that (RIP Instruction/Register, I enjoyed implementing you 💔).
+/// They live on in alu.rs, which runs programs for real, but only to check answers rather than find them.
+/// The answers come from compiled_program.rs, which searches any ALU program, and solve() is only kept around to check it against.
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    type Parsed = AriLogiUni;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+        AriLogiUni::try_from(input.to_lines())
+    }
+
+    /// Highest possible model number.
+    fn part_one(alu: &Self::Parsed) -> Self::PartOne {
+        let program = CompiledProgram::new(alu.get_program());
+        to_model_number(&program.find_largest().expect("No valid model numbers!"))
+    }
+
+    /// Lowest possible model number.
+    fn part_two(alu: &Self::Parsed) -> Self::PartTwo {
+        let program = CompiledProgram::new(alu.get_program());
+        to_model_number(&program.find_smallest().expect("No valid model numbers!"))
+    }
+}
+
+fn to_model_number(digits: &[i8]) -> u64 {
+    digits
+        .iter()
+        .fold(0u64, |acc, val| (acc * 10) + *val as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day24;
+    use aoc_core::harness::assert_answers;
+    use aoc_core::input_resolver::InputResolver;
+    use aoc_core::puzzle_input::PuzzleInput;
+    use aoc_core::solution::Solution;
+
+    #[test]
+    fn answers() {
+        assert_answers::<Day24>();
+    }
+
+    #[test]
+    fn any_program() {
+        let input = InputResolver::workspace().load(24).unwrap();
+        let alu = Day24::parse(&input).unwrap();
+        let (max, min) = (Day24::part_one(&alu), Day24::part_two(&alu));
+        // Adding a no-op after every input means it's no longer 14 blocks of 18, so solve() can't do it.
+        let text = input.as_string().replace("inp w", "inp w\nmul w 1");
+        let alu = Day24::parse(&PuzzleInput::from(text.as_str())).unwrap();
+        assert!(alu.get_instruction_sets().is_none());
+        assert!(alu.solve().is_none());
+        assert_eq!((Day24::part_one(&alu), Day24::part_two(&alu)), (max, min));
+
+        let alu = Day24::parse(&PuzzleInput::from("inp w\ninp z\nadd z w\nadd z -10")).unwrap();
+        assert_eq!((Day24::part_one(&alu), Day24::part_two(&alu)), (91, 19));
+    }
+
+    #[test]
+    fn invalid_programs() {
+        let error = Day24::parse(&PuzzleInput::from("add z 1\nmul z 2"))
+            .err()
+            .unwrap();
+        assert_eq!(error.get_line(), 2);
+        let text = "inp w\nadd z w\n".repeat(20);
+        let error = Day24::parse(&PuzzleInput::from(text.as_str()))
+            .err()
+            .unwrap();
+        assert_eq!(error.get_line(), 39);
+        assert!(Day24::parse(&PuzzleInput::from(text.replacen("inp w\n", "", 1).as_str())).is_ok());
+    }
+}
@@ -0,0 +1,363 @@
+use crate::expr::{Expr, ExprOp};
+use crate::instruction::Instruction;
+use crate::register::Register;
+use crate::stack_op::StackOp;
+use crate::statement::Statement;
+use crate::value_range::ValueRange;
+use std::fmt::{Display, Formatter};
+
+/// An ALU program rewritten as pseudo-code, a block at a time, so it can be read without working through it by hand.
+///
+/// [`Listing::new`] runs every pass in order, but each pass can also be run on its own to see what it does.
+pub struct Listing {
+    blocks: Vec<ListingBlock>,
+}
+
+/// The statements from one `inp` up to the next one, which is one [`crate::instruction_set::InstructionSet`] in MONAD.
+pub struct ListingBlock {
+    /// Which input the block reads, or None for anything before the first `inp`.
+    input: Option<usize>,
+    statements: Vec<Statement>,
+    stack: Option<StackOp>,
+}
+
+impl Listing {
+    /// Lifts the program and runs every pass over it.
+    pub fn new(program: &[Instruction]) -> Self {
+        let mut listing = Listing::lift(program);
+        listing.fold_constants();
+        listing.eliminate_dead_stores();
+        listing.inline();
+        listing.recognize_stack();
+        listing
+    }
+
+    /// Turns each instruction into a statement, without simplifying anything, so `add x z` becomes `x = x + z`.
+    pub fn lift(program: &[Instruction]) -> Self {
+        let mut blocks = vec![ListingBlock::new(None)];
+        let mut inputs = 0;
+        for instruction in program {
+            let operand = |register: Register| match register {
+                Register::Value(v) => Expr::Value(v),
+                _ => Expr::Register(register),
+            };
+            let binary = |op: ExprOp, (a, b): (Register, Register)| {
+                Statement::new(a, Expr::op(op, operand(a), operand(b)))
+            };
+            let statement = match *instruction {
+                Instruction::Inp(a) => {
+                    blocks.push(ListingBlock::new(Some(inputs)));
+                    inputs += 1;
+                    Statement::new(a, Expr::Input(inputs - 1))
+                }
+                Instruction::Add(operands) => binary(ExprOp::Add, operands),
+                Instruction::Mul(operands) => binary(ExprOp::Mul, operands),
+                Instruction::Div(operands) => binary(ExprOp::Div, operands),
+                Instruction::Mod(operands) => binary(ExprOp::Mod, operands),
+                Instruction::Eql(operands) => binary(ExprOp::Eql, operands),
+            };
+            blocks
+                .last_mut()
+                .expect("Always at least one block!")
+                .statements
+                .push(statement);
+        }
+        Listing { blocks }
+    }
+
+    pub fn get_blocks(&self) -> &[ListingBlock] {
+        &self.blocks
+    }
+
+    /// Simplifies each statement with whatever is known about the registers so far, and drops statements that store a register back into itself.
+    ///
+    /// Each block is folded on its own, as if it could start with anything in the registers, so blocks read the same wherever they are.
+    pub fn fold_constants(&mut self) {
+        for block in self.blocks.iter_mut() {
+            let mut registers = [ValueRange::full(); 4];
+            let mut statements = Vec::with_capacity(block.statements.len());
+            for statement in block.statements.drain(..) {
+                let expr = statement.expr.simplify(&registers);
+                registers[statement.get_target_index()] = expr.get_range(&registers);
+                if expr != Expr::Register(statement.target) {
+                    statements.push(Statement::new(statement.target, expr));
+                }
+            }
+            block.statements = statements;
+        }
+    }
+
+    /// Drops statements that store something that's never read, like the `x = 0` that `mul x 0` folds into.
+    /// Only `z` is read once the program is over.
+    pub fn eliminate_dead_stores(&mut self) {
+        let mut live = [false, false, false, true];
+        for block in self.blocks.iter_mut().rev() {
+            let mut statements = Vec::with_capacity(block.statements.len());
+            for statement in block.statements.drain(..).rev() {
+                let target = statement.get_target_index();
+                if !live[target] {
+                    continue;
+                }
+                live[target] = false;
+                for (live, read) in live.iter_mut().zip(statement.expr.get_reads()) {
+                    *live |= read;
+                }
+                statements.push(statement);
+            }
+            statements.reverse();
+            block.statements = statements;
+        }
+    }
+
+    /// Substitutes each statement into the one place that reads what it stores, where it's safe to, until there's nothing left to substitute.
+    pub fn inline(&mut self) {
+        let live_outs = self.find_live_outs();
+        let unknown = [ValueRange::full(); 4];
+        for (block, live_out) in self.blocks.iter_mut().zip(live_outs) {
+            while let Some((def, reader)) = block.find_inline(&live_out) {
+                let def = block.statements.remove(def);
+                let reader = &mut block.statements[reader - 1];
+                let substituted = reader.expr.replace(&Expr::Register(def.target), &def.expr);
+                reader.expr = substituted.simplify(&unknown);
+            }
+        }
+    }
+
+    /// Works out what each block does to `z` from the statements left in it, and recognizes which blocks treat it as a stack.
+    pub fn recognize_stack(&mut self) {
+        let unknown = [ValueRange::full(); 4];
+        for block in self.blocks.iter_mut() {
+            let mut registers =
+                [Register::W, Register::X, Register::Y, Register::Z].map(Expr::Register);
+            for statement in block.statements.iter() {
+                let expr = statement.expr.substitute_all(&registers).simplify(&unknown);
+                registers[statement.get_target_index()] = expr;
+            }
+            block.stack = StackOp::find(&registers[3]);
+        }
+    }
+
+    /// Runs the statements over a model number, returning what ends up in `z`, or None if something failed along the way.
+    pub fn run(&self, digits: &[i8]) -> Option<i64> {
+        let inputs = digits.iter().map(|digit| *digit as i64).collect::<Vec<_>>();
+        let mut registers = [0; 4];
+        for statement in self.blocks.iter().flat_map(|block| block.statements.iter()) {
+            registers[statement.get_target_index()] =
+                statement.expr.evaluate(&registers, &inputs)?;
+        }
+        Some(registers[3])
+    }
+
+    /// Returns which registers are read after each block is over.
+    fn find_live_outs(&self) -> Vec<[bool; 4]> {
+        let mut live = [false, false, false, true];
+        let mut live_outs = vec![live; self.blocks.len()];
+        for (block, live_out) in self.blocks.iter().zip(live_outs.iter_mut()).rev() {
+            *live_out = live;
+            for statement in block.statements.iter().rev() {
+                live[statement.get_target_index()] = false;
+                for (live, read) in live.iter_mut().zip(statement.expr.get_reads()) {
+                    *live |= read;
+                }
+            }
+        }
+        live_outs
+    }
+}
+
+impl ListingBlock {
+    fn new(input: Option<usize>) -> Self {
+        ListingBlock {
+            input,
+            statements: Vec::new(),
+            stack: None,
+        }
+    }
+
+    pub fn get_input(&self) -> Option<usize> {
+        self.input
+    }
+
+    pub fn get_statements(&self) -> &[Statement] {
+        &self.statements
+    }
+
+    /// Returns what the block does to the stack in `z`, if it's been recognized as doing that.
+    pub fn get_stack(&self) -> Option<&StackOp> {
+        self.stack.as_ref()
+    }
+
+    /// Finds the first statement that can be substituted into the one place that reads it, and where that is.
+    fn find_inline(&self, live_out: &[bool; 4]) -> Option<(usize, usize)> {
+        (0..self.statements.len())
+            .find_map(|def| self.find_reader(def, live_out).map(|reader| (def, reader)))
+    }
+
+    /// Returns the only statement that reads what `def` stores, as long as nothing `def` reads is stored into first.
+    fn find_reader(&self, def: usize, live_out: &[bool; 4]) -> Option<usize> {
+        let statement = &self.statements[def];
+        let reads = statement.expr.get_reads();
+        let mut reader = None;
+        let mut changed = false;
+        for (idx, other) in self.statements.iter().enumerate().skip(def + 1) {
+            match (other.expr.count_reads(statement.target), reader) {
+                (0, _) => {}
+                (1, None) if !changed => reader = Some(idx),
+                _ => return None,
+            }
+            if other.target == statement.target {
+                return reader;
+            }
+            changed |= reads[other.get_target_index()];
+        }
+        // Still needed by the next block.
+        if live_out[statement.get_target_index()] {
+            return None;
+        }
+        reader
+    }
+}
+
+impl Display for Listing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for block in self.blocks.iter() {
+            match block.input {
+                Some(input) => writeln!(f, "block {}:", input)?,
+                None if block.statements.is_empty() => continue,
+                None => writeln!(f, "start:")?,
+            }
+            for statement in block.statements.iter() {
+                writeln!(f, "    {}", statement)?;
+            }
+            if let Some(stack) = &block.stack {
+                writeln!(f, "    // {}", stack)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::alu::Alu;
+    use crate::instruction::Instruction;
+    use crate::listing::Listing;
+    use crate::register::Register;
+    use crate::Day24;
+    use aoc_core::error::parse_lines;
+    use aoc_core::input_resolver::InputResolver;
+    use aoc_core::solution::Solution;
+
+    /// A block shaped like the ones in MONAD, with its three constants.
+    fn monad_block(div: i64, add_1: i64, add_2: i64) -> String {
+        format!(
+            "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\nmul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\nmul y x\nadd z y\n",
+            div, add_1, add_2
+        )
+    }
+
+    fn program(text: &str) -> Vec<Instruction> {
+        parse_lines(text.lines()).unwrap()
+    }
+
+    #[test]
+    fn passes() {
+        let program = program(&monad_block(1, 11, 3));
+        let mut listing = Listing::lift(&program);
+        assert_eq!(listing.get_blocks()[1].get_statements().len(), 18);
+        listing.fold_constants();
+        listing.eliminate_dead_stores();
+        let statements = listing.get_blocks()[1]
+            .get_statements()
+            .iter()
+            .map(|statement| statement.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            statements,
+            vec![
+                "w = input[0]",
+                "z = z * 26",
+                "y = w",
+                "y = y + 3",
+                "z = z + y"
+            ]
+        );
+        listing.inline();
+        assert_eq!(
+            listing.to_string(),
+            "block 0:\n    z = z * 26 + input[0] + 3\n"
+        );
+    }
+
+    #[test]
+    fn monad_blocks() {
+        let text = [
+            monad_block(1, 11, 3),
+            monad_block(1, 14, 0),
+            monad_block(26, -4, 6),
+            monad_block(1, 5, 2),
+            monad_block(26, 0, 12),
+        ]
+        .concat();
+        let listing = Listing::new(&program(&text));
+        assert_eq!(
+            listing.to_string(),
+            [
+                "block 0:",
+                "    z = z * 26 + input[0] + 3",
+                "    // push input[0] + 3",
+                "block 1:",
+                "    z = z * 26 + input[1]",
+                "    // push input[1]",
+                "block 2:",
+                "    w = input[2]",
+                "    x = z % 26 - 4 != w",
+                "    z = z / 26 * (25 * x + 1) + (w + 6) * x",
+                "    // top = pop; if top - 4 != input[2] { push input[2] + 6 }",
+                "block 3:",
+                "    w = input[3]",
+                "    x = z % 26 + 5 != w",
+                "    z = z * (25 * x + 1) + (w + 2) * x",
+                "    // if top + 5 != input[3] { push input[3] + 2 }",
+                "block 4:",
+                "    w = input[4]",
+                "    x = z % 26 != w",
+                "    z = z / 26 * (25 * x + 1) + (w + 12) * x",
+                "    // top = pop; if top != input[4] { push input[4] + 12 }",
+                ""
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn real_input() {
+        let input = InputResolver::workspace().load(24).unwrap();
+        let alu = Day24::parse(&input).unwrap();
+        let listing = Listing::new(alu.get_program());
+        let blocks = &listing.get_blocks()[1..];
+        let sets = alu.get_instruction_sets().unwrap();
+        assert_eq!(blocks.len(), sets.len());
+        for (block, set) in blocks.iter().zip(sets) {
+            assert_eq!(block.get_input(), Some(set.idx));
+            let stack = block.get_stack().unwrap();
+            assert_eq!(stack.pop, set.add_1 <= 0);
+            let push = match set.add_2 {
+                0 => format!("input[{}]", set.idx),
+                add_2 => format!("input[{}] + {}", set.idx, add_2),
+            };
+            assert_eq!(stack.push.to_string(), push);
+        }
+
+        let (max, min) = alu.solve().unwrap();
+        let mut invalid = max;
+        invalid[0] -= 1;
+        for digits in [max, min, invalid, [9; 14], [1; 14]] {
+            let mut alu_run = Alu::new();
+            alu_run
+                .run(alu.get_program(), digits.iter().map(|d| *d as i64))
+                .unwrap();
+            assert_eq!(listing.run(&digits), Some(alu_run.get(Register::Z)));
+        }
+    }
+}
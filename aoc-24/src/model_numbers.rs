@@ -0,0 +1,191 @@
+use crate::instruction_set_pair::InstructionSetPair;
+
+/// Every valid model number, which is every way of picking the digits of each [`InstructionSetPair`] that keeps both from 1 to 9.
+///
+/// The first digit of a pair always comes before the second, and the second follows from the first,
+/// so putting model numbers in order is the same as putting the first digits of each pair in order.
+/// That makes the valid model numbers a counter, where each pair is one place and counts from its smallest first digit to its largest.
+pub struct ModelNumbers {
+    /// Sorted by the first digit of each pair, so the most significant place comes first.
+    pairs: Vec<InstructionSetPair>,
+}
+
+impl ModelNumbers {
+    pub fn new(mut pairs: Vec<InstructionSetPair>) -> Self {
+        pairs.sort_by_key(|pair| pair.pos_index);
+        ModelNumbers { pairs }
+    }
+
+    /// Returns how many valid model numbers there are.
+    pub fn count(&self) -> u64 {
+        self.pairs.iter().map(ModelNumbers::place_size).product()
+    }
+
+    pub fn is_valid(&self, digits: &[i8; 14]) -> bool {
+        self.pairs.iter().all(|pair| {
+            let pos = digits[pair.pos_index];
+            (pair.min_pos_value..=pair.max_pos_value).contains(&pos)
+                && digits[pair.neg_index] == pos + pair.get_offset()
+        })
+    }
+
+    /// Returns the `n`th smallest valid model number, counting from 0, or None if there aren't that many.
+    pub fn get_nth(&self, n: u64) -> Option<[i8; 14]> {
+        if n >= self.count() {
+            return None;
+        }
+        let mut digits = [0i8; 14];
+        let mut rest = n;
+        for pair in self.pairs.iter().rev() {
+            let size = ModelNumbers::place_size(pair);
+            let pos = pair.min_pos_value + (rest % size) as i8;
+            rest /= size;
+            digits[pair.pos_index] = pos;
+            digits[pair.neg_index] = pos + pair.get_offset();
+        }
+        Some(digits)
+    }
+
+    /// Returns how many valid model numbers are smaller than `digits`, if `digits` is valid itself. The inverse of [`ModelNumbers::get_nth`].
+    pub fn index_of(&self, digits: &[i8; 14]) -> Option<u64> {
+        if !self.is_valid(digits) {
+            return None;
+        }
+        let index = self.pairs.iter().fold(0, |index, pair| {
+            let place = (digits[pair.pos_index] - pair.min_pos_value) as u64;
+            index * ModelNumbers::place_size(pair) + place
+        });
+        Some(index)
+    }
+
+    /// Returns every valid model number, smallest first.
+    pub fn iter(&self) -> ModelNumberIter<'_> {
+        ModelNumberIter {
+            numbers: self,
+            front: 0,
+            back: self.count(),
+        }
+    }
+
+    /// Returns how many different first digits a pair can have.
+    fn place_size(pair: &InstructionSetPair) -> u64 {
+        (pair.max_pos_value - pair.min_pos_value + 1) as u64
+    }
+}
+
+/// An iterator over every valid model number, see [`ModelNumbers::iter`].
+pub struct ModelNumberIter<'a> {
+    numbers: &'a ModelNumbers,
+    /// The index of the next number from the front, and one past the index of the next number from the back.
+    front: u64,
+    back: u64,
+}
+
+impl<'a> Iterator for ModelNumberIter<'a> {
+    type Item = [i8; 14];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        self.numbers.get_nth(self.front - 1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = usize::try_from(self.back - self.front).ok();
+        (remaining.unwrap_or(usize::MAX), remaining)
+    }
+
+    /// Skips straight to the number, rather than counting up to it.
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self.front.saturating_add(n as u64).min(self.back);
+        self.next()
+    }
+}
+
+impl<'a> DoubleEndedIterator for ModelNumberIter<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        self.numbers.get_nth(self.back)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::alu::Alu;
+    use crate::instruction::Instruction;
+    use crate::model_numbers::ModelNumbers;
+    use crate::Day24;
+    use aoc_core::input_resolver::InputResolver;
+    use aoc_core::solution::Solution;
+
+    fn model_numbers() -> (ModelNumbers, Vec<Instruction>, [i8; 14], [i8; 14]) {
+        let input = InputResolver::workspace().load(24).unwrap();
+        let alu = Day24::parse(&input).unwrap();
+        let (max, min) = alu.solve().unwrap();
+        (
+            alu.model_numbers().unwrap(),
+            alu.get_program().to_vec(),
+            max,
+            min,
+        )
+    }
+
+    #[test]
+    fn ends_match_solve() {
+        let (numbers, _, max, min) = model_numbers();
+        let count = numbers.count();
+        assert!(count > 1);
+        assert_eq!(numbers.get_nth(0), Some(min));
+        assert_eq!(numbers.get_nth(count - 1), Some(max));
+        assert_eq!(numbers.get_nth(count), None);
+        assert_eq!(numbers.iter().next(), Some(min));
+        assert_eq!(numbers.iter().next_back(), Some(max));
+        assert_eq!(numbers.index_of(&max), Some(count - 1));
+        let mut iter = numbers.iter();
+        assert_eq!(iter.nth(count as usize / 2), numbers.get_nth(count / 2));
+        assert_eq!(iter.next(), numbers.get_nth(count / 2 + 1));
+        assert_eq!(iter.size_hint().0 as u64, count - count / 2 - 2);
+    }
+
+    #[test]
+    fn every_number_is_valid() {
+        let (numbers, program, _, _) = model_numbers();
+        // Running the ALU over every one of them would be slow, so only a spread of them are checked against it.
+        let step = (numbers.count() / 500).max(1) as usize;
+        let mut previous = None;
+        for (index, digits) in numbers.iter().enumerate().step_by(step) {
+            assert_eq!(Alu::is_valid_model_number(&program, &digits), Ok(true));
+            assert!(numbers.is_valid(&digits));
+            assert_eq!(numbers.index_of(&digits), Some(index as u64));
+            assert!(previous < Some(digits));
+            previous = Some(digits);
+        }
+        let counted = numbers.iter().count() as u64;
+        assert_eq!(counted, numbers.count());
+        assert!(numbers
+            .iter()
+            .rev()
+            .eq(numbers.iter().collect::<Vec<_>>().into_iter().rev()));
+    }
+
+    #[test]
+    fn invalid_numbers() {
+        let (numbers, program, max, min) = model_numbers();
+        for digits in [max, min] {
+            for idx in 0..14 {
+                let mut changed = digits;
+                changed[idx] = if digits[idx] == 9 { 1 } else { digits[idx] + 1 };
+                let valid = Alu::is_valid_model_number(&program, &changed) == Ok(true);
+                assert_eq!(numbers.is_valid(&changed), valid);
+                assert_eq!(numbers.index_of(&changed), None);
+            }
+        }
+        assert!(!numbers.is_valid(&[9; 14]));
+        assert!(!numbers.is_valid(&[0; 14]));
+    }
+}
@@ -1,3 +1,7 @@
+use aoc_core::error::{parse_number, ParseError};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum Register {
     Value(i64),
@@ -7,14 +11,47 @@ pub enum Register {
     Z,
 }
 
-impl From<&str> for Register {
-    fn from(input: &str) -> Self {
+impl Register {
+    pub fn is_value(&self) -> bool {
+        matches!(self, Register::Value(_))
+    }
+
+    /// Returns which of `w`, `x`, `y` and `z` this is, in that order, or None for a number.
+    pub fn get_index(&self) -> Option<usize> {
+        match self {
+            Register::Value(_) => None,
+            Register::W => Some(0),
+            Register::X => Some(1),
+            Register::Y => Some(2),
+            Register::Z => Some(3),
+        }
+    }
+}
+
+impl Display for Register {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Register::Value(v) => write!(f, "{}", v),
+            Register::W => f.write_str("w"),
+            Register::X => f.write_str("x"),
+            Register::Y => f.write_str("y"),
+            Register::Z => f.write_str("z"),
+        }
+    }
+}
+
+impl FromStr for Register {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
         match input {
-            "w" => Register::W,
-            "x" => Register::X,
-            "y" => Register::Y,
-            "z" => Register::Z,
-            _ => Register::Value(input.parse().unwrap()),
+            "w" => Ok(Register::W),
+            "x" => Ok(Register::X),
+            "y" => Ok(Register::Y),
+            "z" => Ok(Register::Z),
+            _ => parse_number(input)
+                .map(Register::Value)
+                .map_err(|_| ParseError::new("Expected w, x, y, z or a number", input)),
         }
     }
 }
@@ -0,0 +1,91 @@
+use crate::expr::{Expr, ExprOp};
+use crate::register::Register;
+use std::fmt::{Display, Formatter};
+
+/// What a block does to `z`, when `z` is being used as a stack of numbers from 0 to 25.
+///
+/// Every block of MONAD either pushes a number, or pops one off and pushes something else unless it matches the input.
+/// See instruction_set.rs for how that was first worked out.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct StackOp {
+    /// Whether the top of the stack is popped off (`div z 26`) first, rather than left where it is (`div z 1`).
+    pub pop: bool,
+    /// What has to be true to push anything. None when it always pushes. Reads the old top of the stack as [`Expr::Top`].
+    pub condition: Option<Expr>,
+    pub push: Expr,
+}
+
+impl StackOp {
+    /// Recognizes the value a block leaves in `z`, in terms of what was in `z` when the block started,
+    /// as either `z * 26 + push` or `z * (25 * condition + 1) + push * condition`, where `z` may have been divided by 26 first.
+    pub fn find(z_out: &Expr) -> Option<Self> {
+        let z = Expr::Register(Register::Z);
+        let popped = Expr::op(ExprOp::Div, z.clone(), Expr::Value(26));
+        let terms = z_out.get_terms();
+        for (idx, term) in terms.iter().enumerate() {
+            let (scaled, factor) = match term {
+                Expr::Op(ExprOp::Mul, scaled, factor) => (scaled.as_ref(), factor.as_ref()),
+                _ => continue,
+            };
+            let pop = match scaled {
+                _ if *scaled == z => false,
+                _ if *scaled == popped => true,
+                _ => continue,
+            };
+            let rest = terms
+                .iter()
+                .enumerate()
+                .filter(|(other, _)| *other != idx)
+                .map(|(_, term)| (*term).clone())
+                .collect::<Vec<_>>();
+            let (condition, push) = match (factor, rest.as_slice()) {
+                (Expr::Value(26), _) => (None, Expr::sum(rest)),
+                (Expr::Op(ExprOp::Add, scale, one), [Expr::Op(ExprOp::Mul, push, condition)])
+                    if **one == Expr::Value(1)
+                        && **scale
+                            == Expr::op(ExprOp::Mul, Expr::Value(25), (**condition).clone()) =>
+                {
+                    (Some(condition.as_ref().clone()), push.as_ref().clone())
+                }
+                _ => continue,
+            };
+            let top = Expr::op(ExprOp::Mod, z.clone(), Expr::Value(26));
+            let condition = condition.map(|condition| condition.replace(&top, &Expr::Top));
+            let is_boolean = match &condition {
+                Some(Expr::Op(ExprOp::Eql | ExprOp::Neq, _, _)) | None => true,
+                Some(_) => false,
+            };
+            let reads_z = push.get_reads()[3]
+                || condition
+                    .as_ref()
+                    .is_some_and(|condition| condition.get_reads()[3]);
+            if is_boolean && !reads_z {
+                return Some(StackOp {
+                    pop,
+                    condition,
+                    push,
+                });
+            }
+        }
+        None
+    }
+}
+
+impl Display for StackOp {
+    /// Writes the operation as pseudo-code, like `top = pop; if top - 4 != input[3] { push input[3] + 6 }`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let reads_top = self
+            .condition
+            .as_ref()
+            .is_some_and(|condition| condition.contains(&Expr::Top));
+        match (self.pop, reads_top) {
+            (true, true) => f.write_str("top = pop; ")?,
+            (true, false) => f.write_str("pop; ")?,
+            (false, _) => {}
+        }
+        match &self.condition {
+            Some(condition) => write!(f, "if {} {{ push {} }}", condition, self.push),
+            None => write!(f, "push {}", self.push),
+        }
+    }
+}
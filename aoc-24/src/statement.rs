@@ -0,0 +1,27 @@
+use crate::expr::Expr;
+use crate::register::Register;
+use std::fmt::{Display, Formatter};
+
+/// Stores the value of an expression in a register, like `z = z * 26 + input[0] + 3`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Statement {
+    pub target: Register,
+    pub expr: Expr,
+}
+
+impl Statement {
+    pub fn new(target: Register, expr: Expr) -> Self {
+        Statement { target, expr }
+    }
+
+    /// Returns which of `w`, `x`, `y` and `z` the statement stores into.
+    pub fn get_target_index(&self) -> usize {
+        self.target.get_index().expect("Can't store into a number!")
+    }
+}
+
+impl Display for Statement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} = {}", self.target, self.expr)
+    }
+}
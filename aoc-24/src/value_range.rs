@@ -0,0 +1,214 @@
+use std::fmt::{Display, Formatter};
+
+/// Every value a register might hold, from `lo` to `hi` inclusive.
+///
+/// The operations mirror [`crate::alu::Alu`], and return None when every possible value would make the ALU fail,
+/// so a range only ever holds values from runs that could still succeed.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ValueRange {
+    lo: i64,
+    hi: i64,
+}
+
+impl ValueRange {
+    /// Panics if `lo` is greater than `hi`.
+    pub fn new(lo: i64, hi: i64) -> Self {
+        assert!(lo <= hi, "Ranges can't be empty!");
+        ValueRange { lo, hi }
+    }
+
+    pub fn exactly(value: i64) -> Self {
+        ValueRange::new(value, value)
+    }
+
+    /// Every value an `i64` can hold, for when nothing is known.
+    pub fn full() -> Self {
+        ValueRange::new(i64::MIN, i64::MAX)
+    }
+
+    pub fn get_lo(&self) -> i64 {
+        self.lo
+    }
+
+    pub fn get_hi(&self) -> i64 {
+        self.hi
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.lo <= value && value <= self.hi
+    }
+
+    /// Returns the only value in the range, if there's only one.
+    pub fn as_value(&self) -> Option<i64> {
+        if self.lo == self.hi {
+            Some(self.lo)
+        } else {
+            None
+        }
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        let lo = self.lo as i128 + other.lo as i128;
+        let hi = self.hi as i128 + other.hi as i128;
+        ValueRange::from_wide(lo, hi)
+    }
+
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        let products = [
+            self.lo as i128 * other.lo as i128,
+            self.lo as i128 * other.hi as i128,
+            self.hi as i128 * other.lo as i128,
+            self.hi as i128 * other.hi as i128,
+        ];
+        ValueRange::from_corners(&products)
+    }
+
+    /// Dividing by zero fails, so only the non-zero parts of `other` are divided by.
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        let negative =
+            ValueRange::non_empty(other.lo, other.hi.min(-1)).and_then(|o| self.div_by(&o));
+        let positive =
+            ValueRange::non_empty(other.lo.max(1), other.hi).and_then(|o| self.div_by(&o));
+        match (negative, positive) {
+            (Some(n), Some(p)) => Some(n.union(&p)),
+            (n, p) => n.or(p),
+        }
+    }
+
+    /// Mod fails unless `self` is at least 0 and `other` is at least 1, so only those parts are kept.
+    pub fn checked_rem(&self, other: &Self) -> Option<Self> {
+        let a = ValueRange::non_empty(self.lo.max(0), self.hi)?;
+        let b = ValueRange::non_empty(other.lo.max(1), other.hi)?;
+        if a.hi < b.lo {
+            return Some(a);
+        }
+        if let Some(b) = b.as_value() {
+            // Doesn't wrap around, so it's just shifted down.
+            if a.hi - a.lo < b && a.lo % b <= a.hi % b {
+                return Some(ValueRange::new(a.lo % b, a.hi % b));
+            }
+        }
+        Some(ValueRange::new(0, a.hi.min(b.hi - 1)))
+    }
+
+    pub fn eql(&self, other: &Self) -> Self {
+        match (self.as_value(), other.as_value()) {
+            (Some(a), Some(b)) => ValueRange::exactly((a == b) as i64),
+            _ if self.hi < other.lo || other.hi < self.lo => ValueRange::exactly(0),
+            _ => ValueRange::new(0, 1),
+        }
+    }
+
+    /// Returns the smallest range holding everything in both ranges.
+    pub fn union(&self, other: &Self) -> Self {
+        ValueRange::new(self.lo.min(other.lo), self.hi.max(other.hi))
+    }
+
+    /// Divides by a range that doesn't have zero in it.
+    fn div_by(&self, other: &Self) -> Option<Self> {
+        // Division is monotonic in each argument when the divisor doesn't change sign, so the extremes are at the corners.
+        let quotients = [
+            self.lo as i128 / other.lo as i128,
+            self.lo as i128 / other.hi as i128,
+            self.hi as i128 / other.lo as i128,
+            self.hi as i128 / other.hi as i128,
+        ];
+        ValueRange::from_corners(&quotients)
+    }
+
+    fn from_corners(corners: &[i128]) -> Option<Self> {
+        let lo = *corners.iter().min().expect("Always 4 corners!");
+        let hi = *corners.iter().max().expect("Always 4 corners!");
+        ValueRange::from_wide(lo, hi)
+    }
+
+    /// Drops anything that doesn't fit in an `i64`, since the ALU would have overflowed getting there.
+    fn from_wide(lo: i128, hi: i128) -> Option<Self> {
+        let lo = lo.max(i64::MIN as i128);
+        let hi = hi.min(i64::MAX as i128);
+        if lo <= hi {
+            Some(ValueRange::new(lo as i64, hi as i64))
+        } else {
+            None
+        }
+    }
+
+    fn non_empty(lo: i64, hi: i64) -> Option<Self> {
+        if lo <= hi {
+            Some(ValueRange { lo, hi })
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for ValueRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.as_value() {
+            Some(value) => write!(f, "{}", value),
+            None => write!(f, "{}..={}", self.lo, self.hi),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::value_range::ValueRange;
+
+    /// Checks that every pair of values from `a` and `b` that `op` succeeds on ends up inside `result`.
+    fn assert_covers<F>(a: ValueRange, b: ValueRange, result: Option<ValueRange>, op: F)
+    where
+        F: Fn(i64, i64) -> Option<i64>,
+    {
+        for x in a.get_lo()..=a.get_hi() {
+            for y in b.get_lo()..=b.get_hi() {
+                if let Some(value) = op(x, y) {
+                    let range = result.unwrap_or_else(|| panic!("{} and {} gave nothing", a, b));
+                    assert!(range.contains(value), "{} not in {}", value, range);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn operations_are_sound() {
+        let ranges = [
+            ValueRange::new(-7, -2),
+            ValueRange::new(-3, 4),
+            ValueRange::new(0, 0),
+            ValueRange::new(0, 30),
+            ValueRange::new(1, 9),
+            ValueRange::exactly(26),
+            ValueRange::new(27, 52),
+        ];
+        for a in ranges {
+            for b in ranges {
+                assert_covers(a, b, a.checked_add(&b), |x, y| x.checked_add(y));
+                assert_covers(a, b, a.checked_mul(&b), |x, y| x.checked_mul(y));
+                assert_covers(a, b, a.checked_div(&b), |x, y| x.checked_div(y));
+                let rem = a.checked_rem(&b);
+                assert_covers(a, b, rem, |x, y| (x >= 0 && y > 0).then(|| x % y));
+                assert_covers(a, b, Some(a.eql(&b)), |x, y| Some((x == y) as i64));
+            }
+        }
+    }
+
+    #[test]
+    fn exact_results() {
+        let digit = ValueRange::new(1, 9);
+        assert_eq!(ValueRange::new(11, 36).eql(&digit), ValueRange::exactly(0));
+        assert_eq!(
+            ValueRange::new(27, 51).checked_rem(&ValueRange::exactly(26)),
+            Some(ValueRange::new(1, 25))
+        );
+        assert_eq!(digit.checked_div(&ValueRange::exactly(0)), None);
+        assert_eq!(ValueRange::new(-5, -1).checked_rem(&digit), None);
+        let max = ValueRange::exactly(i64::MAX);
+        assert_eq!(max.checked_add(&ValueRange::exactly(1)), None);
+        assert_eq!(
+            max.checked_add(&ValueRange::new(-1, 1)),
+            Some(ValueRange::new(i64::MAX - 1, i64::MAX))
+        );
+        assert_eq!(digit.to_string(), "1..=9");
+    }
+}
@@ -0,0 +1,52 @@
+use crate::ocean_floor::OceanFloor;
+use aoc_core::error::ParseError;
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::solution::{Example, Solution};
+
+pub mod ocean_floor;
+pub mod sea_cucumber;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const EXAMPLES: &'static [Example] = &[Example::both(
+        include_str!("../example.txt"),
+        "58",
+        "Merry Christmas!",
+    )];
+    type Parsed = OceanFloor;
+    type PartOne = u32;
+    type PartTwo = &'static str;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+        OceanFloor::try_from(input.to_lines())
+    }
+
+    /// First step where no sea cucumbers moved.
+    fn part_one(floor: &Self::Parsed) -> Self::PartOne {
+        let mut floor = floor.clone();
+        floor.step_until_settled()
+    }
+
+    /// There is no second puzzle on the last day, the star comes free with the other 49.
+    fn part_two(_floor: &Self::Parsed) -> Self::PartTwo {
+        "Merry Christmas!"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day25;
+    use aoc_core::harness::{assert_answers, assert_examples};
+
+    #[test]
+    fn examples() {
+        assert_examples::<Day25>();
+    }
+
+    #[test]
+    fn answers() {
+        assert_answers::<Day25>();
+    }
+}
@@ -1,14 +1,12 @@
 use crate::sea_cucumber::SeaCucumber;
+use aoc_core::error::ParseError;
+use aoc_core::grid::{Grid, Topology};
 use std::fmt::{Display, Formatter};
 use std::str::Lines;
 
-const Y_MAX: usize = 137;
-const X_MAX: usize = 139;
-
-type OceanFloorMap = [[SeaCucumber; X_MAX]; Y_MAX];
-
+#[derive(Clone)]
 pub struct OceanFloor {
-    map: OceanFloorMap,
+    map: Grid<SeaCucumber>,
     step: u32,
 }
 
@@ -38,78 +36,67 @@ impl OceanFloor {
     }
 
     fn get_moves(&self, t: SeaCucumber) -> Vec<(usize, usize)> {
-        let delta_x = if t == SeaCucumber::Right { 1 } else { 0usize };
-        let delta_y = if t == SeaCucumber::Down { 1 } else { 0usize };
         self.map
-            .into_iter()
-            .enumerate()
-            .flat_map(|(y, row)| {
-                row.into_iter()
-                    .enumerate()
-                    .filter(|(_, c)| *c == t)
-                    .map(move |(x, _)| (x, y))
-            })
-            .filter(move |(x, y)| *self.get_point(x + delta_x, y + delta_y) == SeaCucumber::None)
+            .iter()
+            .filter(|(_, _, c)| **c == t)
+            .map(|(x, y, _)| (x, y))
+            .filter(|(x, y)| self.map[self.get_destination(*x, *y, t)] == SeaCucumber::None)
             .collect()
     }
 
-    fn get_coords(&self, x: usize, y: usize) -> (usize, usize) {
-        let eff_x = if x >= X_MAX { x - X_MAX } else { x };
-        let eff_y = if y >= Y_MAX { y - Y_MAX } else { y };
-        (eff_x, eff_y)
-    }
-
-    fn get_point(&self, x: usize, y: usize) -> &SeaCucumber {
-        let (eff_x, eff_y) = self.get_coords(x, y);
-        &self.map[eff_y][eff_x]
+    /// Returns where a sea cucumber of type `t` at `(x, y)` would move to. Sea cucumbers that fall off the edge of the map reappear on the other side.
+    fn get_destination(&self, x: usize, y: usize, t: SeaCucumber) -> (usize, usize) {
+        let destination = match t {
+            SeaCucumber::Right => self.map.offset(x, y, 1, 0),
+            SeaCucumber::Down => self.map.offset(x, y, 0, 1),
+            SeaCucumber::None => panic!("Tried to move nothing!"),
+        };
+        destination.expect("The ocean floor wraps around, so there's always somewhere to go!")
     }
 
     fn move_point(&mut self, x: usize, y: usize) {
-        let next_type = self.map[y][x]; // Direct-access is fine since move-from points should always be in-bounds.
-        let (nx, ny) = match &next_type {
-            SeaCucumber::Right => self.get_coords(x + 1, y),
-            SeaCucumber::Down => self.get_coords(x, y + 1),
-            SeaCucumber::None => panic!("Tried to move nothing!"),
-        };
-        debug_assert_eq!(self.map[ny][nx], SeaCucumber::None);
-        self.map[y][x] = SeaCucumber::None;
-        self.map[ny][nx] = next_type;
+        let next_type = self.map[(x, y)];
+        let destination = self.get_destination(x, y, next_type);
+        debug_assert_eq!(self.map[destination], SeaCucumber::None);
+        self.map[(x, y)] = SeaCucumber::None;
+        self.map[destination] = next_type;
     }
 }
 
-impl From<Lines<'_>> for OceanFloor {
-    fn from(input: Lines<'_>) -> Self {
-        let mut map = [[SeaCucumber::default(); X_MAX]; Y_MAX];
-        for (y, line) in input.enumerate() {
-            for (x, char) in line.chars().enumerate() {
-                map[y][x] = SeaCucumber::from(char);
-            }
-        }
-        OceanFloor { map, step: 0 }
+impl TryFrom<Lines<'_>> for OceanFloor {
+    type Error = ParseError;
+
+    fn try_from(input: Lines<'_>) -> Result<Self, Self::Error> {
+        let map = Grid::parse_with(input, SeaCucumber::try_from)?.with_topology(Topology::Toroidal);
+        Ok(OceanFloor { map, step: 0 })
     }
 }
 
 impl Display for OceanFloor {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        for y in &self.map {
-            for x in y {
-                f.write_fmt(format_args!("{}", x))?;
-            }
-            f.write_str("\n")?;
-        }
-        Ok(())
+        f.write_fmt(format_args!("{}\n", self.map))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::ocean_floor::OceanFloor;
-    use aoc_core::puzzle_input::PuzzleInput;
+    use aoc_core::input_resolver::InputResolver;
 
     #[test]
     fn display() {
-        let input = PuzzleInput::new("../aoc-25/input.txt");
-        let floor = OceanFloor::from(input.to_lines());
+        let input = InputResolver::workspace().load(25).unwrap();
+        let floor = OceanFloor::try_from(input.to_lines()).unwrap();
         println!("{}", floor);
     }
+
+    #[test]
+    fn wraps_around() {
+        let mut floor = OceanFloor::try_from("..v\n>.>\n...".lines()).unwrap();
+        floor.step();
+        // The right-most sea cucumber is blocked by the one it would wrap around to, which in turn blocks the one above.
+        assert_eq!(floor.to_string(), "..v\n.>>\n...\n");
+        floor.step();
+        assert_eq!(floor.to_string(), "...\n>>v\n...\n");
+    }
 }
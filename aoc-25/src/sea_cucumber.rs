@@ -1,29 +1,27 @@
+use aoc_core::error::ParseError;
 use std::fmt::{Display, Formatter};
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
 pub enum SeaCucumber {
     Right,
     Down,
+    #[default]
     None,
 }
 
-impl From<char> for SeaCucumber {
-    fn from(input: char) -> Self {
+impl TryFrom<char> for SeaCucumber {
+    type Error = ParseError;
+
+    fn try_from(input: char) -> Result<Self, Self::Error> {
         match input {
-            '>' => SeaCucumber::Right,
-            'v' => SeaCucumber::Down,
-            '.' => SeaCucumber::None,
-            _ => panic!("Invalid input: {}", input),
+            '>' => Ok(SeaCucumber::Right),
+            'v' => Ok(SeaCucumber::Down),
+            '.' => Ok(SeaCucumber::None),
+            _ => Err(ParseError::new("Expected >, v or .", &input.to_string())),
         }
     }
 }
 
-impl Default for SeaCucumber {
-    fn default() -> Self {
-        SeaCucumber::None
-    }
-}
-
 impl Display for SeaCucumber {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let s = match self {
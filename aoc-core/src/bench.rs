@@ -0,0 +1,133 @@
+use crate::error::ParseError;
+use crate::puzzle_input::PuzzleInput;
+use crate::solution::Solution;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// How long one stage of solving a day took, over every iteration of a benchmark.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Timing {
+    pub min: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+}
+
+impl Timing {
+    /// Summarizes the time each iteration took. Panics if there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let total: Duration = samples.iter().sum();
+        Timing {
+            min: *samples.iter().min().expect("No samples to summarize!"),
+            mean: total / samples.len() as u32,
+            max: *samples.iter().max().expect("No samples to summarize!"),
+        }
+    }
+}
+
+/// How long parsing and each part of a day took.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Benchmark {
+    pub day: u8,
+    pub iterations: u32,
+    pub parse: Timing,
+    pub part_one: Timing,
+    pub part_two: Timing,
+}
+
+impl Benchmark {
+    /// The average time to solve the whole day, from parsing through part two.
+    pub fn get_mean_total(&self) -> Duration {
+        self.parse.mean + self.part_one.mean + self.part_two.mean
+    }
+}
+
+/// A type-erased [`bench`], so that solutions with different answer types can be kept in one list.
+pub type Bencher = fn(&PuzzleInput, u32) -> Result<Benchmark, ParseError>;
+
+/// Parses `input` with the solution `S` and solves both parts, `iterations` times over, timing each stage separately.
+///
+/// Every iteration parses the input again, so parsing is timed just as often as the parts are. Panics if `iterations` is zero.
+pub fn bench<S: Solution>(input: &PuzzleInput, iterations: u32) -> Result<Benchmark, ParseError> {
+    assert!(iterations > 0, "Can't benchmark zero iterations!");
+    let mut parse = Vec::with_capacity(iterations as usize);
+    let mut part_one = Vec::with_capacity(iterations as usize);
+    let mut part_two = Vec::with_capacity(iterations as usize);
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = S::parse(black_box(input))?;
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part_one(black_box(&parsed)));
+        part_one.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part_two(black_box(&parsed)));
+        part_two.push(start.elapsed());
+    }
+
+    Ok(Benchmark {
+        day: S::DAY,
+        iterations,
+        parse: Timing::from_samples(&parse),
+        part_one: Timing::from_samples(&part_one),
+        part_two: Timing::from_samples(&part_two),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::{bench, Timing};
+    use crate::error::ParseError;
+    use crate::puzzle_input::PuzzleInput;
+    use crate::solution::Solution;
+    use std::time::Duration;
+
+    struct Sleeper;
+
+    impl Solution for Sleeper {
+        const DAY: u8 = 4;
+        type Parsed = u64;
+        type PartOne = u64;
+        type PartTwo = u64;
+
+        fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+            input
+                .to_lines()
+                .count()
+                .try_into()
+                .map_err(|_| ParseError::new("Too many lines", ""))
+        }
+
+        fn part_one(parsed: &Self::Parsed) -> Self::PartOne {
+            *parsed
+        }
+
+        fn part_two(parsed: &Self::Parsed) -> Self::PartTwo {
+            std::thread::sleep(Duration::from_millis(*parsed));
+            *parsed
+        }
+    }
+
+    #[test]
+    fn summarizes_samples() {
+        let samples = [3, 1, 5].map(Duration::from_millis);
+        let timing = Timing::from_samples(&samples);
+        assert_eq!(timing.min, Duration::from_millis(1));
+        assert_eq!(timing.mean, Duration::from_millis(3));
+        assert_eq!(timing.max, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn can_bench() {
+        let input = PuzzleInput::from("a\nb");
+        let benchmark = bench::<Sleeper>(&input, 3).unwrap();
+        assert_eq!(benchmark.day, 4);
+        assert_eq!(benchmark.iterations, 3);
+        assert!(benchmark.part_two.min >= Duration::from_millis(2));
+        assert!(benchmark.part_two.min <= benchmark.part_two.mean);
+        assert!(benchmark.part_two.mean <= benchmark.part_two.max);
+        assert!(benchmark.get_mean_total() >= benchmark.part_two.mean);
+    }
+}
@@ -0,0 +1,233 @@
+use crate::error::ParseError;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Mul};
+use std::str::FromStr;
+
+/// An unsigned integer that grows to fit whatever it holds, for answers that don't fit in a `u64`.
+///
+/// Only has the handful of operations puzzles have needed so far.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct BigUint {
+    /// 32-bit digits, least significant first, with no zeroes at the end. Zero has no digits at all.
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn zero() -> Self {
+        BigUint { limbs: Vec::new() }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Returns how many bits it takes to hold this number, which is 0 for zero.
+    pub fn bit_len(&self) -> usize {
+        match self.limbs.last() {
+            Some(last) => self.limbs.len() * 32 - last.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    /// Returns the bit at `index`, where 0 is the least significant bit.
+    pub fn get_bit(&self, index: usize) -> bool {
+        match self.limbs.get(index / 32) {
+            Some(limb) => (limb >> (index % 32)) & 1 == 1,
+            None => false,
+        }
+    }
+
+    /// Returns the number as a `u64`, if it fits in one.
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.limbs.as_slice() {
+            [] => Some(0),
+            [low] => Some(*low as u64),
+            [low, high] => Some(((*high as u64) << 32) | *low as u64),
+            _ => None,
+        }
+    }
+
+    /// Multiplies by `factor`, then adds `addend`.
+    pub fn mul_add_small(&mut self, factor: u32, addend: u32) {
+        let mut carry = addend as u64;
+        for limb in self.limbs.iter_mut() {
+            let value = *limb as u64 * factor as u64 + carry;
+            *limb = value as u32;
+            carry = value >> 32;
+        }
+        if carry > 0 {
+            self.limbs.push(carry as u32);
+        }
+        self.normalize();
+    }
+
+    /// Divides by `divisor` in place, returning the remainder. Panics if `divisor` is zero.
+    fn div_rem_small(&mut self, divisor: u32) -> u32 {
+        let mut remainder = 0u64;
+        for limb in self.limbs.iter_mut().rev() {
+            let value = (remainder << 32) | *limb as u64;
+            *limb = (value / divisor as u64) as u32;
+            remainder = value % divisor as u64;
+        }
+        self.normalize();
+        remainder as u32
+    }
+
+    fn normalize(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> Self {
+        let mut result = BigUint {
+            limbs: vec![value as u32, (value >> 32) as u32],
+        };
+        result.normalize();
+        result
+    }
+}
+
+impl Add for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let len = self.limbs.len().max(rhs.limbs.len());
+        let mut limbs = Vec::with_capacity(len + 1);
+        let mut carry = 0u64;
+        for idx in 0..len {
+            let lhs = *self.limbs.get(idx).unwrap_or(&0) as u64;
+            let rhs = *rhs.limbs.get(idx).unwrap_or(&0) as u64;
+            let value = lhs + rhs + carry;
+            limbs.push(value as u32);
+            carry = value >> 32;
+        }
+        limbs.push(carry as u32);
+        let mut result = BigUint { limbs };
+        result.normalize();
+        result
+    }
+}
+
+impl Mul for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut limbs = vec![0u32; self.limbs.len() + rhs.limbs.len()];
+        for (i, lhs) in self.limbs.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, rhs) in rhs.limbs.iter().enumerate() {
+                let value = *lhs as u64 * *rhs as u64 + limbs[i + j] as u64 + carry;
+                limbs[i + j] = value as u32;
+                carry = value >> 32;
+            }
+            limbs[i + rhs.limbs.len()] = carry as u32;
+        }
+        let mut result = BigUint { limbs };
+        result.normalize();
+        result
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Neither has leading zeroes, so whichever has more digits is bigger.
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for BigUint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Peel off 9 decimal digits at a time, since that's the most that fits in a u32.
+        let mut rest = self.clone();
+        let mut chunks = Vec::new();
+        loop {
+            chunks.push(rest.div_rem_small(1_000_000_000));
+            if rest.is_zero() {
+                break;
+            }
+        }
+        let mut chunks = chunks.iter().rev();
+        let first = chunks.next().expect("Always at least one chunk!");
+        let mut digits = first.to_string();
+        for chunk in chunks {
+            digits.push_str(&format!("{:09}", chunk));
+        }
+        f.pad_integral(true, "", &digits)
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = ParseError;
+
+    /// Parses a decimal number, of any length.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        if text.is_empty() {
+            return Err(ParseError::new("Expected a number", text));
+        }
+        let mut result = BigUint::zero();
+        for (idx, c) in text.char_indices() {
+            let digit = c.to_digit(10).ok_or_else(|| {
+                ParseError::within("Expected a digit", text, &text[idx..idx + c.len_utf8()])
+            })?;
+            result.mul_add_small(10, digit);
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::big_uint::BigUint;
+    use std::str::FromStr;
+
+    #[test]
+    fn arithmetic() {
+        let max = BigUint::from(u64::MAX);
+        let sum = &max + &BigUint::from(1);
+        assert_eq!(sum.to_string(), "18446744073709551616");
+        assert_eq!(sum.to_u64(), None);
+        assert_eq!(sum.bit_len(), 65);
+        assert!(sum.get_bit(64));
+        assert!(!sum.get_bit(63));
+
+        let product = &max * &max;
+        assert_eq!(
+            product.to_string(),
+            "340282366920938463426481119284349108225"
+        );
+        assert_eq!(&product * &BigUint::zero(), BigUint::zero());
+        assert_eq!((&BigUint::from(6) * &BigUint::from(7)).to_u64(), Some(42));
+    }
+
+    #[test]
+    fn ordering() {
+        let small = BigUint::from(u64::MAX);
+        let big = BigUint::from_str("18446744073709551616").unwrap();
+        assert!(small < big);
+        assert!(BigUint::zero() < small);
+        assert_eq!(big.clone().max(small), big);
+    }
+
+    #[test]
+    fn parse_and_display() {
+        for text in ["0", "7", "1000000000", "123456789012345678901234567890"] {
+            assert_eq!(BigUint::from_str(text).unwrap().to_string(), text);
+        }
+        assert_eq!(format!("{:>4}", BigUint::from(12)), "  12");
+        assert_eq!(BigUint::from_str("12a4").unwrap_err().get_column(), 3);
+        assert!(BigUint::from_str("").is_err());
+    }
+}
@@ -142,6 +142,7 @@ impl<B: Bitty> Display for Bitmap<B> {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use crate::bit::bitmap::Bitmap;
 
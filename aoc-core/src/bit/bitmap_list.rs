@@ -1,4 +1,5 @@
 use crate::bit::bitmap::Bitmap;
+use crate::error::ParseError;
 use std::str::Lines;
 
 #[derive(Debug)]
@@ -13,29 +14,48 @@ impl BitmapList {
     }
 }
 
-impl<'a> From<Lines<'a>> for BitmapList {
-    fn from(lines: Lines<'a>) -> Self {
+impl TryFrom<Lines<'_>> for BitmapList {
+    type Error = ParseError;
+
+    fn try_from(lines: Lines<'_>) -> Result<Self, Self::Error> {
         let vec: Vec<&str> = lines.collect();
-        let first = vec.first();
-        assert_eq!(first.is_some(), true);
-        let size = first.unwrap().len();
-        let list: Vec<Bitmap> = vec
+        let size = match vec.first() {
+            Some(first) if !first.is_empty() => first.len(),
+            _ => return Err(ParseError::new("Expected a list of binary numbers", "")),
+        };
+        let list = vec
             .iter()
-            .map(|x| Bitmap::new(u32::from_str_radix(x, 2).unwrap(), size))
-            .collect();
-        BitmapList::new(list, size)
+            .enumerate()
+            .map(|(y, x)| {
+                if x.len() != size {
+                    let reason = format!("Expected a binary number {} digits long", size);
+                    return Err(ParseError::new(reason, x).offset_lines(y));
+                }
+                u32::from_str_radix(x, 2)
+                    .map(|value| Bitmap::new(value, size))
+                    .map_err(|_| ParseError::new("Expected a binary number", x).offset_lines(y))
+            })
+            .collect::<Result<Vec<Bitmap>, ParseError>>()?;
+        Ok(BitmapList::new(list, size))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::bit::bitmap_list::BitmapList;
-    use crate::puzzle_input::PuzzleInput;
+    use crate::input_resolver::InputResolver;
 
     #[test]
     fn it_can_construct() {
-        let input = PuzzleInput::new("../aoc-03/input.txt");
-        let list: BitmapList = input.to_lines().into();
+        let input = InputResolver::workspace().load(3).unwrap();
+        let list = BitmapList::try_from(input.to_lines()).unwrap();
         assert_eq!(list.bitmap_size, 12);
     }
+
+    #[test]
+    fn it_rejects_invalid_bits() {
+        let error = BitmapList::try_from("0101\n0121".lines()).unwrap_err();
+        assert_eq!(error.get_line(), 2);
+        assert!(BitmapList::try_from("0101\n01".lines()).is_err());
+    }
 }
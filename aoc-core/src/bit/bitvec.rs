@@ -4,7 +4,7 @@ use crate::bit::bitty::Bitty;
 use std::fmt::{Display, Formatter};
 use std::ops::Index;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct BitVec<B = u32> {
     maps: Vec<Bitmap<B>>,
 }
@@ -12,7 +12,7 @@ pub struct BitVec<B = u32> {
 impl<B: Bitty> BitVec<B> {
     pub fn with_capacity(capacity: usize) -> Self {
         let mut maps_len = capacity / B::max_bits();
-        if capacity % B::max_bits() != 0 {
+        if !capacity.is_multiple_of(B::max_bits()) {
             maps_len += 1;
         }
         let maps = (0..maps_len)
@@ -35,10 +35,7 @@ impl<B: Bitty> BitVec<B> {
         }
     }
 
-    pub fn get_range<Rng>(
-        &self,
-        range: Rng,
-    ) -> impl Iterator<Item = bool> + DoubleEndedIterator + '_
+    pub fn get_range<Rng>(&self, range: Rng) -> impl DoubleEndedIterator<Item = bool> + '_
     where
         Rng: Iterator<Item = usize> + DoubleEndedIterator + 'static,
     {
@@ -54,14 +51,26 @@ impl<B: Bitty> BitVec<B> {
     {
         let mut result = 0;
         for val in self.get_range(range).rev() {
-            result = result << 1;
+            result <<= 1;
             if val {
-                result = result | 1;
+                result |= 1;
             }
         }
         result
     }
 
+    /// The inverse of [`BitVec::get_range_as_int`], setting the bits in the range to the bits of `value`.
+    /// The lower bound of the range is set to the *least* significant bit, and bits of `value` past the end of the range are ignored.
+    pub fn set_range_from_int<Rng>(&mut self, range: Rng, value: u64)
+    where
+        Rng: Iterator<Item = usize>,
+    {
+        for (shift, index) in range.enumerate() {
+            let bit = shift < 64 && (value >> shift) & 1 == 1;
+            self.set(index, bit);
+        }
+    }
+
     pub fn set(&mut self, index: usize, value: bool) {
         let map_index = self.get_bitmap_index(index);
         let inner_index = self.get_bit_index(index);
@@ -115,6 +124,7 @@ impl<B: Bitty> Display for BitVec<B> {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use crate::bit::bitvec::BitVec;
 
@@ -174,4 +184,16 @@ mod tests {
         let int_3 = bitvec.get_range_as_int(0..8);
         assert_eq!(int_3, 170u64)
     }
+
+    #[test]
+    fn set_range_from_int() {
+        let mut bitvec = BitVec::<u8>::with_capacity(16);
+        bitvec.set_range_from_int((5..10).rev(), 29);
+        assert_eq!(bitvec.get_range_as_int((5..10).rev()), 29);
+        assert_eq!(bitvec.get_range_as_int(5..10), 23); // 11101 read the other way around is 10111
+        bitvec.set_range_from_int(0..16, u64::MAX);
+        assert_eq!(bitvec.get_range_as_int(0..16), u16::MAX as u64);
+        bitvec.set_range_from_int(0..8, 0);
+        assert_eq!(bitvec.get_range_as_int(0..16), 0xff00);
+    }
 }
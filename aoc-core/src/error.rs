@@ -0,0 +1,264 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// An error encountered while parsing puzzle input, pointing at the text that couldn't be parsed.
+///
+/// Lines and columns start at 1. A parser that only ever sees part of the input (a single line, or a single word in a line)
+/// reports positions relative to the text it was given, and whoever called it moves the error to the right place
+/// with [`ParseError::offset_lines`] and [`ParseError::offset_columns`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    text: String,
+    reason: String,
+}
+
+impl ParseError {
+    /// Creates an error for the offending `text`, positioned at the start of the first line.
+    pub fn new<S: Into<String>>(reason: S, text: &str) -> Self {
+        ParseError {
+            line: 1,
+            column: 1,
+            text: text.to_string(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Creates an error for the offending `text`, positioned wherever `text` starts inside of `line`.
+    ///
+    /// `text` must be a slice of `line` (as returned by `split`, `trim`, etc.), otherwise the error is positioned at the start of the line.
+    pub fn within<S: Into<String>>(reason: S, line: &str, text: &str) -> Self {
+        ParseError::new(reason, text).offset_columns(column_of(line, text))
+    }
+
+    /// Returns the line the error was found on.
+    pub fn get_line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the column the offending text starts at.
+    pub fn get_column(&self) -> usize {
+        self.column
+    }
+
+    /// Returns the text that couldn't be parsed.
+    pub fn get_text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns why the text couldn't be parsed.
+    pub fn get_reason(&self) -> &str {
+        &self.reason
+    }
+
+    /// Moves the error down by `lines`, for when the text being parsed didn't start on the first line of the input.
+    pub fn offset_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+
+    /// Moves the error right by `columns`, for when the text being parsed didn't start at the beginning of a line.
+    ///
+    /// Only errors on the first line are moved, since every line after that starts at the beginning of a line anyway.
+    pub fn offset_columns(mut self, columns: usize) -> Self {
+        if self.line == 1 {
+            self.column += columns;
+        }
+        self
+    }
+
+    /// Moves an error found in `text` to wherever `text` starts inside of `line`. See [`ParseError::within`].
+    pub fn located_in(self, line: &str, text: &str) -> Self {
+        self.offset_columns(column_of(line, text))
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "line {}, column {}: {}: {:?}",
+            self.line, self.column, self.reason, self.text
+        ))
+    }
+}
+
+impl Error for ParseError {}
+
+/// Returns how far into `line` that `text` starts, if `text` is a slice of `line`. Otherwise, returns 0.
+fn column_of(line: &str, text: &str) -> usize {
+    let start = line.as_ptr() as usize;
+    let offset = (text.as_ptr() as usize).wrapping_sub(start);
+    if offset <= line.len() {
+        offset
+    } else {
+        0
+    }
+}
+
+/// Parses `text` as a number, or returns an error pointing at it.
+pub fn parse_number<T: FromStr>(text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::new("Expected a number", text))
+}
+
+/// Parses each line of `lines` with `T::from_str`, positioning any error at the line it was found on.
+pub fn parse_lines<'a, T, I>(lines: I) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+    I: Iterator<Item = &'a str>,
+{
+    parse_lines_with(lines, T::from_str)
+}
+
+/// Parses each line of `lines` with `parser`, positioning any error at the line it was found on.
+pub fn parse_lines_with<'a, T, I, F>(lines: I, parser: F) -> Result<Vec<T>, ParseError>
+where
+    I: Iterator<Item = &'a str>,
+    F: Fn(&'a str) -> Result<T, ParseError>,
+{
+    lines
+        .enumerate()
+        .map(|(index, line)| parser(line).map_err(|e| e.offset_lines(index)))
+        .collect()
+}
+
+/// Parses every number in `text`, whether they're separated by commas (like `3,4,3,1,2`) or by whitespace (like a bingo board).
+///
+/// Commas are used if there are any, otherwise whitespace. Numbers can also be spread across several lines.
+pub fn parse_numbers<T: FromStr>(text: &str) -> Result<Vec<T>, ParseError> {
+    parse_separated_with(text, parse_number)
+}
+
+/// Splits `text` the same way as [`parse_numbers`], and parses each piece with `parser`, positioning any error at the piece it was found in.
+///
+/// Blank lines and commas at the end of a line are skipped, but an empty piece anywhere else (like in `1,,2`) is passed to `parser` like any other.
+pub fn parse_separated_with<'a, T, F>(text: &'a str, parser: F) -> Result<Vec<T>, ParseError>
+where
+    F: Fn(&'a str) -> Result<T, ParseError>,
+{
+    let has_commas = text.contains(',');
+    let mut result = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let pieces: Box<dyn Iterator<Item = &str>> = if has_commas {
+            let trimmed = line.trim();
+            let trimmed = trimmed.strip_suffix(',').unwrap_or(trimmed);
+            Box::new(trimmed.split(',').map(str::trim))
+        } else {
+            Box::new(line.split_whitespace())
+        };
+        for piece in pieces {
+            let value = parser(piece).map_err(|e| e.located_in(line, piece).offset_lines(index))?;
+            result.push(value);
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{
+        parse_lines, parse_lines_with, parse_number, parse_numbers, parse_separated_with,
+        ParseError,
+    };
+    use std::str::FromStr;
+
+    #[derive(Debug, Eq, PartialEq)]
+    struct Digit(u8);
+
+    impl FromStr for Digit {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            parse_number(s).map(Digit)
+        }
+    }
+
+    #[test]
+    fn display() {
+        let error = ParseError::new("Expected a number", "abc");
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: Expected a number: \"abc\""
+        );
+    }
+
+    #[test]
+    fn within() {
+        let line = "add x abc";
+        let error = ParseError::within("Expected a number", line, &line[6..]);
+        assert_eq!(error.get_line(), 1);
+        assert_eq!(error.get_column(), 7);
+        assert_eq!(error.get_text(), "abc");
+
+        // Not a slice of the line, so there's nothing to go on.
+        let error = ParseError::within("Expected a number", line, &String::from("abc"));
+        assert_eq!(error.get_column(), 1);
+    }
+
+    #[test]
+    fn offsets() {
+        let error = ParseError::new("Nope", "x")
+            .offset_columns(4)
+            .offset_lines(2);
+        assert_eq!((error.get_line(), error.get_column()), (3, 5));
+        // Once the error is past the first line, the columns are already relative to the start of its line.
+        let error = error.offset_columns(10);
+        assert_eq!((error.get_line(), error.get_column()), (3, 5));
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(parse_number::<u8>("42"), Ok(42));
+        assert_eq!(parse_number::<i32>("-42"), Ok(-42));
+        assert!(parse_number::<u8>("256").is_err());
+        assert_eq!(parse_number::<u8>("x").unwrap_err().get_text(), "x");
+    }
+
+    #[test]
+    fn lines() {
+        assert_eq!(
+            parse_lines::<Digit, _>("1\n2\n3".lines()),
+            Ok(vec![Digit(1), Digit(2), Digit(3)])
+        );
+        let error = parse_lines::<Digit, _>("1\n2\nthree".lines()).unwrap_err();
+        assert_eq!(error.get_line(), 3);
+        assert_eq!(error.get_text(), "three");
+
+        let numbers = parse_lines_with("1\n-2".lines(), parse_number::<i8>);
+        assert_eq!(numbers, Ok(vec![1, -2]));
+    }
+
+    #[test]
+    fn separated_numbers() {
+        assert_eq!(parse_numbers::<u8>("3,4,3,1,2\n"), Ok(vec![3, 4, 3, 1, 2]));
+        assert_eq!(parse_numbers::<u8>("1, 2,\n3"), Ok(vec![1, 2, 3]));
+        assert_eq!(
+            parse_numbers::<u8>("22 13 17\n 8  2 23\n\n"),
+            Ok(vec![22, 13, 17, 8, 2, 23])
+        );
+        assert_eq!(parse_numbers::<u8>(""), Ok(vec![]));
+
+        let error = parse_numbers::<u8>("1 2\n3 x 4").unwrap_err();
+        assert_eq!((error.get_line(), error.get_column()), (2, 3));
+        assert_eq!(error.get_text(), "x");
+        let error = parse_numbers::<u8>("1,,2").unwrap_err();
+        assert_eq!((error.get_line(), error.get_column()), (1, 3));
+    }
+
+    #[test]
+    fn separated_with() {
+        let small = |x| match parse_number::<u8>(x)? {
+            n if n < 9 => Ok(n),
+            _ => Err(ParseError::new("Too big", x)),
+        };
+        assert_eq!(parse_separated_with("1,8", small), Ok(vec![1, 8]));
+        let error = parse_separated_with("1,8,10", small).unwrap_err();
+        assert_eq!(error.get_reason(), "Too big");
+        assert_eq!(error.get_column(), 5);
+    }
+}
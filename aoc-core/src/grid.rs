@@ -0,0 +1,700 @@
+use crate::error::ParseError;
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// A point in a grid along with its value, as `(x, y, value)`.
+pub type GridPoint<T> = (usize, usize, T);
+
+/// Which of the surrounding points count as neighbours.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Adjacency {
+    /// Left, right, up and down.
+    Cardinal,
+    /// The four corners.
+    Diagonal,
+    /// All eight surrounding points, cardinal ones first.
+    All,
+}
+
+impl Adjacency {
+    /// Returns the `(dx, dy)` offsets of each neighbour, in the order they are returned by the grid.
+    pub fn get_offsets(&self) -> &'static [(isize, isize)] {
+        const ALL: [(isize, isize); 8] = [
+            // Left, right, up, down.
+            (-1, 0),
+            (1, 0),
+            (0, -1),
+            (0, 1),
+            // Top-left, top-right, bottom-left, bottom-right.
+            (-1, -1),
+            (1, -1),
+            (-1, 1),
+            (1, 1),
+        ];
+        match self {
+            Adjacency::Cardinal => &ALL[..4],
+            Adjacency::Diagonal => &ALL[4..],
+            Adjacency::All => &ALL,
+        }
+    }
+}
+
+/// What lies beyond the edges of a grid.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Topology<T> {
+    /// Nothing, points outside of the grid don't exist.
+    Bounded,
+    /// The grid wraps around, so moving off of one edge comes back on at the opposite edge.
+    Toroidal,
+    /// The grid goes on forever, and every point outside of the stored values holds the given value.
+    Infinite(T),
+}
+
+/// A rectangular grid of values, indexed by `(x, y)` from the top-left corner.
+///
+/// Grids are bounded unless given a different [`Topology`] with [`Grid::with_topology`].
+/// Neighbours, offsets, signed lookups and windows all respect the topology.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    /// Every value in the grid, from left to right, top to bottom.
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+    topology: Topology<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from a list of rows, where the index of the outer vector is the y position.
+    ///
+    /// Panics if the rows are not all the same length.
+    pub fn new(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map_or(0, |row| row.len());
+        let height = rows.len();
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "Every row in a grid must be the same length!"
+        );
+        Grid {
+            cells: rows.into_iter().flatten().collect(),
+            width,
+            height,
+            topology: Topology::Bounded,
+        }
+    }
+
+    /// Creates a `width` by `height` grid where every point is `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![value; width * height],
+            width,
+            height,
+            topology: Topology::Bounded,
+        }
+    }
+
+    /// Parses a grid where every character of every line is one point, converting each character with `parser`.
+    ///
+    /// Errors from `parser` are moved to the line and column of the character that caused them.
+    pub fn parse_with<'a, I, F>(lines: I, parser: F) -> Result<Self, ParseError>
+    where
+        I: Iterator<Item = &'a str>,
+        F: Fn(char) -> Result<T, ParseError>,
+    {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for (y, line) in lines.enumerate() {
+            let expected = *width.get_or_insert(line.chars().count());
+            if expected == 0 || line.chars().count() != expected {
+                let reason = match expected {
+                    0 => "Expected a grid".to_string(),
+                    _ => format!("Expected a row {} wide", expected),
+                };
+                return Err(ParseError::new(reason, line).offset_lines(y));
+            }
+            for (x, char) in line.chars().enumerate() {
+                cells.push(parser(char).map_err(|e| e.offset_columns(x).offset_lines(y))?);
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) => Ok(Grid {
+                cells,
+                width,
+                height,
+                topology: Topology::Bounded,
+            }),
+            None => Err(ParseError::new("Expected a grid", "")),
+        }
+    }
+
+    /// Replaces what lies beyond the edges of the grid.
+    pub fn with_topology(mut self, topology: Topology<T>) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    /// Returns what lies beyond the edges of the grid.
+    pub fn get_topology(&self) -> &Topology<T> {
+        &self.topology
+    }
+
+    /// Returns the horizontal size of the grid (not the last index).
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the vertical size of the grid (not the last index).
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the value at a point, or `None` if it is out-of-bounds.
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.get_index(x, y).map(|i| &self.cells[i])
+    }
+
+    /// Returns the value at any point, even one outside of the grid or a negative one, according to the grid's topology.
+    ///
+    /// Bounded grids return `None` outside of the grid, toroidal grids wrap the point around, and infinite grids return their background value.
+    pub fn get_at(&self, x: isize, y: isize) -> Option<&T> {
+        match (self.resolve(x, y), &self.topology) {
+            (Some((x, y)), _) => Some(&self[(x, y)]),
+            (None, Topology::Infinite(background)) => Some(background),
+            (None, _) => None,
+        }
+    }
+
+    /// Returns the value at a point mutably, or `None` if it is out-of-bounds.
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.get_index(x, y).map(move |i| &mut self.cells[i])
+    }
+
+    /// Replaces the value at a point.
+    ///
+    /// Panics if a provided x or y is outside the range of the grid.
+    pub fn set(&mut self, x: usize, y: usize, value: T) {
+        self[(x, y)] = value;
+    }
+
+    /// Returns a new grid with `f` applied to every value.
+    pub fn map<U, F: Fn(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            topology: match &self.topology {
+                Topology::Bounded => Topology::Bounded,
+                Topology::Toroidal => Topology::Toroidal,
+                Topology::Infinite(background) => Topology::Infinite(f(background)),
+            },
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Returns an iterator over every point and its value, from left to right, top to bottom.
+    pub fn iter(&self) -> impl Iterator<Item = GridPoint<&T>> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, value)| (i % self.width, i / self.width, value))
+    }
+
+    /// Returns an iterator over each row, from top to bottom.
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        // chunks panics on a size of 0, but there are no cells to chunk in an empty grid anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Returns an iterator over each column, from left to right. Each column is iterated from top to bottom.
+    pub fn iter_columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(move |x| (0..self.height).map(move |y| &self[(x, y)]))
+    }
+
+    /// Returns an iterator over every `width` by `height` window, from left to right, top to bottom.
+    /// Each window comes with the position of its top-left corner.
+    ///
+    /// On a toroidal grid there is a window starting at every point, wrapping around the edges.
+    /// Otherwise, only the windows that fit entirely inside of the grid are returned (see [`Grid::get_window`] for the rest).
+    pub fn iter_windows(
+        &self,
+        width: usize,
+        height: usize,
+    ) -> impl Iterator<Item = GridPoint<Grid<&T>>> + '_ {
+        let (xs, ys) = match self.topology {
+            Topology::Toroidal => (self.width, self.height),
+            _ => (
+                (self.width + 1).saturating_sub(width),
+                (self.height + 1).saturating_sub(height),
+            ),
+        };
+        (0..ys)
+            .flat_map(move |y| (0..xs).map(move |x| (x, y)))
+            .filter_map(move |(x, y)| {
+                let window = self.get_window(x as isize, y as isize, width, height)?;
+                Some((x, y, window))
+            })
+    }
+
+    /// Returns the `width` by `height` window with its top-left corner at `(x, y)`, according to the grid's topology.
+    ///
+    /// Returns `None` if any point in the window doesn't exist, which only happens on a bounded grid.
+    pub fn get_window(&self, x: isize, y: isize, width: usize, height: usize) -> Option<Grid<&T>> {
+        let cells = (y..y + height as isize)
+            .flat_map(|wy| (x..x + width as isize).map(move |wx| self.get_at(wx, wy)))
+            .collect::<Option<Vec<&T>>>()?;
+        Some(Grid {
+            cells,
+            width,
+            height,
+            topology: Topology::Bounded,
+        })
+    }
+
+    /// Returns the point `dx` across and `dy` down from `(x, y)`, or `None` if that would be outside of the grid.
+    ///
+    /// On a toroidal grid, moving off of one edge comes back on at the opposite edge, so this only returns `None` if the grid is empty.
+    pub fn offset(&self, x: usize, y: usize, dx: isize, dy: isize) -> Option<(usize, usize)> {
+        self.resolve(x as isize + dx, y as isize + dy)
+    }
+
+    /// Wraps any point, even a negative one, around the edges of the grid until it lands inside of it, regardless of the grid's topology.
+    ///
+    /// Panics if the grid is empty.
+    pub fn wrap(&self, x: isize, y: isize) -> (usize, usize) {
+        (
+            x.rem_euclid(self.width as isize) as usize,
+            y.rem_euclid(self.height as isize) as usize,
+        )
+    }
+
+    /// Returns the position of every neighbour of `(x, y)` that is inside of the grid, according to the grid's topology.
+    ///
+    /// On a toroidal grid narrower or shorter than 3, the same point may be returned more than once.
+    pub fn get_neighbours(&self, x: usize, y: usize, adjacency: Adjacency) -> Vec<(usize, usize)> {
+        adjacency
+            .get_offsets()
+            .iter()
+            .filter_map(|(dx, dy)| self.offset(x, y, *dx, *dy))
+            .collect()
+    }
+
+    /// Returns the value of every neighbour of `(x, y)`, according to the grid's topology.
+    ///
+    /// Unlike [`Grid::get_neighbours`], neighbours outside of an infinite grid are included with the background value.
+    pub fn get_neighbour_values(&self, x: isize, y: isize, adjacency: Adjacency) -> Vec<&T> {
+        adjacency
+            .get_offsets()
+            .iter()
+            .filter_map(|(dx, dy)| self.get_at(x + dx, y + dy))
+            .collect()
+    }
+
+    /// Returns a view of the grid repeated `nx` times across and `ny` times down, without copying it.
+    ///
+    /// Every value in the view is `transform(value, tile_x, tile_y)`, where `tile_x` and `tile_y` count which copy of the grid the value is in,
+    /// starting from 0 at the top-left copy.
+    pub fn tiled<F>(&self, nx: usize, ny: usize, transform: F) -> Tiled<'_, T, F>
+    where
+        F: Fn(&T, usize, usize) -> T,
+    {
+        Tiled {
+            grid: self,
+            nx,
+            ny,
+            transform,
+        }
+    }
+
+    /// Returns a new grid made of this one repeated `nx` times across and `ny` times down. See [`Grid::tiled`].
+    pub fn tile<F>(&self, nx: usize, ny: usize, transform: F) -> Grid<T>
+    where
+        F: Fn(&T, usize, usize) -> T,
+    {
+        self.tiled(nx, ny, transform).to_grid()
+    }
+
+    /// Moves a point to where it lands inside of the stored values, or `None` if it doesn't land anywhere.
+    fn resolve(&self, x: isize, y: isize) -> Option<(usize, usize)> {
+        match self.topology {
+            Topology::Toroidal if self.width > 0 && self.height > 0 => Some(self.wrap(x, y)),
+            _ if x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height => {
+                Some((x as usize, y as usize))
+            }
+            _ => None,
+        }
+    }
+
+    fn get_index(&self, x: usize, y: usize) -> Option<usize> {
+        if x < self.width && y < self.height {
+            Some(y * self.width + x)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Copy> Grid<T> {
+    /// Returns a specific point in the grid. Points are 0-indexed.
+    ///
+    /// Panics if a provided x or y is outside the range of the grid.
+    pub fn get_point(&self, x: usize, y: usize) -> GridPoint<T> {
+        (x, y, self[(x, y)])
+    }
+
+    /// Sets a specific point in the grid. Points are 0-indexed.
+    ///
+    /// Panics if a provided x or y is outside the range of the grid.
+    pub fn set_point(&mut self, point: &GridPoint<T>) {
+        self.set(point.0, point.1, point.2);
+    }
+
+    /// Returns an iterator over all points in the grid, from left to right, top to bottom.
+    pub fn iter_points(&self) -> impl Iterator<Item = GridPoint<T>> + '_ {
+        self.iter().map(|(x, y, value)| (x, y, *value))
+    }
+
+    /// Returns a list of all adjacent points, excluding those that would be out-of-bounds.
+    pub fn get_adjacent_points(&self, x: usize, y: usize) -> Vec<GridPoint<T>> {
+        self.get_adjacent_points_with(x, y, Adjacency::All)
+    }
+
+    /// Returns a list of all adjacent points in cardinal directions, excluding those that would be out-of-bounds.
+    pub fn get_adjacent_points_cardinal(&self, x: usize, y: usize) -> Vec<GridPoint<T>> {
+        self.get_adjacent_points_with(x, y, Adjacency::Cardinal)
+    }
+
+    /// Returns a list of all adjacent points in diagonal directions, excluding those that would be out-of-bounds.
+    pub fn get_adjacent_points_diagonal(&self, x: usize, y: usize) -> Vec<GridPoint<T>> {
+        self.get_adjacent_points_with(x, y, Adjacency::Diagonal)
+    }
+
+    fn get_adjacent_points_with(
+        &self,
+        x: usize,
+        y: usize,
+        adjacency: Adjacency,
+    ) -> Vec<GridPoint<T>> {
+        self.get_neighbours(x, y, adjacency)
+            .into_iter()
+            .map(|(nx, ny)| self.get_point(nx, ny))
+            .collect()
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        match self.get_index(x, y) {
+            Some(i) => &self.cells[i],
+            None => panic!("Point ({}, {}) is outside of the grid!", x, y),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        match self.get_index(x, y) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("Point ({}, {}) is outside of the grid!", x, y),
+        }
+    }
+}
+
+/// Writes each row on its own line, with no separator between values.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.iter_rows().enumerate() {
+            if y > 0 {
+                f.write_str("\n")?;
+            }
+            for value in row {
+                f.write_fmt(format_args!("{}", value))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A grid repeated a number of times in each direction, with each copy transformed, that works out each value when it's looked at.
+/// Made with [`Grid::tiled`].
+///
+/// Views are always bounded, no matter the topology of the grid they were made from.
+pub struct Tiled<'a, T, F> {
+    grid: &'a Grid<T>,
+    nx: usize,
+    ny: usize,
+    transform: F,
+}
+
+impl<T, F: Fn(&T, usize, usize) -> T> Tiled<'_, T, F> {
+    /// Returns the horizontal size of the view (not the last index).
+    pub fn get_width(&self) -> usize {
+        self.grid.width * self.nx
+    }
+
+    /// Returns the vertical size of the view (not the last index).
+    pub fn get_height(&self) -> usize {
+        self.grid.height * self.ny
+    }
+
+    /// Returns the value at a point, or `None` if it is out-of-bounds.
+    pub fn get(&self, x: usize, y: usize) -> Option<T> {
+        if x >= self.get_width() || y >= self.get_height() {
+            return None;
+        }
+        let value = &self.grid[(x % self.grid.width, y % self.grid.height)];
+        Some((self.transform)(
+            value,
+            x / self.grid.width,
+            y / self.grid.height,
+        ))
+    }
+
+    /// Returns the position of every neighbour of `(x, y)`, excluding those that would be out-of-bounds.
+    pub fn get_neighbours(&self, x: usize, y: usize, adjacency: Adjacency) -> Vec<(usize, usize)> {
+        adjacency
+            .get_offsets()
+            .iter()
+            .filter_map(|(dx, dy)| {
+                let nx = x
+                    .checked_add_signed(*dx)
+                    .filter(|nx| *nx < self.get_width())?;
+                let ny = y
+                    .checked_add_signed(*dy)
+                    .filter(|ny| *ny < self.get_height())?;
+                Some((nx, ny))
+            })
+            .collect()
+    }
+
+    /// Copies every value in the view into a new grid.
+    pub fn to_grid(&self) -> Grid<T> {
+        let rows = (0..self.get_height())
+            .map(|y| {
+                (0..self.get_width())
+                    .map(|x| self.get(x, y).expect("Point is inside of the view!"))
+                    .collect()
+            })
+            .collect();
+        Grid::new(rows)
+    }
+}
+
+impl<T: Copy, F: Fn(&T, usize, usize) -> T> Tiled<'_, T, F> {
+    /// Returns a specific point in the view. Points are 0-indexed.
+    ///
+    /// Panics if a provided x or y is outside the range of the view.
+    pub fn get_point(&self, x: usize, y: usize) -> GridPoint<T> {
+        match self.get(x, y) {
+            Some(value) => (x, y, value),
+            None => panic!("Point ({}, {}) is outside of the view!", x, y),
+        }
+    }
+
+    /// Returns a list of all adjacent points in the given directions, excluding those that would be out-of-bounds.
+    pub fn get_adjacent_points_with(
+        &self,
+        x: usize,
+        y: usize,
+        adjacency: Adjacency,
+    ) -> Vec<GridPoint<T>> {
+        self.get_neighbours(x, y, adjacency)
+            .into_iter()
+            .map(|(nx, ny)| self.get_point(nx, ny))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ParseError;
+    use crate::grid::{Adjacency, Grid, Topology};
+
+    fn parse_light(char: char) -> Result<bool, ParseError> {
+        match char {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(ParseError::new("Expected # or .", &char.to_string())),
+        }
+    }
+
+    /// 0 1 2
+    /// 3 4 5
+    /// 6 7 8
+    /// 9 A B
+    fn construct() -> Grid<u8> {
+        Grid::new(
+            (0..4)
+                .map(|y| (0..3).map(|x| y * 3 + x).collect())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn can_parse() {
+        let grid = Grid::parse_with("#..\n.#.".lines(), parse_light).unwrap();
+        assert_eq!((grid.get_width(), grid.get_height()), (3, 2));
+        assert_eq!(grid.get(0, 0), Some(&true));
+        assert_eq!(grid.get(1, 1), Some(&true));
+        assert_eq!(grid.get(2, 1), Some(&false));
+        assert_eq!(grid.get(3, 1), None);
+
+        let error = Grid::parse_with("#..\n.x.".lines(), parse_light).unwrap_err();
+        assert_eq!((error.get_line(), error.get_column()), (2, 2));
+        let error = Grid::parse_with("#..\n.#".lines(), parse_light).unwrap_err();
+        assert_eq!((error.get_line(), error.get_column()), (2, 1));
+        let parse_accented = |char| match char {
+            'é' => Ok(true),
+            _ => parse_light(char),
+        };
+        let error = Grid::parse_with("é..\n.éx".lines(), parse_accented).unwrap_err();
+        assert_eq!((error.get_line(), error.get_column()), (2, 3));
+        assert!(Grid::parse_with("".lines(), parse_light).is_err());
+        assert!(Grid::parse_with("\n".lines(), parse_light).is_err());
+    }
+
+    #[test]
+    fn can_set() {
+        let mut grid = construct();
+        grid.set(2, 3, 42);
+        *grid.get_mut(0, 0).unwrap() += 1;
+        assert_eq!(grid[(2, 3)], 42);
+        assert_eq!(grid[(0, 0)], 1);
+        assert!(grid.get_mut(3, 0).is_none());
+    }
+
+    #[test]
+    fn can_iter_rows_and_columns() {
+        let grid = construct();
+        let rows: Vec<&[u8]> = grid.iter_rows().collect();
+        assert_eq!(rows, vec![&[0, 1, 2], &[3, 4, 5], &[6, 7, 8], &[9, 10, 11]]);
+        let columns: Vec<Vec<u8>> = grid
+            .iter_columns()
+            .map(|column| column.copied().collect())
+            .collect();
+        assert_eq!(
+            columns,
+            vec![vec![0, 3, 6, 9], vec![1, 4, 7, 10], vec![2, 5, 8, 11]]
+        );
+        assert_eq!(grid.iter().nth(4), Some((1, 1, &4)));
+    }
+
+    #[test]
+    fn can_iter_windows() {
+        let grid = construct();
+        let windows: Vec<_> = grid.iter_windows(2, 3).collect();
+        assert_eq!(windows.len(), 4);
+        let (x, y, window) = &windows[3];
+        assert_eq!((*x, *y), (1, 1));
+        assert_eq!(window.to_string(), "45\n78\n1011");
+        assert_eq!(grid.iter_windows(4, 1).count(), 0);
+        assert_eq!(grid.iter_windows(3, 4).count(), 1);
+    }
+
+    #[test]
+    fn can_get_neighbours() {
+        let grid = construct();
+        assert_eq!(
+            grid.get_neighbours(0, 0, Adjacency::Cardinal),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.get_neighbours(0, 0, Adjacency::Diagonal), vec![(1, 1)]);
+        assert_eq!(grid.get_neighbours(1, 1, Adjacency::All).len(), 8);
+        assert_eq!(grid.get_neighbours(2, 3, Adjacency::All).len(), 3);
+        let values: Vec<u8> = grid
+            .get_adjacent_points_cardinal(1, 1)
+            .iter()
+            .map(|p| p.2)
+            .collect();
+        assert_eq!(values, vec![3, 5, 1, 7]);
+    }
+
+    #[test]
+    fn toroidal() {
+        let grid = construct().with_topology(Topology::Toroidal);
+        assert_eq!(
+            grid.get_neighbours(0, 0, Adjacency::Cardinal),
+            vec![(2, 0), (1, 0), (0, 3), (0, 1)]
+        );
+        assert_eq!(
+            grid.get_neighbours(2, 3, Adjacency::Diagonal),
+            vec![(1, 2), (0, 2), (1, 0), (0, 0)]
+        );
+        assert_eq!(grid.get_adjacent_points(0, 0).len(), 8);
+        assert_eq!(grid.offset(2, 3, 1, 0), Some((0, 3)));
+        assert_eq!(grid.get_at(-7, 9), Some(&5));
+        assert_eq!(grid.wrap(-7, 9), (2, 1));
+
+        let windows: Vec<_> = grid.iter_windows(2, 2).collect();
+        assert_eq!(windows.len(), 12);
+        assert_eq!(
+            windows[11].2.to_string(),
+            "119
+20"
+        );
+
+        // Bounded grids just stop at the edges.
+        let grid = construct();
+        assert_eq!(grid.offset(2, 3, 1, 0), None);
+        assert_eq!(grid.get_at(-7, 9), None);
+        assert_eq!(grid.get_window(2, 3, 2, 2), None);
+    }
+
+    #[test]
+    fn infinite() {
+        let grid = construct().with_topology(Topology::Infinite(42));
+        assert_eq!(grid.get_at(1, 1), Some(&4));
+        assert_eq!(grid.get_at(-1, 100), Some(&42));
+        assert_eq!(
+            grid.get_neighbours(0, 0, Adjacency::Cardinal),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.get_neighbour_values(0, 0, Adjacency::Cardinal),
+            vec![&42, &1, &42, &3]
+        );
+        let window = grid.get_window(-1, -1, 2, 2).unwrap();
+        assert_eq!(window.to_string(), "4242\n420");
+        assert_eq!(grid.iter_windows(2, 2).count(), 6);
+        assert_eq!(
+            grid.map(|value| *value as u16 * 2).get_topology(),
+            &Topology::Infinite(84)
+        );
+    }
+
+    #[test]
+    fn can_tile() {
+        // 0 1 2
+        // 3 4 5
+        let grid = Grid::new(vec![vec![0u8, 1, 2], vec![3, 4, 5]]);
+        let tiled = grid.tiled(2, 3, |value, tx, ty| value + 10 * tx as u8 + 20 * ty as u8);
+        assert_eq!((tiled.get_width(), tiled.get_height()), (6, 6));
+        assert_eq!(tiled.get(0, 0), Some(0));
+        assert_eq!(tiled.get(4, 1), Some(14));
+        assert_eq!(tiled.get(2, 5), Some(45));
+        assert_eq!(tiled.get(5, 5), Some(55));
+        assert_eq!(tiled.get(6, 0), None);
+        assert_eq!(tiled.get_point(3, 2), (3, 2, 30));
+        assert_eq!(tiled.get_neighbours(5, 5, Adjacency::All).len(), 3);
+        assert_eq!(
+            tiled.get_adjacent_points_with(3, 0, Adjacency::Cardinal),
+            vec![(2, 0, 2), (4, 0, 11), (3, 1, 13)]
+        );
+
+        let tiled = grid.tile(2, 1, |value, tx, _| value * (tx as u8 + 1));
+        assert_eq!(tiled.get_width(), 6);
+        assert_eq!(tiled[(5, 1)], 10);
+        assert_eq!(tiled.to_string(), "012024\n3456810");
+    }
+
+    #[test]
+    fn display() {
+        let grid = Grid::parse_with("#..\n.#.".lines(), parse_light).unwrap();
+        let display = grid.map(|lit| if *lit { '#' } else { '.' }).to_string();
+        assert_eq!(display, "#..\n.#.");
+    }
+}
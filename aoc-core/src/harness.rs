@@ -0,0 +1,122 @@
+//! Checks solutions against the examples from their puzzle descriptions, and against the answers recorded for their puzzle input.
+//!
+//! Every day's tests call into here, so that a change to shared code that breaks an answer anywhere is caught.
+
+use crate::input_resolver::InputResolver;
+use crate::puzzle_input::PuzzleInput;
+use crate::solution::{solve, Part, Solution};
+use std::fs::read_to_string;
+
+/// Solves every one of `S::EXAMPLES`, panicking if any answer doesn't match the one given for the example.
+pub fn assert_examples<S: Solution>() {
+    assert!(!S::EXAMPLES.is_empty(), "Day {} has no examples!", S::DAY);
+    for (index, example) in S::EXAMPLES.iter().enumerate() {
+        let name = format!("Day {:02}, example {}", S::DAY, index + 1);
+        let parsed = S::parse(&PuzzleInput::from(example.input))
+            .unwrap_or_else(|e| panic!("{}: could not parse: {}", name, e));
+        if let Some(expected) = example.part_one {
+            let actual = S::part_one(&parsed).to_string();
+            assert_eq!(actual, expected, "{}, part 1", name);
+        }
+        if let Some(expected) = example.part_two {
+            let actual = S::part_two(&parsed).to_string();
+            assert_eq!(actual, expected, "{}, part 2", name);
+        }
+    }
+}
+
+/// Solves both parts of `S` for its checked-in `aoc-NN/input.txt`, panicking unless the answers match the ones recorded in `aoc-NN/answers.txt`.
+///
+/// The answers are recorded exactly as `aoc run NN` prints them, so a new day's answers can be recorded with `aoc run NN > aoc-NN/answers.txt`.
+/// Only the workspace's own inputs are used, since the recorded answers are for those and not for anyone else's.
+pub fn assert_answers<S: Solution>() {
+    let resolver = InputResolver::workspace();
+    let input = resolver
+        .load(S::DAY)
+        .unwrap_or_else(|e| panic!("Could not read input: {}", e));
+    let answers_path = resolver
+        .resolve_file(S::DAY, "answers.txt")
+        .unwrap_or_else(|e| panic!("Could not find answers: {}", e));
+    let expected = read_to_string(&answers_path)
+        .unwrap_or_else(|e| panic!("Could not read {}: {}", answers_path.display(), e))
+        .replace("\r\n", "\n");
+    let actual: String = solve::<S>(&input, &Part::ALL)
+        .unwrap_or_else(|e| panic!("Could not parse input for day {:02}: {}", S::DAY, e))
+        .iter()
+        .map(|answer| format!("{}\n", answer))
+        .collect();
+    assert_eq!(
+        actual,
+        expected,
+        "Answers for day {:02} have changed",
+        S::DAY
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{parse_lines_with, parse_number, ParseError};
+    use crate::harness::assert_examples;
+    use crate::puzzle_input::PuzzleInput;
+    use crate::solution::{Example, Solution};
+
+    /// Sums a list of numbers, then doubles the sum.
+    struct Summer;
+
+    impl Solution for Summer {
+        const DAY: u8 = 99;
+        const EXAMPLES: &'static [Example] = &[
+            Example::both("1\n2\n3", "6", "12"),
+            Example::part_two("10", "20"),
+        ];
+        type Parsed = Vec<u32>;
+        type PartOne = u32;
+        type PartTwo = u32;
+
+        fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+            parse_lines_with(input.to_lines(), parse_number)
+        }
+
+        fn part_one(numbers: &Self::Parsed) -> Self::PartOne {
+            numbers.iter().sum()
+        }
+
+        fn part_two(numbers: &Self::Parsed) -> Self::PartTwo {
+            numbers.iter().sum::<u32>() * 2
+        }
+    }
+
+    /// The same as [`Summer`], but with an example whose answer is wrong.
+    struct WrongSummer;
+
+    impl Solution for WrongSummer {
+        const DAY: u8 = 99;
+        const EXAMPLES: &'static [Example] = &[Example::part_one("1\n2\n3", "7")];
+        type Parsed = Vec<u32>;
+        type PartOne = u32;
+        type PartTwo = u32;
+
+        fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+            Summer::parse(input)
+        }
+
+        fn part_one(numbers: &Self::Parsed) -> Self::PartOne {
+            Summer::part_one(numbers)
+        }
+
+        fn part_two(numbers: &Self::Parsed) -> Self::PartTwo {
+            Summer::part_two(numbers)
+        }
+    }
+
+    #[test]
+    fn passes_examples() {
+        assert_examples::<Summer>();
+    }
+
+    #[test]
+    #[should_panic(expected = "Day 99, example 1, part 1")]
+    fn fails_wrong_example() {
+        assert_examples::<WrongSummer>();
+    }
+}
@@ -0,0 +1,194 @@
+use crate::puzzle_input::PuzzleInput;
+use std::env;
+use std::ffi::OsString;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The environment variable naming a directory to look for inputs in before any other.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// The environment variable naming whose inputs to use, for when several people's inputs are kept side by side.
+pub const USER_VAR: &str = "AOC_USER";
+
+/// Finds the files for each day, like its puzzle input, without depending on the current directory.
+///
+/// Each directory is laid out like the workspace, with a day's files in `aoc-NN/`. When a user is set, their files are in
+/// `<user>/aoc-NN/` inside each directory instead. Directories are searched in order, and the first one that has the file wins.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct InputResolver {
+    dirs: Vec<PathBuf>,
+    user: Option<String>,
+}
+
+impl InputResolver {
+    pub fn new(dirs: Vec<PathBuf>) -> Self {
+        InputResolver { dirs, user: None }
+    }
+
+    /// Only looks at the inputs checked in to this workspace, which is what the tests are written against.
+    pub fn workspace() -> Self {
+        InputResolver::new(vec![workspace_dir()])
+    }
+
+    /// Looks in `$AOC_INPUT_DIR` if it's set, then in `aoc-2021` inside the user's config directory, then in the workspace.
+    /// The user is taken from `$AOC_USER`, if it's set.
+    pub fn from_env() -> Self {
+        InputResolver::from_settings(
+            non_empty_var(INPUT_DIR_VAR).map(PathBuf::from),
+            config_dir(),
+            non_empty_var(USER_VAR).and_then(|user| user.into_string().ok()),
+        )
+    }
+
+    fn from_settings(
+        input_dir: Option<PathBuf>,
+        config_dir: Option<PathBuf>,
+        user: Option<String>,
+    ) -> Self {
+        let dirs = input_dir
+            .into_iter()
+            .chain(config_dir.map(|dir| dir.join("aoc-2021")))
+            .chain([workspace_dir()])
+            .collect();
+        InputResolver { dirs, user }
+    }
+
+    /// Uses `user`'s files instead of the ones shared by everyone.
+    pub fn with_user<S: Into<String>>(mut self, user: S) -> Self {
+        self.user = Some(user.into());
+        self
+    }
+
+    pub fn get_dirs(&self) -> &[PathBuf] {
+        &self.dirs
+    }
+
+    pub fn get_user(&self) -> Option<&str> {
+        self.user.as_deref()
+    }
+
+    /// Returns every path `file_name` could be at for `day`, in the order they're searched.
+    pub fn get_candidates(&self, day: u8, file_name: &str) -> Vec<PathBuf> {
+        self.dirs
+            .iter()
+            .map(|dir| {
+                let dir = match &self.user {
+                    Some(user) => dir.join(user),
+                    None => dir.clone(),
+                };
+                dir.join(format!("aoc-{:02}", day)).join(file_name)
+            })
+            .collect()
+    }
+
+    /// Returns the first path `file_name` exists at for `day`, or an error listing everywhere it was looked for.
+    pub fn resolve_file(&self, day: u8, file_name: &str) -> io::Result<PathBuf> {
+        let candidates = self.get_candidates(day, file_name);
+        match candidates.iter().find(|path| path.is_file()) {
+            Some(path) => Ok(path.clone()),
+            None => {
+                let tried: Vec<String> = candidates
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect();
+                Err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!(
+                        "No {} for day {:02}, tried: {}",
+                        file_name,
+                        day,
+                        tried.join(", ")
+                    ),
+                ))
+            }
+        }
+    }
+
+    /// Returns the path to `day`'s puzzle input.
+    pub fn resolve(&self, day: u8) -> io::Result<PathBuf> {
+        self.resolve_file(day, "input.txt")
+    }
+
+    /// Finds and reads `day`'s puzzle input.
+    pub fn load(&self, day: u8) -> io::Result<PuzzleInput> {
+        PuzzleInput::new(self.resolve(day)?)
+    }
+}
+
+/// Returns the root of the workspace this was built in, which is where every day's checked-in input is.
+pub fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc-core is always inside the workspace!")
+        .to_path_buf()
+}
+
+/// Returns the directory the user keeps configuration in, if there is one: `$XDG_CONFIG_HOME`, `~/.config` or `%APPDATA%`.
+pub fn config_dir() -> Option<PathBuf> {
+    non_empty_var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| non_empty_var("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .or_else(|| non_empty_var("APPDATA").map(PathBuf::from))
+}
+
+fn non_empty_var(name: &str) -> Option<OsString> {
+    env::var_os(name).filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input_resolver::{workspace_dir, InputResolver};
+    use std::path::PathBuf;
+
+    #[test]
+    fn finds_workspace_inputs() {
+        let resolver = InputResolver::workspace();
+        let path = resolver.resolve(3).unwrap();
+        assert_eq!(path, workspace_dir().join("aoc-03").join("input.txt"));
+        assert_eq!(resolver.load(3).unwrap().to_lines().count(), 1000);
+        assert!(resolver.resolve_file(3, "answers.txt").is_ok());
+    }
+
+    #[test]
+    fn missing_input() {
+        let resolver = InputResolver::workspace();
+        let error = resolver.resolve_file(3, "not-an-input.txt").unwrap_err();
+        assert!(error.to_string().contains("No not-an-input.txt for day 03"));
+        assert!(InputResolver::new(Vec::new()).resolve(3).is_err());
+    }
+
+    #[test]
+    fn searches_in_order() {
+        let resolver = InputResolver::from_settings(
+            Some(PathBuf::from("/inputs")),
+            Some(PathBuf::from("/config")),
+            None,
+        );
+        assert_eq!(
+            resolver.get_candidates(7, "input.txt"),
+            vec![
+                PathBuf::from("/inputs/aoc-07/input.txt"),
+                PathBuf::from("/config/aoc-2021/aoc-07/input.txt"),
+                workspace_dir().join("aoc-07/input.txt"),
+            ]
+        );
+
+        // The workspace is always last, so a directory that's missing some days falls back to the checked-in inputs.
+        let resolver = InputResolver::from_settings(Some(PathBuf::from("/nowhere")), None, None);
+        assert_eq!(resolver.get_dirs().len(), 2);
+        assert_eq!(
+            resolver.resolve(1).unwrap(),
+            workspace_dir().join("aoc-01/input.txt")
+        );
+    }
+
+    #[test]
+    fn per_user_inputs() {
+        let resolver = InputResolver::new(vec![PathBuf::from("/inputs")]).with_user("emily");
+        assert_eq!(resolver.get_user(), Some("emily"));
+        assert_eq!(
+            resolver.get_candidates(25, "input.txt"),
+            vec![PathBuf::from("/inputs/emily/aoc-25/input.txt")]
+        );
+    }
+}
@@ -1,6 +1,16 @@
+pub mod bench;
+pub mod big_uint;
 pub mod bit;
+pub mod error;
+pub mod grid;
+pub mod harness;
+pub mod input_resolver;
 pub mod intmap;
+pub mod pattern;
 pub mod puzzle_input;
+pub mod search;
+pub mod solution;
+pub mod sparse_grid;
 
 #[cfg(test)]
 mod tests {
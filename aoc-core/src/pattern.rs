@@ -0,0 +1,208 @@
+use crate::error::{parse_lines_with, ParseError};
+use std::any::type_name;
+use std::str::FromStr;
+
+/// A template for a line of puzzle input, with `{}` wherever there's a field to pull out, like `target area: x={}..{}, y={}..{}`.
+///
+/// Everything else in the template has to match the line exactly. Each field runs up to the next bit of the template,
+/// so fields can't be right next to each other.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Pattern {
+    /// The text before, between and after the fields, so there's always one more of these than there are fields.
+    literals: Vec<String>,
+}
+
+impl Pattern {
+    /// Panics if two fields are right next to each other, since there'd be no telling where one ends and the next starts.
+    pub fn new(template: &str) -> Self {
+        let literals: Vec<String> = template.split("{}").map(String::from).collect();
+        let between = literals.len().saturating_sub(2);
+        if literals.iter().skip(1).take(between).any(String::is_empty) {
+            panic!("Fields in {:?} need some text between them!", template);
+        }
+        Pattern { literals }
+    }
+
+    pub fn get_field_count(&self) -> usize {
+        self.literals.len() - 1
+    }
+
+    /// Returns the text of each field in `line`, without parsing them.
+    pub fn captures<'a>(&self, line: &'a str) -> Result<Vec<&'a str>, ParseError> {
+        let expected = |literal: &str, rest: &str| {
+            ParseError::within(format!("Expected {:?}", literal), line, rest)
+        };
+
+        let first = &self.literals[0];
+        let mut rest = line
+            .strip_prefix(first.as_str())
+            .ok_or_else(|| expected(first, line))?;
+        let mut fields = Vec::with_capacity(self.get_field_count());
+        for (index, literal) in self.literals.iter().enumerate().skip(1) {
+            let is_last = index == self.literals.len() - 1;
+            let (field, next) = if is_last {
+                // The last bit of the template has to be at the very end of the line.
+                let field = rest
+                    .strip_suffix(literal.as_str())
+                    .ok_or_else(|| expected(literal, rest))?;
+                (field, "")
+            } else {
+                let end = rest
+                    .find(literal.as_str())
+                    .ok_or_else(|| expected(literal, rest))?;
+                (&rest[..end], &rest[end + literal.len()..])
+            };
+            if field.is_empty() {
+                return Err(ParseError::within("Expected a field", line, field));
+            }
+            fields.push(field);
+            rest = next;
+        }
+        if !rest.is_empty() {
+            return Err(ParseError::within(
+                "Expected the end of the line",
+                line,
+                rest,
+            ));
+        }
+        Ok(fields)
+    }
+
+    /// Parses the fields in `line` into a tuple, like `(i32, i32)` for a pattern like `x={}..{}`.
+    ///
+    /// Panics if the tuple doesn't have one element for every field.
+    pub fn parse<T: FromFields>(&self, line: &str) -> Result<T, ParseError> {
+        assert_eq!(
+            T::COUNT,
+            self.get_field_count(),
+            "Pattern has {} fields, but is being parsed into {} values!",
+            self.get_field_count(),
+            T::COUNT
+        );
+        let fields = self.captures(line)?;
+        T::from_fields(line, &fields)
+    }
+
+    /// Parses every line of `lines` with [`Pattern::parse`], positioning any error at the line it was found on.
+    pub fn parse_lines<'a, T, I>(&self, lines: I) -> Result<Vec<T>, ParseError>
+    where
+        T: FromFields,
+        I: Iterator<Item = &'a str>,
+    {
+        parse_lines_with(lines, |line| self.parse(line))
+    }
+}
+
+/// Something that can be built from the fields [`Pattern`] pulls out of a line, like a tuple of numbers.
+pub trait FromFields: Sized {
+    /// How many fields this is built from.
+    const COUNT: usize;
+
+    /// Builds this from `fields`, which are all slices of `line`. There are always [`FromFields::COUNT`] of them.
+    fn from_fields(line: &str, fields: &[&str]) -> Result<Self, ParseError>;
+}
+
+/// Parses a single field, pointing any error at it.
+fn parse_field<T: FromStr>(line: &str, field: &str, index: usize) -> Result<T, ParseError> {
+    field.parse().map_err(|_| {
+        let name = type_name::<T>().rsplit("::").next().unwrap_or("value");
+        ParseError::within(
+            format!("Expected field {} to be a {}", index + 1, name),
+            line,
+            field,
+        )
+    })
+}
+
+macro_rules! impl_from_fields {
+    ($count:expr; $($t:ident: $index:tt),+) => {
+        impl<$($t: FromStr),+> FromFields for ($($t,)+) {
+            const COUNT: usize = $count;
+
+            fn from_fields(line: &str, fields: &[&str]) -> Result<Self, ParseError> {
+                Ok(($(parse_field::<$t>(line, fields[$index], $index)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_fields!(1; A: 0);
+impl_from_fields!(2; A: 0, B: 1);
+impl_from_fields!(3; A: 0, B: 1, C: 2);
+impl_from_fields!(4; A: 0, B: 1, C: 2, D: 3);
+impl_from_fields!(5; A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_from_fields!(6; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+
+#[cfg(test)]
+mod tests {
+    use crate::pattern::Pattern;
+
+    #[test]
+    fn captures() {
+        let pattern = Pattern::new("target area: x={}..{}, y={}..{}");
+        assert_eq!(pattern.get_field_count(), 4);
+        assert_eq!(
+            pattern.captures("target area: x=20..30, y=-10..-5"),
+            Ok(vec!["20", "30", "-10", "-5"])
+        );
+
+        let pattern = Pattern::new("{} -> {}");
+        assert_eq!(pattern.captures("CH -> B"), Ok(vec!["CH", "B"]));
+        assert_eq!(Pattern::new("no fields").captures("no fields"), Ok(vec![]));
+        assert!(Pattern::new("no fields")
+            .captures("no fields here")
+            .is_err());
+    }
+
+    #[test]
+    fn parse() {
+        let pattern = Pattern::new("fold along {}={}");
+        let (axis, line): (char, u32) = pattern.parse("fold along x=655").unwrap();
+        assert_eq!((axis, line), ('x', 655));
+
+        let pattern = Pattern::new("{},{} -> {},{}");
+        assert_eq!(
+            pattern.parse::<(u16, u16, u16, u16)>("0,9 -> 5,9"),
+            Ok((0, 9, 5, 9))
+        );
+        assert_eq!(
+            pattern.parse_lines::<(u8, u8, u8, u8), _>("1,2 -> 3,4\n5,6 -> 7,8".lines()),
+            Ok(vec![(1, 2, 3, 4), (5, 6, 7, 8)])
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let pattern = Pattern::new("x={}..{}");
+        let error = pattern.captures("y=1..2").unwrap_err();
+        assert_eq!(error.get_column(), 1);
+        let error = pattern.captures("x=1,2").unwrap_err();
+        assert_eq!(error.get_reason(), "Expected \"..\"");
+        assert_eq!(error.get_column(), 3);
+        let error = pattern.captures("x=..2").unwrap_err();
+        assert_eq!(error.get_reason(), "Expected a field");
+        assert_eq!(error.get_column(), 3);
+
+        let error = pattern.parse::<(u8, u8)>("x=1..two").unwrap_err();
+        assert_eq!(error.get_reason(), "Expected field 2 to be a u8");
+        assert_eq!(error.get_column(), 6);
+        assert_eq!(error.get_text(), "two");
+
+        let error = Pattern::new("{},{}")
+            .parse_lines::<(u8, u8), _>("1,2\n3;4".lines())
+            .unwrap_err();
+        assert_eq!(error.get_line(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn fields_need_separators() {
+        Pattern::new("{}{}");
+    }
+
+    #[test]
+    #[should_panic]
+    fn parse_needs_every_field() {
+        let _ = Pattern::new("{},{}").parse::<(u8,)>("1,2");
+    }
+}
@@ -1,6 +1,9 @@
+use crate::error::{parse_lines, parse_numbers, ParseError};
+use std::convert::Infallible;
 use std::fs::read_to_string;
+use std::io::{self, Read};
 use std::path::Path;
-use std::str::Lines;
+use std::str::{FromStr, Lines};
 
 #[derive(Debug)]
 pub struct PuzzleInput {
@@ -9,45 +12,263 @@ pub struct PuzzleInput {
 
 impl PuzzleInput {
     /// Reads the file provided in `path` and returns something usable by the puzzles.
-    /// This panics immediately if the file cannot be read.
-    pub fn new<P: AsRef<Path>>(path: P) -> Self {
-        let raw_result = read_to_string(path);
-        PuzzleInput {
-            raw: raw_result.unwrap(),
-        }
+    pub fn new<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let raw = read_to_string(path)?;
+        Ok(PuzzleInput { raw })
+    }
+
+    /// Reads everything from `reader` until it runs out.
+    pub fn from_reader<R: Read>(mut reader: R) -> io::Result<Self> {
+        let mut raw = String::new();
+        reader.read_to_string(&mut raw)?;
+        Ok(PuzzleInput { raw })
+    }
+
+    /// Reads everything piped in on stdin.
+    pub fn from_stdin() -> io::Result<Self> {
+        PuzzleInput::from_reader(io::stdin().lock())
     }
 
     pub fn as_string(&self) -> &String {
         &self.raw
     }
 
-    pub fn to_lines(&self) -> Lines {
+    pub fn to_lines(&self) -> Lines<'_> {
         self.raw.lines()
     }
+
+    /// Parses every line of the input into a `T`. Errors point at the line the bad input was found on.
+    pub fn parse_lines<T: FromStr<Err = ParseError>>(&self) -> Result<Vec<T>, ParseError> {
+        parse_lines(self.to_lines())
+    }
+
+    /// Parses every number in the input, separated by commas or whitespace. See [`parse_numbers`].
+    pub fn numbers<T: FromStr>(&self) -> Result<Vec<T>, ParseError> {
+        parse_numbers(&self.raw)
+    }
+
+    /// Splits the input into the groups of lines between blank lines, like the drawings and boards of a bingo game.
+    pub fn sections(&self) -> Sections<'_> {
+        Sections {
+            rest: &self.raw,
+            line: 0,
+        }
+    }
+}
+
+/// A group of consecutive lines from the puzzle input, with blank lines on either side. See [`PuzzleInput::sections`].
+///
+/// The section remembers where it started, so errors from parsing it can be moved to the right line of the whole input.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Section<'a> {
+    text: &'a str,
+    line: usize,
+}
+
+impl<'a> Section<'a> {
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn to_lines(&self) -> Lines<'a> {
+        self.text.lines()
+    }
+
+    /// Returns the number of lines in the input before this section.
+    pub fn get_line_offset(&self) -> usize {
+        self.line
+    }
+
+    /// Moves an error found by parsing this section on its own to where the section is in the whole input.
+    pub fn locate(&self, error: ParseError) -> ParseError {
+        error.offset_lines(self.line)
+    }
+
+    /// Parses the whole section into a `T`.
+    pub fn parse<T: FromStr<Err = ParseError>>(&self) -> Result<T, ParseError> {
+        self.text.parse().map_err(|e| self.locate(e))
+    }
+
+    /// Parses every line of the section into a `T`.
+    pub fn parse_lines<T: FromStr<Err = ParseError>>(&self) -> Result<Vec<T>, ParseError> {
+        parse_lines(self.to_lines()).map_err(|e| self.locate(e))
+    }
+
+    /// Parses every number in the section, separated by commas or whitespace. See [`parse_numbers`].
+    pub fn numbers<T: FromStr>(&self) -> Result<Vec<T>, ParseError> {
+        parse_numbers(self.text).map_err(|e| self.locate(e))
+    }
+}
+
+/// An iterator over the [`Section`]s of a puzzle input. Any number of blank lines can separate two sections.
+#[derive(Debug, Clone)]
+pub struct Sections<'a> {
+    rest: &'a str,
+    line: usize,
+}
+
+impl<'a> Sections<'a> {
+    /// Splits the first line off of `text`, returning it without its line ending, along with everything after it.
+    fn split_line(text: &'a str) -> (&'a str, &'a str) {
+        let (line, rest) = text.split_once('\n').unwrap_or((text, ""));
+        (line.strip_suffix('\r').unwrap_or(line), rest)
+    }
+}
+
+impl<'a> Iterator for Sections<'a> {
+    type Item = Section<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Skip over the blank lines before the section.
+        loop {
+            if self.rest.is_empty() {
+                return None;
+            }
+            let (line, rest) = Sections::split_line(self.rest);
+            if !line.trim().is_empty() {
+                break;
+            }
+            self.rest = rest;
+            self.line += 1;
+        }
+
+        let start = self.rest;
+        let first_line = self.line;
+        let mut len = 0;
+        while !self.rest.is_empty() {
+            let (line, rest) = Sections::split_line(self.rest);
+            if line.trim().is_empty() {
+                break;
+            }
+            len = (line.as_ptr() as usize - start.as_ptr() as usize) + line.len();
+            self.rest = rest;
+            self.line += 1;
+        }
+        Some(Section {
+            text: &start[..len],
+            line: first_line,
+        })
+    }
+}
+
+/// Uses puzzle input that is already in memory, such as an example from the puzzle description.
+impl From<&str> for PuzzleInput {
+    fn from(raw: &str) -> Self {
+        PuzzleInput {
+            raw: raw.to_string(),
+        }
+    }
+}
+
+impl FromStr for PuzzleInput {
+    type Err = Infallible;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        Ok(PuzzleInput::from(raw))
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::error::{parse_number, ParseError};
+    use crate::input_resolver::InputResolver;
     use crate::puzzle_input::PuzzleInput;
     use std::ops::Index;
+    use std::str::FromStr;
+
+    #[derive(Debug)]
+    struct Depth(u16);
+
+    impl FromStr for Depth {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            parse_number(s).map(Depth)
+        }
+    }
 
     #[test]
     fn can_construct_without_panic() {
-        let input = PuzzleInput::new("../aoc-03/input.txt");
+        let input = InputResolver::workspace().load(3).unwrap();
         assert!(!input.raw.is_empty());
     }
 
     #[test]
     fn as_string() {
-        let input = PuzzleInput::new("../aoc-03/input.txt");
+        let input = InputResolver::workspace().load(3).unwrap();
         assert!(!input.as_string().is_empty());
     }
 
     #[test]
     fn to_lines() {
-        let input = PuzzleInput::new("../aoc-03/input.txt");
+        let input = InputResolver::workspace().load(3).unwrap();
         let lines = input.to_lines().collect::<Vec<&str>>();
         assert_eq!(input.to_lines().collect::<Vec<&str>>().len(), 1000);
         assert_eq!(*lines.index(3), "000101111101")
     }
+
+    #[test]
+    fn missing_file() {
+        assert!(PuzzleInput::new("not-an-input.txt").is_err());
+    }
+
+    #[test]
+    fn numbers() {
+        let input = PuzzleInput::from("3,4,3,1,2\n");
+        assert_eq!(input.numbers::<u8>(), Ok(vec![3, 4, 3, 1, 2]));
+        let input = PuzzleInput::from("1 2\n3 4");
+        assert_eq!(input.numbers::<u8>(), Ok(vec![1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn sections() {
+        let input = PuzzleInput::from("\n7,4,9\n\n22 13\n 8  2\r\n\r\n\n 1 12\n 6 10\n");
+        let sections = input.sections().collect::<Vec<_>>();
+        assert_eq!(sections.len(), 3);
+        assert_eq!(sections[0].as_str(), "7,4,9");
+        assert_eq!(sections[0].get_line_offset(), 1);
+        assert_eq!(sections[1].as_str(), "22 13\n 8  2");
+        assert_eq!(sections[1].get_line_offset(), 3);
+        assert_eq!(
+            sections[2].to_lines().collect::<Vec<_>>(),
+            vec![" 1 12", " 6 10"]
+        );
+        assert_eq!(sections[2].get_line_offset(), 7);
+
+        assert_eq!(sections[0].numbers::<u8>(), Ok(vec![7, 4, 9]));
+        assert_eq!(sections[1].numbers::<u8>(), Ok(vec![22, 13, 8, 2]));
+        assert_eq!(PuzzleInput::from("\n\n").sections().count(), 0);
+    }
+
+    #[test]
+    fn section_errors() {
+        let input = PuzzleInput::from("1,2\n\n3\nfour\n5");
+        let section = input.sections().nth(1).unwrap();
+        let error = section.parse_lines::<Depth>().unwrap_err();
+        assert_eq!(error.get_line(), 4);
+        assert_eq!(error.get_text(), "four");
+        let error = section.numbers::<u8>().unwrap_err();
+        assert_eq!(error.get_line(), 4);
+        assert!(section.parse::<Depth>().is_err());
+    }
+
+    #[test]
+    fn from_reader_and_str() {
+        let input = PuzzleInput::from_reader("1\n2\n".as_bytes()).unwrap();
+        assert_eq!(input.to_lines().collect::<Vec<_>>(), vec!["1", "2"]);
+        let input = PuzzleInput::from_str("3\n4").unwrap();
+        assert_eq!(input.as_string(), "3\n4");
+    }
+
+    #[test]
+    fn parse_lines() {
+        let input = InputResolver::workspace().load(1).unwrap();
+        let depths = input.parse_lines::<Depth>().unwrap();
+        assert_eq!(depths.len(), 2000);
+        assert_eq!(depths[0].0, 157);
+
+        let input = InputResolver::workspace().load(3).unwrap();
+        let error = input.parse_lines::<Depth>().unwrap_err();
+        assert_eq!(error.get_line(), 1);
+    }
 }
@@ -0,0 +1,416 @@
+//! Path finding over any kind of graph, described by a function that returns the neighbours of a node.
+
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The way from a start node to a goal node.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SearchPath<N, C> {
+    /// The total cost of every step along the path. For unweighted searches, this is the number of steps.
+    pub cost: C,
+    /// Every node along the path, starting with the start node and ending with the goal.
+    pub path: Vec<N>,
+}
+
+/// Finds the cheapest path from `start` to a node where `is_goal` returns true.
+///
+/// `neighbours` returns every node reachable in one step from a node, along with the cost of that step.
+/// Costs must not be negative, and `C::default()` must be zero.
+pub fn dijkstra<N, C, FN, IN, FG>(start: N, neighbours: FN, is_goal: FG) -> Option<SearchPath<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FG: FnMut(&N) -> bool,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// Finds the cheapest path from `start` to a node where `is_goal` returns true, visiting the most promising nodes first.
+///
+/// `heuristic` estimates the remaining cost from a node to the nearest goal. As long as it never overestimates,
+/// the path found is the cheapest one. If it also never drops by more than the cost of a step, no node is visited twice.
+/// See [`dijkstra`] for the rest.
+pub fn astar<N, C, FN, IN, FH, FG>(
+    start: N,
+    neighbours: FN,
+    heuristic: FH,
+    is_goal: FG,
+) -> Option<SearchPath<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FG: FnMut(&N) -> bool,
+{
+    let search = explore(start, neighbours, heuristic, is_goal);
+    let goal = search.goal?;
+    Some(search.nodes.to_path(goal, search.costs[goal]))
+}
+
+/// Finds the cost of the cheapest path from `start` to every node reachable from it. See [`dijkstra`].
+pub fn dijkstra_all<N, C, FN, IN>(start: N, neighbours: FN) -> HashMap<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let search = explore(start, neighbours, |_| C::default(), |_| false);
+    search.nodes.list.into_iter().zip(search.costs).collect()
+}
+
+/// Everything learned by [`explore`].
+struct Exploration<N, C> {
+    nodes: Nodes<N>,
+    /// The cost of the cheapest path found to each node, by index.
+    costs: Vec<C>,
+    /// The index of the goal, if one was reached.
+    goal: Option<usize>,
+}
+
+/// Runs an A* search until a goal is reached, or until every reachable node has been visited.
+///
+/// A node that's already been visited is visited again if a cheaper path to it turns up later,
+/// which only happens when the heuristic drops by more than the cost of a step.
+fn explore<N, C, FN, IN, FH, FG>(
+    start: N,
+    mut neighbours: FN,
+    mut heuristic: FH,
+    mut is_goal: FG,
+) -> Exploration<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FG: FnMut(&N) -> bool,
+{
+    let mut nodes = Nodes::new(start);
+    let mut costs = vec![C::default()];
+    let mut queue = BinaryHeap::new();
+    queue.push(Candidate {
+        estimate: heuristic(&nodes.list[0]),
+        cost: C::default(),
+        index: 0,
+    });
+
+    while let Some(Candidate { cost, index, .. }) = queue.pop() {
+        // Only the cheapest path to a node is ever queued last, so anything costlier is out of date.
+        if cost > costs[index] {
+            continue;
+        }
+        if is_goal(&nodes.list[index]) {
+            return Exploration {
+                nodes,
+                costs,
+                goal: Some(index),
+            };
+        }
+
+        for (next, step) in neighbours(&nodes.list[index]) {
+            let next_cost = cost + step;
+            let next_index = match nodes.insert(next, index) {
+                (next_index, true) => {
+                    costs.push(next_cost);
+                    next_index
+                }
+                (next_index, false) => {
+                    if next_cost >= costs[next_index] {
+                        continue;
+                    }
+                    costs[next_index] = next_cost;
+                    nodes.parents[next_index] = Some(index);
+                    next_index
+                }
+            };
+            queue.push(Candidate {
+                estimate: next_cost + heuristic(&nodes.list[next_index]),
+                cost: next_cost,
+                index: next_index,
+            });
+        }
+    }
+
+    Exploration {
+        nodes,
+        costs,
+        goal: None,
+    }
+}
+
+/// Finds the path from `start` to a node where `is_goal` returns true with the fewest steps.
+///
+/// `neighbours` returns every node reachable in one step from a node.
+pub fn bfs<N, FN, IN, FG>(
+    start: N,
+    mut neighbours: FN,
+    mut is_goal: FG,
+) -> Option<SearchPath<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    let mut nodes = Nodes::new(start);
+    let mut steps = vec![0];
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(index) = queue.pop_front() {
+        if is_goal(&nodes.list[index]) {
+            return Some(nodes.to_path(index, steps[index]));
+        }
+        for next in neighbours(&nodes.list[index]) {
+            if let (next_index, true) = nodes.insert(next, index) {
+                steps.push(steps[index] + 1);
+                queue.push_back(next_index);
+            }
+        }
+    }
+
+    None
+}
+
+/// Finds any path from `start` to a node where `is_goal` returns true, following each branch as deep as it goes before trying the next.
+///
+/// The path found isn't necessarily the shortest, but no node is visited twice. See [`bfs`] for the rest.
+pub fn dfs<N, FN, IN, FG>(
+    start: N,
+    mut neighbours: FN,
+    mut is_goal: FG,
+) -> Option<SearchPath<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    let mut nodes = Nodes::new(start);
+    let mut visited = vec![false];
+    // Nodes may be pushed more than once before they are visited, so keep track of who pushed them each time.
+    let mut stack = vec![(0, None)];
+
+    while let Some((index, parent)) = stack.pop() {
+        if visited[index] {
+            continue;
+        }
+        visited[index] = true;
+        nodes.parents[index] = parent;
+        if is_goal(&nodes.list[index]) {
+            let mut result = nodes.to_path(index, 0);
+            result.cost = result.path.len() - 1;
+            return Some(result);
+        }
+        // Push in reverse, so that the first neighbour is the first one explored.
+        let next: Vec<N> = neighbours(&nodes.list[index]).into_iter().collect();
+        for next in next.into_iter().rev() {
+            let (next_index, is_new) = nodes.insert(next, index);
+            if is_new {
+                visited.push(false);
+            }
+            if !visited[next_index] {
+                stack.push((next_index, Some(index)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Every node seen so far by a search, so that nodes can be referred to by index instead of being cloned around.
+struct Nodes<N> {
+    list: Vec<N>,
+    indices: HashMap<N, usize>,
+    /// The node each node was reached from, on the best path found to it so far.
+    parents: Vec<Option<usize>>,
+}
+
+impl<N: Clone + Eq + Hash> Nodes<N> {
+    fn new(start: N) -> Self {
+        Nodes {
+            list: vec![start.clone()],
+            indices: HashMap::from([(start, 0)]),
+            parents: vec![None],
+        }
+    }
+
+    /// Returns the index of `node`, and whether it was just seen for the first time (in which case its parent is set).
+    fn insert(&mut self, node: N, parent: usize) -> (usize, bool) {
+        match self.indices.entry(node) {
+            Entry::Occupied(entry) => (*entry.get(), false),
+            Entry::Vacant(entry) => {
+                let index = self.list.len();
+                self.list.push(entry.key().clone());
+                self.parents.push(Some(parent));
+                entry.insert(index);
+                (index, true)
+            }
+        }
+    }
+
+    fn to_path<C>(&self, goal: usize, cost: C) -> SearchPath<N, C> {
+        let mut path = vec![self.list[goal].clone()];
+        let mut current = goal;
+        while let Some(parent) = self.parents[current] {
+            path.push(self.list[parent].clone());
+            current = parent;
+        }
+        path.reverse();
+        SearchPath { cost, path }
+    }
+}
+
+/// A node waiting to be visited. Ordered so that the lowest estimate comes out of a [`BinaryHeap`] first.
+struct Candidate<C> {
+    estimate: C,
+    cost: C,
+    index: usize,
+}
+
+impl<C: Ord> PartialEq for Candidate<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for Candidate<C> {}
+
+impl<C: Ord> PartialOrd for Candidate<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> Ord for Candidate<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Among equal estimates, prefer the node furthest along, since it's probably closer to the goal.
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then(self.cost.cmp(&other.cost))
+            .then(other.index.cmp(&self.index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::search::{astar, bfs, dfs, dijkstra, dijkstra_all};
+
+    /// A little road map, where each road goes both ways.
+    const ROADS: [(char, char, u32); 7] = [
+        ('a', 'b', 7),
+        ('a', 'c', 9),
+        ('a', 'f', 14),
+        ('b', 'c', 10),
+        ('b', 'd', 15),
+        ('c', 'd', 11),
+        ('c', 'f', 2),
+    ];
+
+    fn roads_from(town: &char) -> Vec<(char, u32)> {
+        ROADS
+            .iter()
+            .filter_map(|(a, b, cost)| match (a == town, b == town) {
+                (true, _) => Some((*b, *cost)),
+                (_, true) => Some((*a, *cost)),
+                _ => None,
+            })
+            .chain(match town {
+                'f' => Some(('e', 9)),
+                'd' => Some(('e', 6)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn finds_cheapest_path() {
+        let result = dijkstra('a', roads_from, |town| *town == 'e').unwrap();
+        assert_eq!(result.cost, 20);
+        assert_eq!(result.path, vec!['a', 'c', 'f', 'e']);
+
+        assert!(dijkstra('a', roads_from, |town| *town == 'z').is_none());
+        let result = dijkstra('a', roads_from, |town| *town == 'a').unwrap();
+        assert_eq!((result.cost, result.path), (0, vec!['a']));
+    }
+
+    #[test]
+    fn finds_every_cheapest_cost() {
+        let costs = dijkstra_all('a', roads_from);
+        assert_eq!(costs.len(), 6);
+        assert_eq!(costs[&'a'], 0);
+        assert_eq!(costs[&'b'], 7);
+        assert_eq!(costs[&'d'], 20);
+        assert_eq!(costs[&'e'], 20);
+        assert_eq!(costs[&'f'], 11);
+        // Roads to e are one way.
+        assert_eq!(dijkstra_all('e', roads_from).len(), 1);
+    }
+
+    #[test]
+    fn finds_cheapest_path_with_heuristic() {
+        // Walking around a 10x10 grid, with a manhattan distance heuristic.
+        let neighbours = |(x, y): &(i32, i32)| {
+            [(x - 1, *y), (x + 1, *y), (*x, y - 1), (*x, y + 1)]
+                .into_iter()
+                .filter(|(x, y)| (0..10).contains(x) && (0..10).contains(y))
+                // There's a wall in the way at x = 5, except at the very bottom.
+                .filter(|(x, y)| *x != 5 || *y == 9)
+                .map(|point| (point, 1u32))
+        };
+        let heuristic = |(x, y): &(i32, i32)| (9 - x).unsigned_abs() + y.unsigned_abs();
+        let result = astar((0, 0), neighbours, heuristic, |point| *point == (9, 0)).unwrap();
+        assert_eq!(result.cost, 27);
+        assert_eq!(result.path.len(), 28);
+        assert!(result.path.contains(&(5, 9)));
+    }
+
+    #[test]
+    fn finds_cheapest_path_with_inconsistent_heuristic() {
+        // s -> a -> c -> g is cheapest, but a's estimate makes c look better reached straight from s.
+        let neighbours = |node: &char| match node {
+            's' => vec![('a', 1), ('c', 3)],
+            'a' => vec![('c', 1)],
+            'c' => vec![('g', 3)],
+            _ => vec![],
+        };
+        // Never overestimates, but drops by 4 going from a to c, which is only 1 away.
+        let heuristic = |node: &char| if *node == 'a' { 4u32 } else { 0 };
+        let result = astar('s', neighbours, heuristic, |node| *node == 'g').unwrap();
+        assert_eq!(result.cost, 5);
+        assert_eq!(result.path, vec!['s', 'a', 'c', 'g']);
+    }
+
+    #[test]
+    fn finds_fewest_steps() {
+        let neighbours = |town: &char| roads_from(town).into_iter().map(|(town, _)| town);
+        let result = bfs('a', neighbours, |town| *town == 'e').unwrap();
+        assert_eq!(result.cost, 2);
+        assert_eq!(result.path, vec!['a', 'f', 'e']);
+        assert!(bfs('a', neighbours, |town| *town == 'z').is_none());
+    }
+
+    #[test]
+    fn finds_any_path() {
+        let neighbours = |town: &char| roads_from(town).into_iter().map(|(town, _)| town);
+        let result = dfs('a', neighbours, |town| *town == 'e').unwrap();
+        assert_eq!(result.path.first(), Some(&'a'));
+        assert_eq!(result.path.last(), Some(&'e'));
+        assert_eq!(result.cost, result.path.len() - 1);
+        // Every step along the way is a real road.
+        for step in result.path.windows(2) {
+            assert!(neighbours(&step[0]).any(|town| town == step[1]));
+        }
+        // Goes as deep as it can first: a -> b -> c -> d -> e.
+        assert_eq!(result.path, vec!['a', 'b', 'c', 'd', 'e']);
+        assert!(dfs('a', neighbours, |town| *town == 'z').is_none());
+    }
+}
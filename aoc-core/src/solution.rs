@@ -0,0 +1,222 @@
+use crate::error::ParseError;
+use crate::puzzle_input::PuzzleInput;
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+
+/// One of the two halves of a day's puzzle.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl TryFrom<u8> for Part {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(value),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.write_str("1"),
+            Part::Two => f.write_str("2"),
+        }
+    }
+}
+
+/// An example from a day's puzzle description, along with the answers the description gives for it.
+///
+/// Some examples are only given for one of the parts, so either answer can be left out.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Example {
+    pub input: &'static str,
+    pub part_one: Option<&'static str>,
+    pub part_two: Option<&'static str>,
+}
+
+impl Example {
+    /// An example with answers for both parts.
+    pub const fn both(input: &'static str, part_one: &'static str, part_two: &'static str) -> Self {
+        Example {
+            input,
+            part_one: Some(part_one),
+            part_two: Some(part_two),
+        }
+    }
+
+    /// An example that is only used by part one.
+    pub const fn part_one(input: &'static str, answer: &'static str) -> Self {
+        Example {
+            input,
+            part_one: Some(answer),
+            part_two: None,
+        }
+    }
+
+    /// An example that is only used by part two.
+    pub const fn part_two(input: &'static str, answer: &'static str) -> Self {
+        Example {
+            input,
+            part_one: None,
+            part_two: Some(answer),
+        }
+    }
+}
+
+/// A solution to a single day's puzzle.
+///
+/// The puzzle input is parsed once, and the parsed value is shared by both parts.
+/// Parts that need to mutate the parsed value (to run a simulation, for example) should clone it first.
+pub trait Solution {
+    /// The day of the advent calendar this solves, starting at 1.
+    const DAY: u8;
+
+    /// The examples given in the puzzle description. See [`crate::harness::assert_examples`].
+    const EXAMPLES: &'static [Example] = &[];
+
+    /// The value the puzzle input is parsed into.
+    type Parsed;
+
+    type PartOne: Display;
+
+    type PartTwo: Display;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError>;
+
+    fn part_one(parsed: &Self::Parsed) -> Self::PartOne;
+
+    fn part_two(parsed: &Self::Parsed) -> Self::PartTwo;
+}
+
+/// The answer to one part of a day's puzzle, formatted for display.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Answer {
+    pub day: u8,
+    pub part: Part,
+    pub value: String,
+    /// How long solving the part took, not including parsing the input.
+    pub elapsed: Duration,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Some answers (like day 13's dot sheet) span several lines, so start those on their own line.
+        let separator = if self.value.contains('\n') { "\n" } else { " " };
+        f.write_fmt(format_args!(
+            "Day {:02}, part {}:{}{}",
+            self.day, self.part, separator, self.value
+        ))
+    }
+}
+
+/// A type-erased [`solve`], so that solutions with different answer types can be kept in one list.
+pub type Solver = fn(&PuzzleInput, &[Part]) -> Result<Vec<Answer>, ParseError>;
+
+/// Parses `input` with the solution `S`, then solves each of the requested `parts` in order.
+pub fn solve<S: Solution>(input: &PuzzleInput, parts: &[Part]) -> Result<Vec<Answer>, ParseError> {
+    let parsed = S::parse(input)?;
+    let answers = parts
+        .iter()
+        .map(|part| {
+            let start = Instant::now();
+            let value = match part {
+                Part::One => S::part_one(&parsed).to_string(),
+                Part::Two => S::part_two(&parsed).to_string(),
+            };
+            Answer {
+                day: S::DAY,
+                part: *part,
+                value,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect();
+    Ok(answers)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ParseError;
+    use crate::input_resolver::InputResolver;
+    use crate::puzzle_input::PuzzleInput;
+    use crate::solution::{solve, Answer, Part, Solution};
+    use std::time::Duration;
+
+    struct LineCounter;
+
+    impl Solution for LineCounter {
+        const DAY: u8 = 3;
+        type Parsed = usize;
+        type PartOne = usize;
+        type PartTwo = String;
+
+        fn parse(input: &PuzzleInput) -> Result<Self::Parsed, ParseError> {
+            match input.to_lines().find(|line| line.is_empty()) {
+                Some(line) => Err(ParseError::new("Unexpected blank line", line)),
+                None => Ok(input.to_lines().count()),
+            }
+        }
+
+        fn part_one(parsed: &Self::Parsed) -> Self::PartOne {
+            *parsed
+        }
+
+        fn part_two(parsed: &Self::Parsed) -> Self::PartTwo {
+            format!("{} lines", parsed)
+        }
+    }
+
+    #[test]
+    fn part_from_int() {
+        assert_eq!(Part::try_from(1), Ok(Part::One));
+        assert_eq!(Part::try_from(2), Ok(Part::Two));
+        assert_eq!(Part::try_from(3), Err(3));
+    }
+
+    #[test]
+    fn can_solve() {
+        let input = InputResolver::workspace().load(3).unwrap();
+        let answers = solve::<LineCounter>(&input, &Part::ALL).unwrap();
+        assert_eq!(answers.len(), 2);
+        assert_eq!(answers[0].part, Part::One);
+        assert_eq!(answers[0].value, "1000");
+        assert_eq!(answers[1].part, Part::Two);
+        assert_eq!(answers[1].value, "1000 lines");
+
+        let answers = solve::<LineCounter>(&input, &[Part::Two]).unwrap();
+        assert_eq!(answers.len(), 1);
+        assert_eq!(answers[0].part, Part::Two);
+
+        let input = InputResolver::workspace().load(4).unwrap();
+        assert!(solve::<LineCounter>(&input, &Part::ALL).is_err());
+    }
+
+    #[test]
+    fn display_answer() {
+        let answer = Answer {
+            day: 3,
+            part: Part::One,
+            value: "1000".to_string(),
+            elapsed: Duration::from_millis(5),
+        };
+        assert_eq!(answer.to_string(), "Day 03, part 1: 1000");
+        let answer = Answer {
+            day: 13,
+            part: Part::Two,
+            value: "#.\n.#".to_string(),
+            elapsed: Duration::ZERO,
+        };
+        assert_eq!(answer.to_string(), "Day 13, part 2:\n#.\n.#");
+    }
+}
@@ -0,0 +1,191 @@
+use crate::grid::{Grid, Topology};
+use std::collections::HashMap;
+
+/// A point in a sparse grid, as `(x, y)`. Unlike a [`Grid`], points can be anywhere, including at negative positions.
+pub type SparsePoint = (i64, i64);
+
+/// A grid that goes on forever in every direction, where every point holds the same background value
+/// unless it has been set to something else.
+///
+/// Only the points that differ from the background are stored, so this is a good fit for mostly-empty grids, or grids that keep growing.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SparseGrid<T> {
+    /// Every point that doesn't hold the background value.
+    points: HashMap<SparsePoint, T>,
+    /// The value of every point that isn't in `points`.
+    background: T,
+    /// The top-left and bottom-right corners (inclusive) of the smallest box containing every point in `points`.
+    bounds: Option<(SparsePoint, SparsePoint)>,
+}
+
+impl<T: Clone + PartialEq> SparseGrid<T> {
+    /// Creates a grid where every point holds `background`.
+    pub fn new(background: T) -> Self {
+        SparseGrid {
+            points: HashMap::new(),
+            background,
+            bounds: None,
+        }
+    }
+
+    /// Copies every point of `grid` that isn't `background`, with the top-left corner of `grid` at `(0, 0)`.
+    pub fn from_grid(grid: &Grid<T>, background: T) -> Self {
+        let mut result = SparseGrid::new(background);
+        for (x, y, value) in grid.iter() {
+            result.set((x as i64, y as i64), value.clone());
+        }
+        result
+    }
+
+    /// Returns the value of every point that hasn't been set to something else.
+    pub fn get_background(&self) -> &T {
+        &self.background
+    }
+
+    /// Returns the value at a point.
+    pub fn get(&self, point: SparsePoint) -> &T {
+        self.points.get(&point).unwrap_or(&self.background)
+    }
+
+    /// Sets the value at a point. Setting a point back to the background value stops storing it.
+    pub fn set(&mut self, point: SparsePoint, value: T) {
+        if value == self.background {
+            if self.points.remove(&point).is_some() && self.is_on_edge(point) {
+                self.bounds = SparseGrid::<T>::measure(self.points.keys());
+            }
+        } else {
+            self.points.insert(point, value);
+            self.bounds = Some(match self.bounds {
+                None => (point, point),
+                Some((min, max)) => (
+                    (min.0.min(point.0), min.1.min(point.1)),
+                    (max.0.max(point.0), max.1.max(point.1)),
+                ),
+            });
+        }
+    }
+
+    /// Returns the number of points that don't hold the background value.
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns true if every point holds the background value.
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Returns an iterator over every point that doesn't hold the background value, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (SparsePoint, &T)> + '_ {
+        self.points.iter().map(|(point, value)| (*point, value))
+    }
+
+    /// Returns the top-left and bottom-right corners (inclusive) of the smallest box containing every point that doesn't hold the background value,
+    /// or `None` if there aren't any.
+    pub fn get_bounds(&self) -> Option<(SparsePoint, SparsePoint)> {
+        self.bounds
+    }
+
+    /// Returns the 3x3 square of values centered on `point`, from left to right, top to bottom.
+    pub fn get_window(&self, point: SparsePoint) -> [&T; 9] {
+        let (x, y) = point;
+        [
+            self.get((x - 1, y - 1)),
+            self.get((x, y - 1)),
+            self.get((x + 1, y - 1)),
+            self.get((x - 1, y)),
+            self.get((x, y)),
+            self.get((x + 1, y)),
+            self.get((x - 1, y + 1)),
+            self.get((x, y + 1)),
+            self.get((x + 1, y + 1)),
+        ]
+    }
+
+    /// Copies the bounding box into a dense [`Grid`], with the top-left corner of the bounds at `(0, 0)`.
+    /// The grid is infinite, so everything outside of it is still the background.
+    pub fn to_grid(&self) -> Grid<T> {
+        let rows = match self.bounds {
+            None => Vec::new(),
+            Some((min, max)) => (min.1..=max.1)
+                .map(|y| (min.0..=max.0).map(|x| self.get((x, y)).clone()).collect())
+                .collect(),
+        };
+        Grid::new(rows).with_topology(Topology::Infinite(self.background.clone()))
+    }
+
+    fn is_on_edge(&self, point: SparsePoint) -> bool {
+        match self.bounds {
+            None => false,
+            Some((min, max)) => {
+                point.0 == min.0 || point.1 == min.1 || point.0 == max.0 || point.1 == max.1
+            }
+        }
+    }
+
+    fn measure<'a, I: Iterator<Item = &'a SparsePoint>>(
+        points: I,
+    ) -> Option<(SparsePoint, SparsePoint)> {
+        points.fold(None, |bounds, point| match bounds {
+            None => Some((*point, *point)),
+            Some((min, max)) => Some((
+                (min.0.min(point.0), min.1.min(point.1)),
+                (max.0.max(point.0), max.1.max(point.1)),
+            )),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::{Grid, Topology};
+    use crate::sparse_grid::SparseGrid;
+
+    #[test]
+    fn can_set() {
+        let mut grid = SparseGrid::new('.');
+        assert_eq!(grid.get((-100, 100)), &'.');
+        assert_eq!(grid.get_bounds(), None);
+
+        grid.set((-2, 3), '#');
+        grid.set((4, -1), '#');
+        assert_eq!(grid.get((-2, 3)), &'#');
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.get_bounds(), Some(((-2, -1), (4, 3))));
+
+        // Setting a point to the background forgets it, and shrinks the bounds if it was on the edge.
+        grid.set((4, -1), '.');
+        assert_eq!(grid.len(), 1);
+        assert_eq!(grid.get_bounds(), Some(((-2, 3), (-2, 3))));
+        grid.set((-2, 3), '.');
+        assert!(grid.is_empty());
+        assert_eq!(grid.get_bounds(), None);
+    }
+
+    #[test]
+    fn can_get_window() {
+        let mut grid = SparseGrid::new(0u8);
+        grid.set((-1, -1), 1);
+        grid.set((0, 0), 5);
+        grid.set((1, 1), 9);
+        assert_eq!(
+            grid.get_window((0, 0)),
+            [&1, &0, &0, &0, &5, &0, &0, &0, &9]
+        );
+        assert_eq!(grid.get_window((2, -2)), [&0; 9]);
+    }
+
+    #[test]
+    fn converts_to_and_from_grid() {
+        let dense = Grid::new(vec![vec![0u8, 1, 0], vec![0, 0, 2]]);
+        let sparse = SparseGrid::from_grid(&dense, 0);
+        assert_eq!(sparse.len(), 2);
+        assert_eq!(sparse.get((2, 1)), &2);
+        assert_eq!(sparse.get_bounds(), Some(((1, 0), (2, 1))));
+
+        let dense = sparse.to_grid();
+        assert_eq!(dense.to_string(), "10\n02");
+        assert_eq!(dense.get_topology(), &Topology::Infinite(0));
+        assert_eq!(SparseGrid::new(0u8).to_grid().get_width(), 0);
+    }
+}
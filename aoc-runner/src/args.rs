@@ -0,0 +1,246 @@
+use crate::report::Format;
+use aoc_core::solution::Part;
+use std::path::PathBuf;
+
+pub const USAGE: &str = "Usage: aoc <command> [options]
+
+Commands:
+  run [DAY]         Solve DAY (1-25), or every day if DAY is omitted
+  bench [DAY]       Time parsing and each part of DAY, or of every day if DAY is omitted
+  help              Print this message
+
+Options:
+  --part <1|2>      Only solve the given part (run only)
+  --format <FORMAT> Write answers as text, json or csv, with how long each part took (run only, default text)
+  --input <PATH>    Read the puzzle input from PATH, or from stdin if PATH is - (requires DAY)
+  --iterations <N>  Time each day N times, and show the mean (bench only, default 5)
+  --json <PATH>     Also write the min, mean and max timings to PATH as JSON (bench only)
+
+Inputs are looked for in $AOC_INPUT_DIR/aoc-NN/input.txt, then ~/.config/aoc-2021/aoc-NN/input.txt, then in this
+workspace. Set $AOC_USER to use <dir>/$AOC_USER/aoc-NN/input.txt in each of those instead.";
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Command {
+    Run(RunArgs),
+    Bench(BenchArgs),
+    Help,
+}
+
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct RunArgs {
+    /// The day to solve, or every day if none.
+    pub day: Option<u8>,
+    /// The part to solve, or both parts if none.
+    pub part: Option<Part>,
+    /// The puzzle input to use instead of the checked-in input.
+    pub input: Option<PathBuf>,
+    /// How to write out the answers.
+    pub format: Format,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct BenchArgs {
+    /// The day to time, or every day if none.
+    pub day: Option<u8>,
+    /// How many times to solve each day.
+    pub iterations: u32,
+    /// The puzzle input to use instead of the checked-in input.
+    pub input: Option<PathBuf>,
+    /// Where to write the timings as JSON, if anywhere.
+    pub json: Option<PathBuf>,
+}
+
+impl Default for BenchArgs {
+    fn default() -> Self {
+        BenchArgs {
+            day: None,
+            iterations: 5,
+            input: None,
+            json: None,
+        }
+    }
+}
+
+impl Command {
+    /// Parses the command line arguments, not including the name of the binary.
+    pub fn parse<I: Iterator<Item = String>>(mut args: I) -> Result<Self, String> {
+        match args.next().as_deref() {
+            None | Some("help") | Some("--help") | Some("-h") => Ok(Command::Help),
+            Some("run") => RunArgs::parse(args).map(Command::Run),
+            Some("bench") => BenchArgs::parse(args).map(Command::Bench),
+            Some(other) => Err(format!("Unknown command: {}", other)),
+        }
+    }
+}
+
+impl RunArgs {
+    fn parse<I: Iterator<Item = String>>(mut args: I) -> Result<Self, String> {
+        let mut result = RunArgs::default();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--part" => {
+                    let value = args.next().ok_or("--part requires a value")?;
+                    let part = value
+                        .parse::<u8>()
+                        .ok()
+                        .and_then(|p| Part::try_from(p).ok())
+                        .ok_or(format!("Invalid part: {}", value))?;
+                    result.part = Some(part);
+                }
+                "--input" => {
+                    let value = args.next().ok_or("--input requires a value")?;
+                    result.input = Some(PathBuf::from(value));
+                }
+                "--format" => {
+                    let value = args.next().ok_or("--format requires a value")?;
+                    result.format = value.parse()?;
+                }
+                _ if result.day.is_none() && !arg.starts_with("--") => {
+                    result.day = Some(parse_day(&arg)?);
+                }
+                _ => return Err(format!("Unexpected argument: {}", arg)),
+            }
+        }
+        if result.input.is_some() && result.day.is_none() {
+            return Err("--input can only be used when running a single day".to_string());
+        }
+        Ok(result)
+    }
+}
+
+impl BenchArgs {
+    fn parse<I: Iterator<Item = String>>(mut args: I) -> Result<Self, String> {
+        let mut result = BenchArgs::default();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--iterations" => {
+                    let value = args.next().ok_or("--iterations requires a value")?;
+                    result.iterations = value
+                        .parse::<u32>()
+                        .ok()
+                        .filter(|i| *i > 0)
+                        .ok_or(format!("Invalid number of iterations: {}", value))?;
+                }
+                "--input" => {
+                    let value = args.next().ok_or("--input requires a value")?;
+                    result.input = Some(PathBuf::from(value));
+                }
+                "--json" => {
+                    let value = args.next().ok_or("--json requires a value")?;
+                    result.json = Some(PathBuf::from(value));
+                }
+                _ if result.day.is_none() && !arg.starts_with("--") => {
+                    result.day = Some(parse_day(&arg)?);
+                }
+                _ => return Err(format!("Unexpected argument: {}", arg)),
+            }
+        }
+        if result.input.is_some() && result.day.is_none() {
+            return Err("--input can only be used when benchmarking a single day".to_string());
+        }
+        Ok(result)
+    }
+}
+
+fn parse_day(arg: &str) -> Result<u8, String> {
+    arg.parse::<u8>()
+        .ok()
+        .filter(|d| (1..=25).contains(d))
+        .ok_or(format!("Invalid day: {}", arg))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::args::{BenchArgs, Command, RunArgs, USAGE};
+    use crate::report::Format;
+    use aoc_core::solution::Part;
+    use std::path::PathBuf;
+
+    fn parse(args: &str) -> Result<Command, String> {
+        Command::parse(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn help() {
+        assert_eq!(parse(""), Ok(Command::Help));
+        assert_eq!(parse("help"), Ok(Command::Help));
+        assert!(parse("fly").is_err());
+
+        // Every option is listed together, right under the heading.
+        let (_, options) = USAGE.split_once("Options:\n").unwrap();
+        let (options, _) = options.split_once("\n\n").unwrap();
+        for option in ["--part", "--format", "--input", "--iterations", "--json"] {
+            assert!(options.contains(option), "{} isn't with the others", option);
+        }
+    }
+
+    #[test]
+    fn run_all() {
+        assert_eq!(parse("run"), Ok(Command::Run(RunArgs::default())));
+        assert_eq!(
+            parse("run --part 2"),
+            Ok(Command::Run(RunArgs {
+                part: Some(Part::Two),
+                ..RunArgs::default()
+            }))
+        );
+    }
+
+    #[test]
+    fn run_day() {
+        assert_eq!(
+            parse("run 15 --part 2 --input path/to/input.txt"),
+            Ok(Command::Run(RunArgs {
+                day: Some(15),
+                part: Some(Part::Two),
+                input: Some(PathBuf::from("path/to/input.txt")),
+                format: Format::Text,
+            }))
+        );
+        assert_eq!(
+            parse("run --part 1 3"),
+            Ok(Command::Run(RunArgs {
+                day: Some(3),
+                part: Some(Part::One),
+                input: None,
+                format: Format::Text,
+            }))
+        );
+        assert_eq!(
+            parse("run --format csv"),
+            Ok(Command::Run(RunArgs {
+                format: Format::Csv,
+                ..RunArgs::default()
+            }))
+        );
+    }
+
+    #[test]
+    fn run_invalid() {
+        assert!(parse("run 0").is_err());
+        assert!(parse("run 26").is_err());
+        assert!(parse("run 1 2").is_err());
+        assert!(parse("run 1 --part 3").is_err());
+        assert!(parse("run 1 --part").is_err());
+        assert!(parse("run --input input.txt").is_err());
+        assert!(parse("run 1 --verbose").is_err());
+        assert!(parse("run --format xml").is_err());
+    }
+
+    #[test]
+    fn bench() {
+        assert_eq!(parse("bench"), Ok(Command::Bench(BenchArgs::default())));
+        assert_eq!(
+            parse("bench 23 --iterations 2 --json bench.json"),
+            Ok(Command::Bench(BenchArgs {
+                day: Some(23),
+                iterations: 2,
+                json: Some(PathBuf::from("bench.json")),
+                ..BenchArgs::default()
+            }))
+        );
+        assert!(parse("bench --iterations 0").is_err());
+        assert!(parse("bench --part 1").is_err());
+        assert!(parse("bench --input input.txt").is_err());
+    }
+}
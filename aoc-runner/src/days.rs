@@ -0,0 +1,74 @@
+use aoc_core::bench::{bench, Bencher};
+use aoc_core::solution::{solve, Solver};
+
+/// Lists `$f::<DayNN>` for every day, so that each kind of type-erased function can be collected without repeating the list of days.
+macro_rules! every_day {
+    ($f:ident) => {
+        [
+            $f::<aoc_01::Day01>,
+            $f::<aoc_02::Day02>,
+            $f::<aoc_03::Day03>,
+            $f::<aoc_04::Day04>,
+            $f::<aoc_05::Day05>,
+            $f::<aoc_06::Day06>,
+            $f::<aoc_07::Day07>,
+            $f::<aoc_08::Day08>,
+            $f::<aoc_09::Day09>,
+            $f::<aoc_10::Day10>,
+            $f::<aoc_11::Day11>,
+            $f::<aoc_12::Day12>,
+            $f::<aoc_13::Day13>,
+            $f::<aoc_14::Day14>,
+            $f::<aoc_15::Day15>,
+            $f::<aoc_16::Day16>,
+            $f::<aoc_17::Day17>,
+            $f::<aoc_18::Day18>,
+            $f::<aoc_19::Day19>,
+            $f::<aoc_20::Day20>,
+            $f::<aoc_21::Day21>,
+            $f::<aoc_22::Day22>,
+            $f::<aoc_23::Day23>,
+            $f::<aoc_24::Day24>,
+            $f::<aoc_25::Day25>,
+        ]
+    };
+}
+
+/// Every day's solver, where the index is the day minus one.
+const SOLVERS: [Solver; 25] = every_day!(solve);
+
+/// Every day's benchmark, where the index is the day minus one.
+const BENCHERS: [Bencher; 25] = every_day!(bench);
+
+/// Returns the solver for the given day, if there is one.
+pub fn get_solver(day: u8) -> Option<Solver> {
+    let index = (day as usize).checked_sub(1)?;
+    SOLVERS.get(index).copied()
+}
+
+/// Returns the benchmark for the given day, if there is one.
+pub fn get_bencher(day: u8) -> Option<Bencher> {
+    let index = (day as usize).checked_sub(1)?;
+    BENCHERS.get(index).copied()
+}
+
+/// Returns every day that has a solver, in order.
+pub fn all() -> impl Iterator<Item = u8> {
+    1..=(SOLVERS.len() as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::{all, get_bencher, get_solver};
+
+    #[test]
+    fn can_get_solver() {
+        assert!(get_solver(0).is_none());
+        assert!(get_solver(1).is_some());
+        assert!(get_solver(25).is_some());
+        assert!(get_solver(26).is_none());
+        assert!(get_bencher(25).is_some());
+        assert!(get_bencher(26).is_none());
+        assert_eq!(all().count(), 25);
+    }
+}
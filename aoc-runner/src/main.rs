@@ -0,0 +1,126 @@
+mod args;
+mod days;
+mod report;
+
+use crate::args::{BenchArgs, Command, RunArgs, USAGE};
+use crate::report::AnswerWriter;
+use aoc_core::input_resolver::InputResolver;
+use aoc_core::puzzle_input::PuzzleInput;
+use aoc_core::solution::Part;
+use std::env;
+use std::fs::write;
+use std::io;
+use std::path::PathBuf;
+use std::process::exit;
+
+fn main() {
+    let command = match Command::parse(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            exit(2);
+        }
+    };
+
+    match command {
+        Command::Help => println!("{}", USAGE),
+        Command::Run(args) => run(args),
+        Command::Bench(args) => bench(args),
+    }
+}
+
+fn run(args: RunArgs) {
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    let days: Vec<u8> = match args.day {
+        Some(day) => vec![day],
+        None => days::all().collect(),
+    };
+
+    let mut writer = AnswerWriter::new(io::stdout().lock(), args.format);
+    for day in days {
+        let solver = days::get_solver(day).expect("Day was validated when parsing arguments!");
+        let (source, input) = read_input(day, &args.input);
+        match solver(&input, &parts) {
+            Ok(answers) => answers
+                .iter()
+                .for_each(|answer| writer.write(answer).unwrap_or_else(exit_on_write_error)),
+            Err(e) => {
+                eprintln!("Could not parse {}: {}", source, e);
+                // Close off whatever was already written, so the output is still valid.
+                writer
+                    .finish()
+                    .map(drop)
+                    .unwrap_or_else(exit_on_write_error);
+                exit(1);
+            }
+        }
+    }
+    writer
+        .finish()
+        .map(drop)
+        .unwrap_or_else(exit_on_write_error);
+}
+
+fn bench(args: BenchArgs) {
+    let days: Vec<u8> = match args.day {
+        Some(day) => vec![day],
+        None => days::all().collect(),
+    };
+
+    let mut benchmarks = Vec::with_capacity(days.len());
+    for day in days {
+        let bencher = days::get_bencher(day).expect("Day was validated when parsing arguments!");
+        let (source, input) = read_input(day, &args.input);
+        eprintln!("Benchmarking day {:02}...", day);
+        match bencher(&input, args.iterations) {
+            Ok(benchmark) => benchmarks.push(benchmark),
+            Err(e) => {
+                eprintln!("Could not parse {}: {}", source, e);
+                exit(1);
+            }
+        }
+    }
+
+    print!("{}", report::render_table(&benchmarks));
+    if let Some(path) = args.json {
+        if let Err(e) = write(&path, report::render_json(&benchmarks)) {
+            eprintln!("Could not write {}: {}", path.display(), e);
+            exit(1);
+        }
+    }
+}
+
+/// Reads the input for `day` from `input` (or stdin, if it's `-`), or finds it with [`InputResolver::from_env`] if none was given.
+/// Returns where the input came from along with it, and exits if it can't be read.
+fn read_input(day: u8, input: &Option<PathBuf>) -> (String, PuzzleInput) {
+    let (source, result) = match input {
+        Some(path) if path.as_os_str() == "-" => ("stdin".to_string(), PuzzleInput::from_stdin()),
+        Some(path) => (path.display().to_string(), PuzzleInput::new(path)),
+        None => {
+            // The resolver's error lists everywhere it looked.
+            let path = InputResolver::from_env().resolve(day).unwrap_or_else(|e| {
+                eprintln!("Could not find input for day {:02}: {}", day, e);
+                exit(1);
+            });
+            (path.display().to_string(), PuzzleInput::new(&path))
+        }
+    };
+    match result {
+        Ok(input) => (source, input),
+        Err(e) => {
+            eprintln!(
+                "Could not read input for day {:02} from {}: {}",
+                day, source, e
+            );
+            exit(1);
+        }
+    }
+}
+
+fn exit_on_write_error(e: io::Error) {
+    eprintln!("Could not write answers: {}", e);
+    exit(1);
+}
@@ -0,0 +1,311 @@
+use aoc_core::bench::{Benchmark, Timing};
+use aoc_core::solution::Answer;
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// How answers are written out.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub enum Format {
+    /// One `Day NN, part N: answer` line per answer, for people to read.
+    #[default]
+    Text,
+    /// An array of `{"day", "part", "answer", "elapsed_ns"}` objects.
+    Json,
+    /// A `day,part,answer,elapsed_ns` header, then a row per answer.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("Invalid format: {}", input)),
+        }
+    }
+}
+
+/// Writes answers in a [`Format`] as they're solved, rather than waiting for every day to finish.
+pub struct AnswerWriter<W: Write> {
+    out: W,
+    format: Format,
+    written: usize,
+}
+
+impl<W: Write> AnswerWriter<W> {
+    pub fn new(out: W, format: Format) -> Self {
+        AnswerWriter {
+            out,
+            format,
+            written: 0,
+        }
+    }
+
+    /// Writes a single answer, starting with the header if this is the first one.
+    pub fn write(&mut self, answer: &Answer) -> io::Result<()> {
+        if self.written == 0 {
+            self.write_header()?;
+        }
+        match self.format {
+            Format::Text => writeln!(self.out, "{}", answer)?,
+            Format::Json => {
+                let separator = if self.written == 0 { "" } else { "," };
+                write!(
+                    self.out,
+                    "{}\n{{\"day\":{},\"part\":{},\"answer\":\"{}\",\"elapsed_ns\":{}}}",
+                    separator,
+                    answer.day,
+                    answer.part,
+                    escape_json(&answer.value),
+                    answer.elapsed.as_nanos()
+                )?;
+            }
+            Format::Csv => writeln!(
+                self.out,
+                "{},{},{},{}",
+                answer.day,
+                answer.part,
+                escape_csv(&answer.value),
+                answer.elapsed.as_nanos()
+            )?,
+        }
+        self.written += 1;
+        Ok(())
+    }
+
+    /// Closes off everything written so far, and returns the writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        if self.written == 0 {
+            self.write_header()?;
+        }
+        if self.format == Format::Json {
+            let newline = if self.written == 0 { "" } else { "\n" };
+            writeln!(self.out, "{}]", newline)?;
+        }
+        self.out.flush()?;
+        Ok(self.out)
+    }
+
+    fn write_header(&mut self) -> io::Result<()> {
+        match self.format {
+            Format::Text => Ok(()),
+            Format::Json => write!(self.out, "["),
+            Format::Csv => writeln!(self.out, "day,part,answer,elapsed_ns"),
+        }
+    }
+}
+
+/// Renders the mean time of each stage as a table, with a row for each day and a total across every day at the bottom.
+pub fn render_table(benchmarks: &[Benchmark]) -> String {
+    let mut rows = vec![[
+        "Day".to_string(),
+        "Parse".to_string(),
+        "Part 1".to_string(),
+        "Part 2".to_string(),
+        "Total".to_string(),
+    ]];
+    for benchmark in benchmarks {
+        rows.push([
+            format!("{:02}", benchmark.day),
+            format_duration(benchmark.parse.mean),
+            format_duration(benchmark.part_one.mean),
+            format_duration(benchmark.part_two.mean),
+            format_duration(benchmark.get_mean_total()),
+        ]);
+    }
+    if benchmarks.len() > 1 {
+        let sum = |f: fn(&Benchmark) -> Duration| benchmarks.iter().map(f).sum::<Duration>();
+        rows.push([
+            "All".to_string(),
+            format_duration(sum(|b| b.parse.mean)),
+            format_duration(sum(|b| b.part_one.mean)),
+            format_duration(sum(|b| b.part_two.mean)),
+            format_duration(sum(Benchmark::get_mean_total)),
+        ]);
+    }
+
+    rows.iter()
+        .map(|row| {
+            let line = format!(
+                "{:<5}{:>12}{:>12}{:>12}{:>12}",
+                row[0], row[1], row[2], row[3], row[4]
+            );
+            line + "\n"
+        })
+        .collect()
+}
+
+/// Renders every timing as JSON, in nanoseconds, so that runs can be compared by other tools.
+pub fn render_json(benchmarks: &[Benchmark]) -> String {
+    let days: Vec<String> = benchmarks
+        .iter()
+        .map(|benchmark| {
+            format!(
+                "{{\"day\":{},\"iterations\":{},\"parse\":{},\"part_one\":{},\"part_two\":{}}}",
+                benchmark.day,
+                benchmark.iterations,
+                render_timing(&benchmark.parse),
+                render_timing(&benchmark.part_one),
+                render_timing(&benchmark.part_two)
+            )
+        })
+        .collect();
+    format!("[{}]\n", days.join(","))
+}
+
+fn render_timing(timing: &Timing) -> String {
+    format!(
+        "{{\"min_ns\":{},\"mean_ns\":{},\"max_ns\":{}}}",
+        timing.min.as_nanos(),
+        timing.mean.as_nanos(),
+        timing.max.as_nanos()
+    )
+}
+
+/// Escapes `text` so it can be put between quotes in a JSON string.
+fn escape_json(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if c.is_control() => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+/// Quotes `text` if it has anything in it that would otherwise break up a CSV row, like commas or newlines.
+fn escape_csv(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.1?}", duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::report::{render_json, render_table, AnswerWriter, Format};
+    use aoc_core::bench::{Benchmark, Timing};
+    use aoc_core::solution::{Answer, Part};
+    use std::time::Duration;
+
+    fn answers() -> Vec<Answer> {
+        vec![
+            Answer {
+                day: 1,
+                part: Part::One,
+                value: "42".to_string(),
+                elapsed: Duration::from_micros(3),
+            },
+            Answer {
+                day: 13,
+                part: Part::Two,
+                value: "\"#\",\n.#".to_string(),
+                elapsed: Duration::from_nanos(10),
+            },
+        ]
+    }
+
+    fn render_answers(answers: &[Answer], format: Format) -> String {
+        let mut writer = AnswerWriter::new(Vec::new(), format);
+        for answer in answers {
+            writer.write(answer).unwrap();
+        }
+        String::from_utf8(writer.finish().unwrap()).unwrap()
+    }
+
+    fn timing(micros: u64) -> Timing {
+        let duration = Duration::from_micros(micros);
+        Timing {
+            min: duration,
+            mean: duration,
+            max: duration,
+        }
+    }
+
+    fn benchmark(day: u8) -> Benchmark {
+        Benchmark {
+            day,
+            iterations: 2,
+            parse: timing(5),
+            part_one: timing(1500),
+            part_two: timing(2_000_000),
+        }
+    }
+
+    #[test]
+    fn table() {
+        assert_eq!(
+            render_table(&[benchmark(1)]),
+            "Day         Parse      Part 1      Part 2       Total\n\
+             01          5.0µs       1.5ms        2.0s        2.0s\n"
+        );
+        assert!(render_table(&[benchmark(1), benchmark(2)])
+            .ends_with("All        10.0µs       3.0ms        4.0s        4.0s\n"));
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(render_json(&[]), "[]\n");
+        assert_eq!(
+            render_json(&[benchmark(7)]),
+            "[{\"day\":7,\"iterations\":2,\
+             \"parse\":{\"min_ns\":5000,\"mean_ns\":5000,\"max_ns\":5000},\
+             \"part_one\":{\"min_ns\":1500000,\"mean_ns\":1500000,\"max_ns\":1500000},\
+             \"part_two\":{\"min_ns\":2000000000,\"mean_ns\":2000000000,\"max_ns\":2000000000}}]\n"
+        );
+    }
+
+    #[test]
+    fn parse_format() {
+        assert_eq!("text".parse(), Ok(Format::Text));
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("csv".parse(), Ok(Format::Csv));
+        assert!("xml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn answers_as_text() {
+        assert_eq!(
+            render_answers(&answers(), Format::Text),
+            "Day 01, part 1: 42\nDay 13, part 2:\n\"#\",\n.#\n"
+        );
+        assert_eq!(render_answers(&[], Format::Text), "");
+    }
+
+    #[test]
+    fn answers_as_json() {
+        assert_eq!(
+            render_answers(&answers(), Format::Json),
+            "[\n{\"day\":1,\"part\":1,\"answer\":\"42\",\"elapsed_ns\":3000},\n\
+             {\"day\":13,\"part\":2,\"answer\":\"\\\"#\\\",\\n.#\",\"elapsed_ns\":10}\n]\n"
+        );
+        assert_eq!(render_answers(&[], Format::Json), "[]\n");
+    }
+
+    #[test]
+    fn answers_as_csv() {
+        assert_eq!(
+            render_answers(&answers(), Format::Csv),
+            "day,part,answer,elapsed_ns\n1,1,42,3000\n13,2,\"\"\"#\"\",\n.#\",10\n"
+        );
+        assert_eq!(
+            render_answers(&[], Format::Csv),
+            "day,part,answer,elapsed_ns\n"
+        );
+    }
+}